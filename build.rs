@@ -0,0 +1,35 @@
+//! Checks the bundled restaurant data (`food.json`) before it's compiled in, so that a typo fails
+//! the build instead of leaving the app without restaurants.
+//!
+//! The library can't be used from here, so this repeats the parts of its parser that edits to the
+//! data tend to break: the JSON itself, restaurant names, and times (see `Time::from_str`),
+//! including those in hours written as strings (see `Hours::from_str`). Errors name the restaurant
+//! and field the way the library's do (e.g. `Raising Cane's → tuesday → start: invalid time
+//! "1O:30": hours and minutes must be numbers`).
+
+extern crate serde_json;
+
+use std::{fs, process};
+
+// The checks themselves, shared with the tests (see `tests/build_check.rs`).
+include!("build/check.rs");
+
+/// The bundled data, relative to the crate root.
+const DATA: &str = "food.json";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", DATA);
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build/check.rs");
+    let data = fs::read_to_string(DATA).unwrap_or_else(|e| fail(&e.to_string()));
+    let value = serde_json::from_str(&data).unwrap_or_else(|e| fail(&e.to_string()));
+    if let Err(e) = check(&value) {
+        fail(&e);
+    }
+}
+
+/// Reports a problem with the data and aborts the build.
+fn fail(message: &str) -> ! {
+    eprintln!("error: invalid {}: {}", DATA, message);
+    process::exit(1)
+}
@@ -0,0 +1,148 @@
+use serde_json::{Map, Value};
+
+/// The latest hour a time may have (see `MAX_HOURS` in `schedule.rs`).
+const MAX_HOURS: u32 = 47;
+
+/// Checks a list of restaurants, or an object with `"restaurants"` and (optionally) shared
+/// `"schedules"`.
+fn check(data: &Value) -> Result<(), String> {
+    let restaurants = match *data {
+        Value::Array(ref restaurants) => restaurants,
+        Value::Object(ref data) => {
+            if let Some(schedules) = data.get("schedules").and_then(Value::as_object) {
+                for (name, hours) in schedules {
+                    let path = format!("schedule {:?}", name);
+                    if let Value::Object(ref days) = *hours {
+                        check_days(days, &path)?;
+                    }
+                    check_times(hours, &path)?;
+                }
+            }
+            match data.get("restaurants") {
+                Some(Value::Array(restaurants)) => restaurants,
+                _ => return Err("expected a \"restaurants\" list".into()),
+            }
+        }
+        _ => return Err("expected a list of restaurants".into()),
+    };
+    for (i, restaurant) in restaurants.iter().enumerate() {
+        let name = restaurant
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("restaurant #{} has no name", i + 1))?;
+        check_times(restaurant, name)?;
+    }
+    Ok(())
+}
+
+/// Checks every `"start"` and `"end"` time within the given value, naming the path to any that's
+/// invalid.
+fn check_times(value: &Value, path: &str) -> Result<(), String> {
+    match *value {
+        Value::Object(ref object) => {
+            for (key, value) in object {
+                let path = match key.as_str() {
+                    "hours" => path.to_string(),
+                    _ => format!("{} → {}", path, key),
+                };
+                match (key.as_str(), value) {
+                    ("start", Value::String(time)) | ("end", Value::String(time)) => {
+                        check_time(time)
+                            .map_err(|e| format!("{}: invalid time {:?}: {}", path, time, e))?
+                    }
+                    ("hours", Value::String(hours)) => check_hours(hours, &path)?,
+                    ("hours", Value::Object(days)) => {
+                        check_days(days, &path)?;
+                        check_times(value, &path)?
+                    }
+                    _ => check_times(value, &path)?,
+                }
+            }
+            Ok(())
+        }
+        Value::Array(ref values) => values.iter().try_for_each(|v| check_times(v, path)),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the hours written as strings (e.g. `"monday": "9:00-17:00"`) in a map of days to
+/// hours.
+fn check_days(days: &Map<String, Value>, path: &str) -> Result<(), String> {
+    for (day, hours) in days {
+        match (day.as_str(), hours) {
+            ("extends", _) => {}
+            (_, Value::String(hours)) => check_hours(hours, &format!("{} → {}", path, day))?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Checks hours written as a single string (see `Hours::from_str`): a start and end separated by
+/// a hyphen or en dash, or `"Open 24 hours"`, optionally followed by breaks in parentheses (e.g.
+/// `"11:00-21:00 (closed 15:00-16:00)"`).
+fn check_hours(s: &str, path: &str) -> Result<(), String> {
+    let invalid = |e: String| format!("{}: invalid hours {:?}: {}", path, s, e);
+    let (span, breaks) = match s.find('(') {
+        Some(index) => {
+            let breaks = s[index + 1..]
+                .trim()
+                .strip_prefix("closed ")
+                .and_then(|breaks| breaks.strip_suffix(')'))
+                .ok_or_else(|| invalid("expected breaks as in (closed 15:00-16:00)".into()))?;
+            (&s[..index], breaks.split(',').collect())
+        }
+        None => (s, Vec::new()),
+    };
+    if !span.trim().eq_ignore_ascii_case("open 24 hours") {
+        check_span(span).map_err(invalid)?;
+    }
+    breaks.into_iter().try_for_each(check_span).map_err(invalid)
+}
+
+/// Checks a start and end time separated by a hyphen or en dash, either of which may be written
+/// on a 12-hour clock (e.g. `"5:00 PM"`).
+fn check_span(s: &str) -> Result<(), String> {
+    let mut ends = s.splitn(2, ['-', '–']);
+    let start = ends.next().unwrap_or_default();
+    let end = ends
+        .next()
+        .ok_or_else(|| "expected a start and end separated by a hyphen".to_string())?;
+    for time in &[start, end] {
+        let upper = time.trim().to_ascii_uppercase();
+        let clock = upper
+            .strip_suffix("AM")
+            .or_else(|| upper.strip_suffix("PM"))
+            .unwrap_or(&upper);
+        check_time(clock)?;
+    }
+    Ok(())
+}
+
+/// Checks a time written as hours and minutes, either separated by a colon (e.g. `"9:30"`) or in
+/// compact form (e.g. `"0930"`).
+fn check_time(s: &str) -> Result<(), String> {
+    let parts = if s.contains(':') {
+        s.split(':')
+            .map(|c| c.trim().parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| "hours and minutes must be numbers".to_string())?
+    } else {
+        let s = s.trim();
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err("expected hours and minutes separated by a colon, as in 9:30".into());
+        }
+        if !(3..=4).contains(&s.len()) {
+            return Err("expected three or four digits without a colon, as in 0930".into());
+        }
+        let (hours, minutes) = s.split_at(s.len() - 2);
+        vec![hours.parse().unwrap(), minutes.parse().unwrap()]
+    };
+    match parts.len() {
+        0..=1 => Err("expected both hours and minutes".into()),
+        2 if parts[0] > MAX_HOURS => Err(format!("hours must be between 0 and {}", MAX_HOURS)),
+        2 if parts[1] > 59 => Err("minutes must be between 0 and 59".into()),
+        2 => Ok(()),
+        _ => Err("expected only hours and minutes".into()),
+    }
+}
@@ -0,0 +1,447 @@
+use clock::{Clock, FixedClock};
+use filter::RestaurantFilter;
+use schedule::{Day, Hours, Restaurant, Time, ViabilityOptions};
+use sort::SortBy;
+use std::{fmt, mem};
+use ui::{State, TRANSITION_CLASS};
+
+/// Represents the action to take in response to a request for the next suggestion.
+#[derive(Clone)]
+pub enum Step {
+    /// Present the given restaurant.
+    Suggest(Box<Restaurant>),
+    /// There are no suggestions left; present the end screen.
+    Exhaust,
+    /// The end screen was already showing; begin the cycle anew.
+    Restart,
+    /// The request doesn't apply in the current state (e.g. while tabulating).
+    Ignore,
+}
+
+/// Represents which restaurants list mode shows.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ListSource {
+    /// Only the restaurants that could be suggested right now.
+    Viable,
+    /// Every restaurant, open or not.
+    All,
+}
+
+impl ListSource {
+    /// Returns the other source.
+    pub fn toggled(self) -> Self {
+        match self {
+            ListSource::Viable => ListSource::All,
+            ListSource::All => ListSource::Viable,
+        }
+    }
+}
+
+/// Holds the session data for the application.
+///
+/// The DOM reflects this state, but never stores it; all transitions happen here first, and the
+/// user interface is updated to match afterwards.
+pub struct AppState {
+    /// The current mode of the user interface.
+    pub mode: State,
+    /// Every restaurant the app knows about, as loaded.
+    pub restaurants: Vec<Restaurant>,
+    /// The restaurants yet to be suggested, in reverse order of presentation.
+    pub remaining: Vec<Restaurant>,
+    /// The restaurants suggested so far in this cycle, in order of presentation.
+    pub history: Vec<Restaurant>,
+    /// The IDs (see `Restaurant::id`) of the restaurants the user has marked as favorites.
+    pub favorites: Vec<String>,
+    /// The criteria restaurants must meet to be suggested or listed.
+    pub filters: RestaurantFilter,
+    /// The travel time and minimum remaining time restaurants must allow for to be suggested.
+    pub viability: ViabilityOptions,
+    /// Which restaurants list mode shows.
+    pub list_source: ListSource,
+    /// The order in which list mode shows restaurants.
+    pub sort_by: SortBy,
+    /// The text typed into the search box, narrowing list mode to matching names.
+    pub search: String,
+    /// Whether to move keyboard focus to each new suggestion.
+    pub focus_suggestions: bool,
+    /// Whether to animate the transition between suggestions.
+    ///
+    /// Off by default, and overridden by `reduced_motion`; see `transition_class`.
+    pub animate_suggestions: bool,
+    /// Whether the user prefers reduced motion, as checked once at startup.
+    pub reduced_motion: bool,
+    /// The IDs of the restaurants shown in list mode, in the order they're shown.
+    pub shown: Vec<String>,
+    /// The position (in `shown`) of the listing highlighted in list mode, if any.
+    pub selected: Option<usize>,
+    /// The mode to return to when leaving list mode.
+    resume: State,
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AppState {
+    /// Creates a new, empty session.
+    pub fn new() -> Self {
+        Self {
+            mode: State::Presenting,
+            restaurants: Vec::new(),
+            remaining: Vec::new(),
+            history: Vec::new(),
+            favorites: Vec::new(),
+            filters: RestaurantFilter::new(),
+            viability: ViabilityOptions::default(),
+            list_source: ListSource::Viable,
+            sort_by: SortBy::Name,
+            search: String::new(),
+            focus_suggestions: false,
+            animate_suggestions: false,
+            reduced_motion: false,
+            shown: Vec::new(),
+            selected: None,
+            resume: State::Presenting,
+        }
+    }
+
+    /// Begins a new suggestion cycle over the given (already shuffled) restaurants.
+    ///
+    /// If there are no restaurants to begin with, the session moves straight to the
+    /// `NothingOpen` state rather than presenting anything.
+    ///
+    /// # Notes
+    /// When the new mode is `Presenting`, the caller should follow up with `advance` to present
+    /// the first suggestion.
+    pub fn start(&mut self, restaurants: Vec<Restaurant>) {
+        self.mode = if restaurants.is_empty() {
+            State::NothingOpen
+        } else {
+            State::Presenting
+        };
+        self.remaining = restaurants;
+        self.history.clear();
+    }
+
+    /// Begins a new suggestion cycle over the restaurants `queue` picks (e.g. the viable ones,
+    /// shuffled) from the session.
+    ///
+    /// If the session knows no restaurants at all, it moves to the `NoData` state instead,
+    /// without calling `queue`; otherwise, this proceeds as `start`.
+    pub fn start_with<F>(&mut self, queue: F)
+    where
+        F: FnOnce(&Self) -> Vec<Restaurant>,
+    {
+        if self.restaurants.is_empty() {
+            self.mode = State::NoData;
+            self.remaining.clear();
+            self.history.clear();
+            return;
+        }
+        let restaurants = queue(self);
+        self.start(restaurants);
+    }
+
+    /// Advances the suggestion cycle, consuming the next restaurant from the queue if one
+    /// remains.
+    ///
+    /// # Notes
+    /// This method does not touch the DOM; the caller is responsible for acting on the returned
+    /// `Step`.
+    pub fn advance(&mut self) -> Step {
+        match self.mode {
+            State::Presenting | State::Terminated => match self.remaining.pop() {
+                Some(restaurant) => {
+                    self.mode = State::Presenting;
+                    self.history.push(restaurant.clone());
+                    Step::Suggest(Box::new(restaurant))
+                }
+                None if self.mode == State::Terminated => Step::Restart,
+                None => {
+                    self.end();
+                    Step::Exhaust
+                }
+            },
+            State::NothingOpen => Step::Restart,
+            State::Tabulating | State::Loading | State::Failed | State::NoData => Step::Ignore,
+        }
+    }
+
+    /// Stops the suggestion cycle.
+    pub fn end(&mut self) {
+        self.mode = State::Terminated;
+    }
+
+    /// Returns the restaurants list mode should show at the clock's current time, according to
+    /// the list source.
+    ///
+    /// Either way, only restaurants satisfying the filter are listed.
+    pub fn listed(&self, clock: &impl Clock) -> Vec<Restaurant> {
+        match self.list_source {
+            ListSource::Viable => {
+                get_viable(&self.restaurants, &self.filters, self.viability, clock)
+            }
+            ListSource::All => self
+                .filters
+                .apply(&self.restaurants)
+                .into_iter()
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Switches list mode between showing viable restaurants and showing every restaurant.
+    ///
+    /// Returns the new source.
+    pub fn toggle_list_source(&mut self) -> ListSource {
+        self.list_source = self.list_source.toggled();
+        self.list_source
+    }
+
+    /// Returns the CSS class to apply to the suggestion while it changes, if it should be
+    /// animated at all.
+    ///
+    /// Suggestions are only animated if animation was requested and the user doesn't prefer
+    /// reduced motion.
+    pub fn transition_class(&self) -> Option<&'static str> {
+        if self.animate_suggestions && !self.reduced_motion {
+            Some(TRANSITION_CLASS)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the restaurant being presented, if any.
+    pub fn current(&self) -> Option<&Restaurant> {
+        match self.mode {
+            State::Presenting => self.history.last(),
+            _ => None,
+        }
+    }
+
+    /// Records which restaurants list mode is showing, in order, clearing any highlight.
+    ///
+    /// Restaurants are identified by ID (see `Restaurant::id`), since a chain's locations share a
+    /// name.
+    pub fn show_listings(&mut self, ids: Vec<String>) {
+        self.shown = ids;
+        self.selected = None;
+    }
+
+    /// Moves the highlight in list mode by `offset` listings, wrapping around at either end (see
+    /// `move_selection`).
+    ///
+    /// Returns the ID of the newly highlighted restaurant, or `None` if nothing is highlighted
+    /// (including outside list mode, where this does nothing).
+    pub fn move_selection(&mut self, offset: isize) -> Option<&str> {
+        if self.mode != State::Tabulating {
+            return None;
+        }
+        self.selected = move_selection(self.selected, self.shown.len(), offset);
+        let shown = &self.shown;
+        self.selected.map(move |index| shown[index].as_str())
+    }
+
+    /// Leaves list mode to present the highlighted restaurant, recording it as suggested and
+    /// taking it out of the remaining suggestions, so that it isn't suggested again this cycle.
+    ///
+    /// Returns the restaurant, or `None` (leaving the mode alone) if nothing is highlighted or
+    /// the session isn't in list mode.
+    pub fn choose_selected(&mut self) -> Option<Restaurant> {
+        if self.mode != State::Tabulating {
+            return None;
+        }
+        let id = &self.shown[self.selected?];
+        let restaurant = self.restaurants.iter().find(|r| r.id() == id)?.clone();
+        self.remaining.retain(|r| r.id() != restaurant.id());
+        self.mode = State::Presenting;
+        self.selected = None;
+        self.history.push(restaurant.clone());
+        Some(restaurant)
+    }
+
+    /// Switches into list mode, or back out of it to the last-used mode.
+    ///
+    /// Returns the new mode.
+    pub fn toggle_list_mode(&mut self) -> State {
+        self.selected = None;
+        if self.mode == State::Tabulating {
+            self.mode = self.resume;
+        } else {
+            self.resume = self.mode;
+            self.mode = State::Tabulating;
+        }
+        self.mode
+    }
+
+    /// Counts the restaurants known to the session, how many are viable at the clock's current
+    /// time (see `get_viable`), and how many are favorites.
+    pub fn stats(&self, clock: &impl Clock) -> Stats {
+        Stats {
+            total: self.restaurants.len(),
+            viable: get_viable(&self.restaurants, &self.filters, self.viability, clock).len(),
+            favorites: self
+                .restaurants
+                .iter()
+                .filter(|r| self.favorites.iter().any(|id| id == r.id()))
+                .count(),
+        }
+    }
+}
+
+/// Summarizes the restaurants a session knows about, for diagnostics (see `AppState::stats`).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    /// How many restaurants are known.
+    pub total: usize,
+    /// How many of them are viable right now, and so could be suggested.
+    pub viable: usize,
+    /// How many of them the user has marked as favorites.
+    pub favorites: usize,
+}
+
+/// Formats the counts for display (e.g. "42 places known, 5 open now, 1 favorite.").
+///
+/// Favorites are only mentioned if there are any.
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let plural = |count: usize, one: &str, many: &str| {
+            format!("{} {}", count, if count == 1 { one } else { many })
+        };
+        write!(
+            f,
+            "{} known, {} open now",
+            plural(self.total, "place", "places"),
+            self.viable
+        )?;
+        if self.favorites > 0 {
+            write!(f, ", {}", plural(self.favorites, "favorite", "favorites"))?;
+        }
+        write!(f, ".")
+    }
+}
+
+/// Moves a selection within a list of `len` items by `offset` items, wrapping around at either
+/// end.
+///
+/// With nothing selected, moving forward selects the first item and moving back selects the last.
+/// Returns `None` if the list is empty (or the offset is zero and nothing was selected).
+pub fn move_selection(current: Option<usize>, len: usize, offset: isize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let len = len as isize;
+    let next = match current {
+        Some(index) => (index.min(len as usize - 1) as isize + offset).rem_euclid(len),
+        None if offset > 0 => (offset - 1).rem_euclid(len),
+        None if offset < 0 => offset.rem_euclid(len),
+        None => return None,
+    };
+    Some(next as usize)
+}
+
+/// Writes a message suggesting the named restaurant to others (e.g. "Let's eat at Joe's Diner
+/// (open until 10:00 PM)"), noting whether it's open at the given time on the given day.
+///
+/// The hours are those for that day, and the time is relative to it (see `Hours::is_open_at`).
+/// During a break, the message says when the restaurant reopens.
+pub fn share_message(name: &str, hours: &Hours, day: Day, time: Time) -> String {
+    let status = match hours.remaining_after(time) {
+        Some(_) if hours.is_all_day() => "open 24 hours".to_string(),
+        Some(remaining) => format!("open until {}", time + remaining),
+        None => match hours.opens_after(time) {
+            Some(opens) => format!("opens at {}", opens),
+            None => format!("closed for the rest of {}", day),
+        },
+    };
+    format!("Let's eat at {} ({})", name, status)
+}
+
+/// The number of distinct values drawn from the random source for each pick in
+/// `weighted_shuffle`.
+const WEIGHT_RESOLUTION: u32 = 1 << 24;
+
+/// Shuffles a queue of restaurants so that those with higher weights (see `Restaurant::weight`)
+/// tend to be suggested earlier: each suggestion is picked from those remaining with probability
+/// proportional to its weight.
+///
+/// `rng(n)` must return a uniformly random number below `n`; taking the random source as a
+/// closure keeps this independent of where randomness comes from (e.g. `Math.random` in the
+/// browser, or a seeded generator in tests).
+///
+/// # Notes
+/// The queue is consumed from the back (see `AppState::remaining`), so the first suggestion ends
+/// up last. Weights that aren't positive are treated as zero, so such restaurants come up only
+/// once nothing else is left.
+pub fn weighted_shuffle<F>(restaurants: &mut Vec<Restaurant>, mut rng: F)
+where
+    F: FnMut(u32) -> u32,
+{
+    let weight = |r: &Restaurant| r.weight().max(0.0);
+    let mut pool = mem::take(restaurants);
+    while !pool.is_empty() {
+        let total = pool.iter().map(weight).sum::<f64>();
+        let fraction = (f64::from(rng(WEIGHT_RESOLUTION)) + 0.5) / f64::from(WEIGHT_RESOLUTION);
+        let mut target = fraction * total;
+        let index = pool
+            .iter()
+            .position(|r| {
+                target -= weight(r);
+                target < 0.0
+            })
+            .unwrap_or(pool.len() - 1);
+        restaurants.push(pool.remove(index));
+    }
+    restaurants.reverse();
+}
+
+/// How far ahead a session looking for places open soon judges restaurants, in minutes (see
+/// `session_clock`).
+pub const SOON_MINUTES: u8 = 60;
+
+/// Returns the moment a session judges restaurants at: the clock's current reading, or if the
+/// diner is leaving `soon`, `SOON_MINUTES` from now (rolling over into the next day past
+/// midnight), so that places opening shortly are included.
+///
+/// Suggestions, list mode, exports, and stats should all be judged at this moment, so that they
+/// agree on what's viable.
+pub fn session_clock(clock: &impl Clock, soon: bool) -> FixedClock {
+    let clock = FixedClock::snapshot(clock);
+    if soon {
+        clock.advance(SOON_MINUTES)
+    } else {
+        clock
+    }
+}
+
+/// Returns the restaurants that are viable at the clock's current time and satisfy the filter.
+///
+/// Each restaurant's own travel time is allowed for, falling back to the travel time in the
+/// options for restaurants that don't specify one, and each must stay open for the minimum
+/// remaining time after arrival. Schedule exceptions (e.g. holidays) are taken into account if the
+/// clock knows the date.
+pub fn get_viable(
+    restaurants: &[Restaurant],
+    filter: &RestaurantFilter,
+    options: ViabilityOptions,
+    clock: &impl Clock,
+) -> Vec<Restaurant> {
+    let (day, time, date) = (clock.today(), clock.now(), clock.date());
+    restaurants
+        .iter()
+        .filter(|r| {
+            let options = ViabilityOptions {
+                travel: r.travel_time().unwrap_or(options.travel),
+                ..options
+            };
+            let viable = match date {
+                Some(date) => r.is_viable_on_with_options(date, day, time, options),
+                None => r.is_viable_with_options(day, time, options),
+            };
+            viable && filter.matches(r)
+        })
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,244 @@
+use day_map::DayMapSpec;
+use dietary::Dietary;
+use location::Coordinates;
+use price::Price;
+use schedule::{Date, DateRange, Day, Hours, RawException, RawRestaurant, Restaurant, Time};
+use std::{collections::HashMap, error::Error, fmt};
+
+/// Constructs a `Restaurant` without going through its JSON representation.
+///
+/// Every field but the name is optional, and defaults as it would if left out of the data.
+///
+/// # Examples
+/// ```
+/// use eat_ou::{Day, Hours, RestaurantBuilder, Time};
+///
+/// let lunch = Hours::new(Time::new(11, 0), Time::new(14, 0));
+/// let restaurant = RestaurantBuilder::new()
+///     .name("Crossroads")
+///     .hours(Day::Monday, lunch.clone())
+///     .hours(Day::Tuesday, lunch)
+///     .tag("late-night")
+///     .build()
+///     .unwrap();
+/// assert_eq!(restaurant.id(), "crossroads");
+/// assert!(restaurant.is_open_at(Day::Tuesday, Time::new(12, 0)));
+/// assert!(!restaurant.has_hours(Day::Wednesday));
+/// ```
+#[derive(Default)]
+pub struct RestaurantBuilder {
+    name: Option<String>,
+    id: Option<String>,
+    aliases: Vec<String>,
+    hours: DayMapSpec<Hours>,
+    walk_minutes: Option<u8>,
+    tags: Vec<String>,
+    cuisine: Option<String>,
+    zone: Option<String>,
+    url: Option<String>,
+    phone: Option<String>,
+    coordinates: Option<Coordinates>,
+    dietary: Vec<Dietary>,
+    price: Option<Price>,
+    exceptions: Vec<RawException>,
+    closed_ranges: Vec<DateRange>,
+    note: Option<String>,
+    weight: Option<f64>,
+    temporarily_closed: bool,
+    /// The first problem with a value given as a string (see `open`), reported by `build`.
+    invalid: Option<String>,
+}
+
+impl RestaurantBuilder {
+    /// Creates a builder with nothing set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the restaurant's name, which is required.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the restaurant's ID, rather than generating one from its name.
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Adds another name the restaurant goes by (e.g. `"the Caf"`).
+    pub fn alias<S: Into<String>>(mut self, alias: S) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Sets the restaurant's hours on the given day, replacing any set before.
+    pub fn hours(mut self, day: Day, hours: Hours) -> Self {
+        self.hours.insert(day, hours);
+        self
+    }
+
+    /// Sets the restaurant's hours on the given day from times written as in the data (e.g.
+    /// `"9:00"`, or `"26:00"` for 2 AM the next morning), replacing any set before.
+    ///
+    /// A time that can't be parsed is reported by `build`.
+    pub fn open(mut self, day: Day, start: &str, end: &str) -> Self {
+        let parse = |field: &str, s: &str| {
+            s.parse::<Time>()
+                .map_err(|e| format!("{} → {}: invalid time {:?}: {}", day.key(), field, s, e))
+        };
+        match parse("start", start).and_then(|start| Ok(Hours::new(start, parse("end", end)?))) {
+            Ok(hours) => self.hours(day, hours),
+            Err(e) => {
+                self.invalid = self.invalid.or(Some(e));
+                self
+            }
+        }
+    }
+
+    /// Sets how many minutes it takes to walk to the restaurant.
+    pub fn walk_minutes(mut self, minutes: u8) -> Self {
+        self.walk_minutes = Some(minutes);
+        self
+    }
+
+    /// Adds a tag (e.g. `"vegan"`) to the restaurant.
+    pub fn tag<S: Into<String>>(mut self, tag: S) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the kind of food the restaurant serves (e.g. `"Mexican"`).
+    pub fn cuisine<S: Into<String>>(mut self, cuisine: S) -> Self {
+        self.cuisine = Some(cuisine.into());
+        self
+    }
+
+    /// Sets the part of campus the restaurant is in (e.g. `"research"`).
+    pub fn zone<S: Into<String>>(mut self, zone: S) -> Self {
+        self.zone = Some(zone.into());
+        self
+    }
+
+    /// Sets the address of the restaurant's website (e.g. its menu).
+    pub fn url<S: Into<String>>(mut self, url: S) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets the restaurant's phone number.
+    pub fn phone<S: Into<String>>(mut self, phone: S) -> Self {
+        self.phone = Some(phone.into());
+        self
+    }
+
+    /// Sets where the restaurant is.
+    pub fn coordinates(mut self, coordinates: Coordinates) -> Self {
+        self.coordinates = Some(coordinates);
+        self
+    }
+
+    /// Adds a dietary need the restaurant caters to.
+    pub fn dietary(mut self, option: Dietary) -> Self {
+        self.dietary.push(option);
+        self
+    }
+
+    /// Sets how expensive the restaurant is.
+    pub fn price(mut self, price: Price) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    /// Replaces the restaurant's weekly hours from `first` to `last` (inclusive) with the given
+    /// hours, or closes it if there are none.
+    pub fn exception(mut self, first: Date, last: Date, hours: Option<Hours>) -> Self {
+        self.exceptions.push(RawException {
+            date: None,
+            from: Some(first),
+            to: Some(last),
+            hours,
+        });
+        self
+    }
+
+    /// Closes the restaurant over the given span of days every year.
+    pub fn closed_range(mut self, range: DateRange) -> Self {
+        self.closed_ranges.push(range);
+        self
+    }
+
+    /// Sets the note shown alongside the restaurant.
+    pub fn note<S: Into<String>>(mut self, note: S) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    /// Sets how strongly the restaurant is favored when shuffling suggestions.
+    pub fn weight(mut self, weight: f64) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Marks the restaurant as closed until further notice (see
+    /// `Restaurant::is_temporarily_closed`).
+    pub fn temporarily_closed(mut self) -> Self {
+        self.temporarily_closed = true;
+        self
+    }
+
+    /// Creates the restaurant.
+    ///
+    /// # Errors
+    /// Returns `BuildError::MissingName` if no name was given, or `BuildError::Invalid` for
+    /// anything that would prevent the same restaurant from being parsed from JSON (e.g. an
+    /// exception that ends before it starts, or a time passed to `open` that can't be parsed).
+    pub fn build(self) -> Result<Restaurant, BuildError> {
+        let name = self.name.ok_or(BuildError::MissingName)?;
+        if let Some(reason) = self.invalid {
+            return Err(BuildError::Invalid(format!("{} → {}", name, reason)));
+        }
+        let raw = RawRestaurant {
+            name,
+            id: self.id,
+            aliases: self.aliases,
+            hours: self.hours,
+            walk_minutes: self.walk_minutes,
+            tags: self.tags,
+            cuisine: self.cuisine,
+            zone: self.zone,
+            url: self.url,
+            phone: self.phone,
+            coordinates: self.coordinates,
+            dietary: self.dietary,
+            price: self.price,
+            exceptions: self.exceptions,
+            closed_ranges: self.closed_ranges,
+            note: self.note,
+            weight: self.weight,
+            temporarily_closed: Some(self.temporarily_closed).filter(|&closed| closed),
+        };
+        raw.resolve(&HashMap::new()).map_err(BuildError::Invalid)
+    }
+}
+
+/// Represents a failure to build a restaurant.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BuildError {
+    /// No name was given.
+    MissingName,
+    /// The restaurant is invalid, for the given reason.
+    Invalid(String),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            BuildError::MissingName => write!(f, "restaurant has no name"),
+            BuildError::Invalid(ref reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl Error for BuildError {}
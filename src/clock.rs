@@ -0,0 +1,118 @@
+use schedule::{Date, Day, Time};
+use stdweb::web::Date as JsDate;
+
+/// The number of minutes in a day.
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// Provides the current day and time.
+pub trait Clock {
+    /// Returns the current day as an instance of `Day`.
+    fn today(&self) -> Day;
+
+    /// Returns the approximate current time as an instance of `Time`.
+    fn now(&self) -> Time;
+
+    /// Returns the current date, if the clock knows it.
+    ///
+    /// Without a date, schedule exceptions (e.g. holidays) can't be taken into account.
+    fn date(&self) -> Option<Date> {
+        None
+    }
+}
+
+/// Reads the current day and time from the browser.
+///
+/// Depends on JavaScript APIs for time information.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BrowserClock;
+
+impl Clock for BrowserClock {
+    fn today(&self) -> Day {
+        JsDate::new().get_day().into()
+    }
+
+    fn now(&self) -> Time {
+        let now = JsDate::new();
+        Time::new(now.get_hours(), now.get_minutes())
+    }
+
+    fn date(&self) -> Option<Date> {
+        let now = JsDate::new();
+        Date::try_new(
+            now.get_full_year() as u16,
+            now.get_month() as u8 + 1,
+            now.get_date() as u8,
+        )
+    }
+}
+
+/// Always reports the same day and time.
+///
+/// Useful for tests, and for demoing the app at a particular time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FixedClock {
+    pub day: Day,
+    pub time: Time,
+    pub date: Option<Date>,
+}
+
+impl FixedClock {
+    /// Creates a clock stopped at the given day and time, with no particular date.
+    pub fn new(day: Day, time: Time) -> Self {
+        Self {
+            day,
+            time,
+            date: None,
+        }
+    }
+
+    /// Creates a clock stopped at the given clock's current reading.
+    ///
+    /// Useful for making several decisions against the same moment.
+    pub fn snapshot(clock: &impl Clock) -> Self {
+        Self {
+            day: clock.today(),
+            time: clock.now(),
+            date: clock.date(),
+        }
+    }
+
+    /// Sets the date the clock reports.
+    ///
+    /// # Notes
+    /// The date should fall on the clock's day; this is not checked.
+    pub fn with_date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Moves the clock forward by the given number of minutes, rolling over into the following
+    /// day (and date, if known) past midnight.
+    ///
+    /// The time is always brought back within 0:00–23:59, so that the following day's hours are
+    /// consulted instead of judging a time like 24:30 against the same day.
+    pub fn advance(mut self, minutes: u8) -> Self {
+        let total = self.time.total_minutes() + u32::from(minutes);
+        for _ in 0..total / MINUTES_PER_DAY {
+            self.day = self.day + 1;
+            self.date = self.date.map(Date::next);
+        }
+        let total = total % MINUTES_PER_DAY;
+        self.time = Time::new((total / 60) as i32, (total % 60) as i32);
+        self
+    }
+}
+
+impl Clock for FixedClock {
+    fn today(&self) -> Day {
+        self.day
+    }
+
+    fn now(&self) -> Time {
+        self.time
+    }
+
+    fn date(&self) -> Option<Date> {
+        self.date
+    }
+}
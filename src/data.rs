@@ -0,0 +1,564 @@
+use day_map::DayMapSpec;
+use import::parse_osm_times;
+use schedule::{assign_ids, Date, Day, Hours, HoursMap, RawRestaurant, Restaurant};
+use serde::de::Error as DeserializationError;
+use serde::de::{Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_json::{self, Map, Value};
+use std::io::Read;
+use std::{cell::RefCell, collections::HashMap, error::Error, fmt, iter, rc::Rc, time::Duration};
+use stdweb::traits::IEvent;
+use stdweb::web::event::{ProgressErrorEvent, ProgressLoadEvent};
+use stdweb::web::{set_timeout, IEventTarget, XmlHttpRequest};
+use storage::Storage;
+use validation::{validate, Severity, ValidationIssue};
+
+/// How long to wait for remote restaurant data before giving up on it.
+pub const FETCH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Represents a failure to load the restaurant data.
+#[derive(Debug)]
+pub enum DataError {
+    /// The data couldn't be parsed.
+    Parse(serde_json::Error),
+    /// The data was parsed, but contains no restaurants.
+    Empty,
+    /// The data was parsed, but has the given problems (all of which are errors).
+    Invalid(Vec<ValidationIssue>),
+    /// The data couldn't be fetched, for the given reason.
+    Fetch(String),
+    /// The data is in a newer version of the format (see `DATA_VERSION`) than this crate
+    /// understands.
+    UnsupportedVersion(u32),
+    /// A cell of spreadsheet data (see `Restaurant::from_csv`) is invalid. Rows and columns are
+    /// numbered from 1, as in a spreadsheet.
+    Csv {
+        row: usize,
+        column: usize,
+        reason: String,
+    },
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            DataError::Parse(ref e) => write!(f, "Failed to parse restaurant data: {}", e),
+            DataError::Empty => write!(f, "The restaurant data contains no restaurants"),
+            DataError::Invalid(ref issues) => {
+                let issues = issues.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+                write!(f, "The restaurant data is invalid: {}", issues.join("; "))
+            }
+            DataError::Fetch(ref reason) => {
+                write!(f, "Failed to fetch restaurant data: {}", reason)
+            }
+            DataError::UnsupportedVersion(version) => write!(
+                f,
+                "The restaurant data is in version {} of the format, but only versions up to {} \
+                 are understood (reloading may update the app)",
+                version, DATA_VERSION
+            ),
+            DataError::Csv {
+                row,
+                column,
+                ref reason,
+            } => write!(
+                f,
+                "The restaurant data is invalid at row {}, column {}: {}",
+                row, column, reason
+            ),
+        }
+    }
+}
+
+impl Error for DataError {}
+
+impl From<serde_json::Error> for DataError {
+    fn from(error: serde_json::Error) -> Self {
+        DataError::Parse(error)
+    }
+}
+
+/// The newest version of the data format this crate understands.
+///
+/// Data without a version (including a bare array of restaurants) is taken to be version 1.
+pub const DATA_VERSION: u32 = 2;
+
+/// A list of restaurants, along with what the data says about itself.
+#[derive(Clone)]
+pub struct Dataset {
+    /// The version of the data format (see `DATA_VERSION`).
+    pub version: u32,
+    /// When the data was last updated, if it says.
+    pub updated: Option<Date>,
+    /// The restaurants, with their schedules resolved.
+    pub restaurants: Vec<Restaurant>,
+}
+
+/// Parses restaurant data from JSON, along with its version and when it was last updated.
+///
+/// Besides the layouts `Restaurant::from_json_str` accepts, the object form may carry a
+/// `"version"` (see `DATA_VERSION`) and an `"updated"` date, e.g. `{ "version": 2, "updated":
+/// "2024-09-01", "restaurants": [...] }`.
+///
+/// # Errors
+/// Returns `DataError::UnsupportedVersion` if the data is in a newer version of the format than
+/// this crate understands (before looking at the restaurants, whose fields may have changed), or
+/// `DataError::Parse` if it can't be parsed.
+pub fn load(s: &str) -> Result<Dataset, DataError> {
+    load_value(serde_json::from_str(s)?)
+}
+
+/// Parses restaurant data from an already-parsed JSON value (see `load`).
+pub(crate) fn load_value(value: Value) -> Result<Dataset, DataError> {
+    let (version, updated) = metadata(&value)?;
+    let restaurants = serde_json::from_value::<RestaurantList>(value)?.0;
+    Ok(Dataset {
+        version,
+        updated,
+        restaurants,
+    })
+}
+
+/// Parses restaurant data from TOML (see `Restaurant::from_toml_str`).
+#[cfg(feature = "toml-data")]
+pub(crate) fn load_toml(s: &str) -> Result<Dataset, DataError> {
+    let value = toml::from_str(s).map_err(serde_json::Error::custom)?;
+    load_value(toml_to_json(value))
+}
+
+/// Converts a TOML value into the equivalent JSON, writing dates and times as strings (e.g.
+/// `"2024-09-01"`) as they are in JSON data.
+#[cfg(feature = "toml-data")]
+fn toml_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::from(i),
+        toml::Value::Float(f) => Value::from(f),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+        toml::Value::Array(values) => Value::Array(values.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Parses restaurant data from CSV (see `Restaurant::from_csv`).
+pub(crate) fn load_csv<R: Read>(mut reader: R) -> Result<Vec<Restaurant>, DataError> {
+    let mut csv = String::new();
+    reader
+        .read_to_string(&mut csv)
+        .map_err(|e| DataError::Fetch(e.to_string()))?;
+    let mut rows = csv
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, split_csv_row(line)))
+        .filter(|(_, cells)| cells.iter().any(|cell| !cell.trim().is_empty()));
+    let invalid = |row, column, reason: String| DataError::Csv {
+        row,
+        column,
+        reason,
+    };
+    let columns = iter::once("name").chain(Day::all().map(Day::key));
+    match rows.next() {
+        Some((row, header)) => {
+            for (column, expected) in columns.enumerate() {
+                let found = header.get(column).map_or("", |cell| cell.trim());
+                if !found.eq_ignore_ascii_case(expected) {
+                    let reason = format!("expected a column named {:?}, not {:?}", expected, found);
+                    return Err(invalid(row, column + 1, reason));
+                }
+            }
+        }
+        None => return Err(DataError::Empty),
+    }
+    let mut entries = Vec::new();
+    for (row, cells) in rows {
+        if cells.len() != 8 {
+            let reason = format!(
+                "expected a name and 7 days of hours, not {} cells",
+                cells.len()
+            );
+            return Err(invalid(row, cells.len().min(8) + 1, reason));
+        }
+        let name = cells[0].trim();
+        if name.is_empty() {
+            return Err(invalid(row, 1, "expected a name".to_string()));
+        }
+        let mut hours = HoursMap::new();
+        for (day, (column, cell)) in Day::all().zip(cells.iter().enumerate().skip(1)) {
+            let reason = |e: String| invalid(row, column + 1, format!("{}: {}", day.key(), e));
+            if cell.trim().is_empty() {
+                return Err(reason("expected hours or \"closed\"".to_string()));
+            }
+            if let Some(day_hours) = parse_osm_times(cell).map_err(|e| reason(e.to_string()))? {
+                hours.insert(day, day_hours);
+            }
+        }
+        let mut entry = Map::new();
+        entry.insert("name".to_string(), Value::String(name.to_string()));
+        entry.insert("hours".to_string(), serde_json::to_value(&hours)?);
+        entries.push(Value::Object(entry));
+    }
+    Ok(load_value(Value::Array(entries))?.restaurants)
+}
+
+/// Splits a line of CSV into its cells, unquoting any quoted ones (e.g. `"11:00-14:00,17:00-21:00"`,
+/// whose commas don't separate cells).
+fn split_csv_row(line: &str) -> Vec<String> {
+    let mut cells = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.trim_end_matches('\r').chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                cells.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => cells.push(String::new()),
+            c => cells.last_mut().unwrap().push(c),
+        }
+    }
+    cells
+}
+
+/// Reads the version and last-updated date from restaurant data, checking that the version is
+/// one this crate understands.
+fn metadata(value: &Value) -> Result<(u32, Option<Date>), DataError> {
+    let version = match value.get("version") {
+        None => 1,
+        Some(version) => match version.as_u64() {
+            Some(version) if version >= 1 => version.min(u64::from(u32::MAX)) as u32,
+            _ => {
+                return Err(DataError::Parse(serde_json::Error::custom(format!(
+                    "version: expected a positive whole number, not {}",
+                    version
+                ))))
+            }
+        },
+    };
+    if version > DATA_VERSION {
+        return Err(DataError::UnsupportedVersion(version));
+    }
+    let updated = match value.get("updated") {
+        Some(updated) => Some(
+            serde_json::from_value(updated.clone())
+                .map_err(|e| serde_json::Error::custom(format!("updated: {}", e)))?,
+        ),
+        None => None,
+    };
+    Ok((version, updated))
+}
+
+/// The storage key under which extra, user-provided restaurants are kept.
+pub const EXTRA_RESTAURANTS_KEY: &str = "eat-ou:extra-restaurants";
+
+/// Rejects restaurants with validation errors, passing any others through.
+fn reject_invalid(restaurants: Vec<Restaurant>) -> Result<Vec<Restaurant>, DataError> {
+    let errors = validate(&restaurants)
+        .into_iter()
+        .filter(|issue| issue.severity == Severity::Error)
+        .collect::<Vec<_>>();
+    if errors.is_empty() {
+        Ok(restaurants)
+    } else {
+        Err(DataError::Invalid(errors))
+    }
+}
+
+/// Parses a list of restaurants from JSON, rejecting data with validation errors.
+///
+/// Validation warnings are allowed through; see `validate` to report them.
+///
+/// # Errors
+/// Returns `Err` if the data can't be parsed, contains no restaurants, or fails validation.
+pub fn parse_validated(s: &str) -> Result<Vec<Restaurant>, DataError> {
+    let restaurants = Restaurant::from_json_str(s)?;
+    if restaurants.is_empty() {
+        return Err(DataError::Empty);
+    }
+    reject_invalid(restaurants)
+}
+
+/// Reads the extra restaurants the user has stored under `EXTRA_RESTAURANTS_KEY`.
+///
+/// The stored value uses the same schema as the bundled data. If nothing is stored, there are
+/// no extra restaurants.
+///
+/// # Errors
+/// Returns `Err` if the stored data can't be parsed or fails validation.
+pub fn load_extra_restaurants(storage: &impl Storage) -> Result<Vec<Restaurant>, DataError> {
+    match storage.get(EXTRA_RESTAURANTS_KEY) {
+        Some(s) => reject_invalid(Restaurant::from_json_str(&s)?),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Merges extra restaurants into a base list.
+///
+/// An extra restaurant with the same ID as one already in the list is merged into it (in place;
+/// see `Restaurant::merge`), which allows correcting its details; the rest are appended, in
+/// order. Each merge is reported as a warning, so that the duplicate can be cleaned up at its
+/// source, except for exact duplicates, which collapse silently.
+pub fn merge_restaurants(
+    base: Vec<Restaurant>,
+    extra: Vec<Restaurant>,
+) -> (Vec<Restaurant>, Vec<ValidationIssue>) {
+    let mut merged = base;
+    let mut issues = Vec::new();
+    for restaurant in extra {
+        let index = match merged.iter().position(|r| r.id() == restaurant.id()) {
+            Some(index) => index,
+            None => {
+                merged.push(restaurant);
+                continue;
+            }
+        };
+        if serde_json::to_value(&merged[index]).ok() == serde_json::to_value(&restaurant).ok() {
+            continue;
+        }
+        issues.push(ValidationIssue {
+            severity: Severity::Warning,
+            restaurant: restaurant.name.clone(),
+            day: None,
+            message: format!(
+                "merged with an earlier entry with the same ID (\"{}\")",
+                restaurant.id()
+            ),
+        });
+        merged[index].merge(restaurant);
+    }
+    (merged, issues)
+}
+
+/// Fetches a list of restaurants from the given URL, passing the result to `callback`.
+///
+/// The callback is invoked exactly once: with the restaurants if the request succeeds and the
+/// payload passes `parse_validated`, or with an error if the request fails, the payload is
+/// rejected, or no response arrives within `timeout`.
+///
+/// Depends on JavaScript APIs for networking.
+pub fn fetch_restaurants<F>(url: &str, timeout: Duration, callback: F)
+where
+    F: FnOnce(Result<Vec<Restaurant>, DataError>) + 'static,
+{
+    // Whichever of the handlers below fires first takes the callback.
+    let callback = Rc::new(RefCell::new(Some(callback)));
+    let finish = move |result| {
+        if let Some(callback) = callback.borrow_mut().take() {
+            callback(result);
+        }
+    };
+    let request = XmlHttpRequest::new();
+    if let Err(e) = request.open("GET", url) {
+        return finish(Err(DataError::Fetch(e.to_string())));
+    }
+    let on_load = finish.clone();
+    let loaded = request.clone();
+    request.add_event_listener(move |_: ProgressLoadEvent| {
+        let result = match loaded.status() {
+            200..=299 => match loaded.response_text() {
+                Ok(Some(text)) => parse_validated(&text),
+                _ => Err(DataError::Fetch("the response has no body".into())),
+            },
+            status => Err(DataError::Fetch(format!("HTTP status {}", status))),
+        };
+        on_load(result);
+    });
+    let on_error = finish.clone();
+    request.add_event_listener(move |event: ProgressErrorEvent| {
+        on_error(Err(DataError::Fetch(format!(
+            "network error ({})",
+            event.event_type()
+        ))));
+    });
+    if let Err(e) = request.send() {
+        return finish(Err(DataError::Fetch(e.to_string())));
+    }
+    let millis = timeout.as_secs() * 1000 + u64::from(timeout.subsec_millis());
+    set_timeout(
+        move || {
+            request.abort();
+            finish(Err(DataError::Fetch("timed out".into())));
+        },
+        millis as u32,
+    );
+}
+
+/// A list of restaurants with their schedules resolved.
+///
+/// Deserializes from either a bare array of restaurants or an object with `"restaurants"` and
+/// (optionally) `"schedules"` keys.
+pub(crate) struct RestaurantList(pub(crate) Vec<Restaurant>);
+
+impl<'de> Deserialize<'de> for RestaurantList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(RestaurantListVisitor)
+    }
+}
+
+/// Resolves the named schedules, which restaurants may refer to.
+fn resolve_schedules<E>(
+    schedules: HashMap<String, DayMapSpec<Hours>>,
+) -> Result<HashMap<String, HoursMap>, E>
+where
+    E: DeserializationError,
+{
+    let mut resolved = HashMap::new();
+    for (name, spec) in schedules {
+        if spec.extends().is_some() {
+            return Err(E::custom(format!(
+                "schedule \"{}\" can't extend another schedule",
+                name
+            )));
+        }
+        let hours: HoursMap = spec
+            .resolve(None)
+            .map_err(|e| E::custom(format!("schedule \"{}\": {}", name, e)))?;
+        resolved.insert(name, hours);
+    }
+    Ok(resolved)
+}
+
+/// Resolves each restaurant's schedule against the named schedules.
+fn resolve<E>(
+    schedules: HashMap<String, DayMapSpec<Hours>>,
+    restaurants: Vec<RawRestaurant>,
+) -> Result<Vec<Restaurant>, E>
+where
+    E: DeserializationError,
+{
+    let resolved = resolve_schedules(schedules)?;
+    restaurants
+        .into_iter()
+        .map(|r| r.resolve(&resolved).map_err(E::custom))
+        .collect()
+}
+
+/// Parses a list of restaurants from JSON, collecting an error for every invalid entry rather
+/// than stopping at the first.
+///
+/// See `Restaurant::validate_list`.
+pub(crate) fn parse_entries(s: &str) -> Result<Vec<Restaurant>, Vec<(usize, String)>> {
+    let document = |e: &dyn fmt::Display| vec![(0, e.to_string())];
+    let value = serde_json::from_str(s).map_err(|e| document(&e))?;
+    metadata(&value).map_err(|e| document(&e))?;
+    let (schedules, entries) = match value {
+        Value::Array(entries) => (None, entries),
+        Value::Object(mut object) => match object.remove("restaurants") {
+            Some(Value::Array(entries)) => (object.remove("schedules"), entries),
+            _ => return Err(document(&"expected a \"restaurants\" array")),
+        },
+        _ => {
+            return Err(document(
+                &"expected an array of restaurants, or an object containing one",
+            ))
+        }
+    };
+    let schedules = match schedules {
+        Some(schedules) => serde_json::from_value(schedules)
+            .and_then(resolve_schedules::<serde_json::Error>)
+            .map_err(|e| document(&format!("schedules → {}", e)))?,
+        None => HashMap::new(),
+    };
+    let mut raw = Vec::new();
+    let mut errors = Vec::new();
+    for (index, entry) in entries.into_iter().enumerate() {
+        let label = entry
+            .get("name")
+            .and_then(Value::as_str)
+            .map(|name| format!("{} → ", name))
+            .unwrap_or_default();
+        match serde_json::from_value::<RawRestaurant>(entry) {
+            Ok(restaurant) => raw.push((index, restaurant)),
+            Err(e) => errors.push((index, format!("{}{}", label, e))),
+        }
+    }
+    let (indices, mut raw): (Vec<_>, Vec<_>) = raw.into_iter().unzip();
+    assign_ids(&mut raw);
+    let mut restaurants = Vec::new();
+    for (index, restaurant) in indices.into_iter().zip(raw) {
+        match restaurant.resolve(&schedules) {
+            Ok(restaurant) => restaurants.push(restaurant),
+            Err(e) => errors.push((index, e)),
+        }
+    }
+    errors.sort_by_key(|&(index, _)| index);
+    if errors.is_empty() {
+        Ok(restaurants)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Deserializes the restaurants in a list, noting which restaurant (by name, or by position if
+/// it has none) any error belongs to.
+///
+/// # Notes
+/// Each restaurant is first read into a `Value`, since errors don't carry their location.
+fn deserialize_restaurants<E>(restaurants: Vec<Value>) -> Result<Vec<RawRestaurant>, E>
+where
+    E: DeserializationError,
+{
+    restaurants
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let label = match value.get("name").and_then(Value::as_str) {
+                Some(name) => name.to_string(),
+                None => format!("restaurant #{}", index + 1),
+            };
+            serde_json::from_value(value).map_err(|e| E::custom(format!("{} → {}", label, e)))
+        })
+        .collect()
+}
+
+struct RestaurantListVisitor;
+
+impl<'de> Visitor<'de> for RestaurantListVisitor {
+    type Value = RestaurantList;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "an array of restaurants, or an object containing one")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut restaurants = Vec::new();
+        while let Some(restaurant) = seq.next_element()? {
+            restaurants.push(restaurant);
+        }
+        let mut restaurants = deserialize_restaurants(restaurants)?;
+        assign_ids(&mut restaurants);
+        resolve(HashMap::new(), restaurants).map(RestaurantList)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut schedules = None;
+        let mut restaurants = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "schedules" => schedules = Some(map.next_value()?),
+                "restaurants" => restaurants = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let restaurants = restaurants.ok_or_else(|| A::Error::missing_field("restaurants"))?;
+        let mut restaurants = deserialize_restaurants(restaurants)?;
+        assign_ids(&mut restaurants);
+        resolve(schedules.unwrap_or_default(), restaurants).map(RestaurantList)
+    }
+}
@@ -0,0 +1,252 @@
+use schedule::Day;
+use serde::de::Error as DeserializationError;
+use serde::de::{Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::{error::Error, fmt, marker::PhantomData};
+
+/// Associates a value with some (or all) days of the week.
+///
+/// Deserializes from a map keyed by lowercase day names (e.g. `"monday"`); days that are absent
+/// or `null` have no value, and unrecognized keys are ignored. The shorthand keys `"daily"`,
+/// `"weekdays"`, and `"weekends"` may be used to cover several days at once, with specific days
+/// taking precedence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayMap<T> {
+    days: [Option<T>; 7],
+}
+
+impl<T> Default for DayMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> DayMap<T> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self {
+            days: [None, None, None, None, None, None, None],
+        }
+    }
+
+    /// Returns the value associated with the given day, if any.
+    pub fn get(&self, day: Day) -> Option<&T> {
+        self.days[day as usize].as_ref()
+    }
+
+    /// Associates the value with the given day, returning the previous value, if any.
+    pub fn insert(&mut self, day: Day, value: T) -> Option<T> {
+        self.days[day as usize].replace(value)
+    }
+
+    /// Removes the value associated with the given day, returning it, if any.
+    pub fn remove(&mut self, day: Day) -> Option<T> {
+        self.days[day as usize].take()
+    }
+
+    /// Iterates over the days with values, in order from Sunday to Saturday.
+    pub fn iter(&self) -> impl Iterator<Item = (Day, &T)> {
+        Day::all()
+            .zip(self.days.iter())
+            .filter_map(|(day, value)| value.as_ref().map(|v| (day, v)))
+    }
+}
+
+/// Serializes as a map keyed by lowercase day names, omitting days without values.
+impl<T: Serialize> Serialize for DayMap<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        for (day, value) in self.iter() {
+            map.serialize_entry(day.key(), value)?;
+        }
+        map.end()
+    }
+}
+
+/// Represents shorthand keys (`"weekdays"` or `"weekends"` alongside `"daily"`) that specify
+/// different values for the same day.
+#[derive(Debug)]
+pub struct ShorthandConflict {
+    shorthand: &'static str,
+}
+
+impl fmt::Display for ShorthandConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "\"{}\" and \"daily\" specify conflicting hours",
+            self.shorthand
+        )
+    }
+}
+
+impl Error for ShorthandConflict {}
+
+/// A `DayMap` as written in the data, before shorthand keys are expanded.
+///
+/// In addition to lowercase day names, the keys `"daily"`, `"weekdays"`, and `"weekends"` are
+/// accepted; they apply to every day they cover that isn't given explicitly (an explicit `null`
+/// clears the day). The spec may also name another map it `"extends"`, either with that key or by
+/// being a bare string, in which case only the days it covers are overridden.
+pub(crate) struct DayMapSpec<T> {
+    extends: Option<String>,
+    days: [Option<Option<T>>; 7],
+    daily: Option<T>,
+    weekdays: Option<T>,
+    weekends: Option<T>,
+}
+
+impl<T> Default for DayMapSpec<T> {
+    fn default() -> Self {
+        Self {
+            extends: None,
+            days: [None, None, None, None, None, None, None],
+            daily: None,
+            weekdays: None,
+            weekends: None,
+        }
+    }
+}
+
+impl<T> DayMapSpec<T>
+where
+    T: Clone + PartialEq,
+{
+    /// Gives the day a value explicitly, taking precedence over any shorthand keys.
+    pub(crate) fn insert(&mut self, day: Day, value: T) {
+        self.days[day as usize] = Some(Some(value));
+    }
+
+    /// Returns the name of the map this spec extends, if any.
+    pub(crate) fn extends(&self) -> Option<&str> {
+        self.extends.as_deref()
+    }
+
+    /// Expands the shorthand keys on top of `base` (the map this spec extends, if any),
+    /// producing the map the verbose form would have.
+    ///
+    /// # Errors
+    /// Returns `Err` if `"weekdays"` or `"weekends"` conflicts with `"daily"`.
+    pub(crate) fn resolve(self, base: Option<&DayMap<T>>) -> Result<DayMap<T>, ShorthandConflict> {
+        let conflicts = |shorthand: &Option<T>| match (shorthand, &self.daily) {
+            (Some(a), Some(b)) => a != b,
+            _ => false,
+        };
+        if conflicts(&self.weekdays) {
+            return Err(ShorthandConflict {
+                shorthand: "weekdays",
+            });
+        }
+        if conflicts(&self.weekends) {
+            return Err(ShorthandConflict {
+                shorthand: "weekends",
+            });
+        }
+        let mut map = base.cloned().unwrap_or_default();
+        for (day, explicit) in Day::all().zip(self.days.iter()) {
+            let shorthand = if day.is_weekend() {
+                &self.weekends
+            } else {
+                &self.weekdays
+            };
+            match explicit {
+                Some(Some(value)) => {
+                    map.insert(day, value.clone());
+                }
+                Some(None) => {
+                    map.remove(day);
+                }
+                None => {
+                    if let Some(value) = shorthand.as_ref().or(self.daily.as_ref()) {
+                        map.insert(day, value.clone());
+                    }
+                }
+            }
+        }
+        Ok(map)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for DayMapSpec<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DayMapVisitor(PhantomData))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for DayMap<T>
+where
+    T: Deserialize<'de> + Clone + PartialEq,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let spec = DayMapSpec::deserialize(deserializer)?;
+        if let Some(name) = spec.extends() {
+            return Err(DeserializationError::custom(format!(
+                "unknown schedule \"{}\"",
+                name
+            )));
+        }
+        spec.resolve(None).map_err(DeserializationError::custom)
+    }
+}
+
+struct DayMapVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for DayMapVisitor<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = DayMapSpec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "a map keyed by lowercase day names, or the name of a schedule"
+        )
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeserializationError,
+    {
+        Ok(DayMapSpec {
+            extends: Some(value.to_string()),
+            ..DayMapSpec::default()
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut spec = DayMapSpec::default();
+        while let Some(key) = map.next_key::<String>()? {
+            // Note which key a value belongs to, since errors don't carry their location.
+            let context = |e: A::Error| A::Error::custom(format!("{} → {}", key, e));
+            match key.as_str() {
+                "extends" => spec.extends = Some(map.next_value()?),
+                "daily" => spec.daily = map.next_value().map_err(context)?,
+                "weekdays" => spec.weekdays = map.next_value().map_err(context)?,
+                "weekends" => spec.weekends = map.next_value().map_err(context)?,
+                _ => match Day::all().find(|day| day.key() == key) {
+                    Some(day) => spec.days[day as usize] = Some(map.next_value().map_err(context)?),
+                    None => {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                },
+            }
+        }
+        Ok(spec)
+    }
+}
@@ -0,0 +1,76 @@
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+/// Represents a dietary need a restaurant caters to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Dietary {
+    /// Has dishes without meat or fish.
+    Vegetarian,
+    /// Has dishes without any animal products.
+    Vegan,
+    /// Has dishes prepared according to Islamic dietary law.
+    Halal,
+    /// Has dishes without gluten.
+    GlutenFree,
+    /// An option this version of the app doesn't recognize, as written in the data.
+    ///
+    /// Unrecognized options are kept (and reported by `validate`) rather than rejected, so that
+    /// data written for a newer version still loads.
+    Unknown(String),
+}
+
+impl Dietary {
+    /// Returns the name of the option, as written in the data (e.g. `"gluten_free"`).
+    pub fn key(&self) -> &str {
+        match *self {
+            Dietary::Vegetarian => "vegetarian",
+            Dietary::Vegan => "vegan",
+            Dietary::Halal => "halal",
+            Dietary::GlutenFree => "gluten_free",
+            Dietary::Unknown(ref key) => key,
+        }
+    }
+
+    /// Returns whether this option is one the app recognizes.
+    pub fn is_known(&self) -> bool {
+        !matches!(*self, Dietary::Unknown(_))
+    }
+}
+
+impl fmt::Display for Dietary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.key())
+    }
+}
+
+impl<'a> From<&'a str> for Dietary {
+    fn from(s: &'a str) -> Self {
+        match s.to_lowercase().as_str() {
+            "vegetarian" => Dietary::Vegetarian,
+            "vegan" => Dietary::Vegan,
+            "halal" => Dietary::Halal,
+            "gluten_free" | "gluten-free" => Dietary::GlutenFree,
+            _ => Dietary::Unknown(s.into()),
+        }
+    }
+}
+
+impl Serialize for Dietary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for Dietary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(Dietary::from(s.as_str()))
+    }
+}
@@ -0,0 +1,58 @@
+use schedule::{format_iso8601_minutes, Day, Restaurant, Time, DEFAULT_TRAVEL_TIME};
+use serde_json;
+
+/// Describes a viable restaurant for other tools, as written by `export_viable`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExportedRestaurant {
+    /// The restaurant's name.
+    pub name: String,
+    /// The restaurant's hours that day, formatted for display (see
+    /// `Restaurant::describe_hours`).
+    pub hours: String,
+    /// How many minutes are left before the restaurant closes, counted from the moment of export,
+    /// or `None` if it's open 24 hours.
+    pub minutes_until_close: Option<u32>,
+    /// The same, as an ISO 8601 duration (e.g. `"PT1H30M"`; see `format_iso8601_minutes`).
+    pub closes_in: Option<String>,
+}
+
+impl ExportedRestaurant {
+    /// Describes the given restaurant as of the given moment, if it's open once the diner arrives
+    /// (after its travel time, as `Restaurant::is_viable` judges).
+    ///
+    /// A restaurant that opens on the way is described as of the arrival, so it's still included.
+    fn new(restaurant: &Restaurant, day: Day, time: Time) -> Option<Self> {
+        let travel = restaurant.travel_time().unwrap_or(DEFAULT_TRAVEL_TIME);
+        let minutes = restaurant
+            .closes_in(day, time + travel)?
+            .map(|closes_in| ((closes_in + travel).as_secs() / 60) as u32);
+        Some(Self {
+            name: restaurant.name.clone(),
+            hours: restaurant.describe_hours(day),
+            minutes_until_close: minutes,
+            closes_in: minutes.map(|minutes| format_iso8601_minutes(minutes as i32)),
+        })
+    }
+}
+
+/// Writes the restaurants that are viable at the given moment (see `Restaurant::is_viable`) as a
+/// JSON array of `ExportedRestaurant`s, in the order given.
+///
+/// # Examples
+/// ```
+/// use eat_ou::{export_viable, Day, Restaurant, Time};
+///
+/// let restaurants = Restaurant::from_json_str(r#"[
+///     { "name": "Crossroads", "hours": { "daily": { "start": "7:00", "end": "26:00" } } }
+/// ]"#).unwrap();
+/// let json = export_viable(&restaurants, Day::Monday, Time::new(24, 30));
+/// assert!(json.contains("\"closes_in\": \"PT1H30M\""));
+/// ```
+pub fn export_viable(restaurants: &[Restaurant], day: Day, time: Time) -> String {
+    let exported = restaurants
+        .iter()
+        .filter(|r| r.is_viable(day, time))
+        .filter_map(|r| ExportedRestaurant::new(r, day, time))
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&exported).expect("exported restaurants always serialize to JSON")
+}
@@ -0,0 +1,206 @@
+use dietary::Dietary;
+use price::Price;
+use query;
+use schedule::{Day, Restaurant, Time};
+use storage::Storage;
+
+/// Describes criteria that restaurants must meet in order to be suggested or listed.
+///
+/// Criteria combine with AND semantics (a restaurant must meet all of them), and an empty filter
+/// passes every restaurant.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RestaurantFilter {
+    open_at: Option<(Day, Time)>,
+    open_on_weekend: bool,
+    tags: Vec<String>,
+    excluded_tags: Vec<String>,
+    include_untagged: bool,
+    exclude_temporarily_closed: bool,
+    dietary: Vec<Dietary>,
+    max_price: Option<Price>,
+    zone: Option<String>,
+}
+
+impl RestaurantFilter {
+    /// Creates an empty filter, which passes every restaurant.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a filter passing restaurants with every one of the `include` tags and none of the
+    /// `exclude` tags, ignoring case.
+    ///
+    /// With no `include` tags, every restaurant (tagged or not) passes that part of the filter.
+    pub fn filter_by_tags(include: &[&str], exclude: &[&str]) -> Self {
+        Self::new()
+            .with_tags(include.iter().cloned())
+            .without_tags(exclude.iter().cloned())
+    }
+
+    /// Restricts the filter to restaurants open at the given moment (see `Restaurant::is_open_at`).
+    pub fn open_at(mut self, day: Day, time: Time) -> Self {
+        self.open_at = Some((day, time));
+        self
+    }
+
+    /// Restricts the filter to restaurants open at some point this weekend.
+    pub fn open_on_weekend(mut self) -> Self {
+        self.open_on_weekend = true;
+        self
+    }
+
+    /// Restricts the filter to restaurants with every one of the given tags (e.g. `"vegan"`),
+    /// ignoring case.
+    ///
+    /// # Notes
+    /// Restaurants without any tags are excluded unless `include_untagged` is also used.
+    pub fn with_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tags.extend(tags.into_iter().map(Into::into));
+        self
+    }
+
+    /// Restricts the filter to restaurants with the given tag, ignoring case (see `with_tags`).
+    pub fn with_tag<S: Into<String>>(self, tag: S) -> Self {
+        self.with_tags(Some(tag))
+    }
+
+    /// Restricts the filter to restaurants without the given tag, ignoring case.
+    pub fn without_tag<S: Into<String>>(self, tag: S) -> Self {
+        self.without_tags(Some(tag))
+    }
+
+    /// Restricts the filter to restaurants with none of the given tags (e.g. `"pizza"`), ignoring
+    /// case.
+    pub fn without_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.excluded_tags.extend(tags.into_iter().map(Into::into));
+        self
+    }
+
+    /// Restricts the filter to restaurants catering to every one of the given dietary needs.
+    ///
+    /// # Notes
+    /// Unlike tags, restaurants listing no dietary options are never let through, since the
+    /// filter exists so that everyone can eat there.
+    pub fn with_dietary<I>(mut self, options: I) -> Self
+    where
+        I: IntoIterator<Item = Dietary>,
+    {
+        self.dietary.extend(options);
+        self
+    }
+
+    /// Restricts the filter to restaurants catering to the given dietary need (see
+    /// `with_dietary`).
+    pub fn dietary(self, option: Dietary) -> Self {
+        self.with_dietary(Some(option))
+    }
+
+    /// Restricts the filter to restaurants costing at most the given price.
+    ///
+    /// # Notes
+    /// Restaurants without a price are let through, since they may well qualify.
+    pub fn max_price(mut self, price: Price) -> Self {
+        self.max_price = Some(price);
+        self
+    }
+
+    /// Restricts the filter to restaurants in the given part of campus (see `Restaurant::zone`),
+    /// ignoring case.
+    ///
+    /// # Notes
+    /// Restaurants without a zone are let through, since they may well be nearby.
+    pub fn zone<S: Into<String>>(mut self, zone: S) -> Self {
+        self.zone = Some(zone.into());
+        self
+    }
+
+    /// Lets restaurants without any tags through a tag filter, since they may still qualify.
+    pub fn include_untagged(mut self) -> Self {
+        self.include_untagged = true;
+        self
+    }
+
+    /// Restricts the filter to restaurants that aren't temporarily closed (see
+    /// `Restaurant::is_temporarily_closed`).
+    ///
+    /// Restricting the filter to restaurants open at some moment (or on the weekend) excludes
+    /// temporarily closed restaurants already.
+    pub fn exclude_temporarily_closed(mut self) -> Self {
+        self.exclude_temporarily_closed = true;
+        self
+    }
+
+    /// Returns whether the given restaurant satisfies this filter.
+    pub fn matches(&self, restaurant: &Restaurant) -> bool {
+        !(self.exclude_temporarily_closed && restaurant.is_temporarily_closed())
+            && self
+                .open_at
+                .is_none_or(|(day, time)| restaurant.is_open_at(day, time))
+            && (!self.open_on_weekend || restaurant.is_open_on_weekend())
+            && self.matches_tags(restaurant)
+            && self.dietary.iter().all(|d| restaurant.has_dietary(d))
+            && self.matches_price(restaurant)
+            && self.matches_zone(restaurant)
+    }
+
+    /// Returns whether the given restaurant satisfies the tag portion of this filter.
+    fn matches_tags(&self, restaurant: &Restaurant) -> bool {
+        if self.excluded_tags.iter().any(|tag| restaurant.has_tag(tag)) {
+            return false;
+        }
+        if self.tags.is_empty() || (self.include_untagged && restaurant.tags().is_empty()) {
+            return true;
+        }
+        self.tags.iter().all(|tag| restaurant.has_tag(tag))
+    }
+
+    /// Returns whether the given restaurant satisfies the zone portion of this filter.
+    fn matches_zone(&self, restaurant: &Restaurant) -> bool {
+        match (&self.zone, restaurant.zone()) {
+            (Some(wanted), Some(zone)) => zone.eq_ignore_ascii_case(wanted),
+            _ => true,
+        }
+    }
+
+    /// Returns whether the given restaurant satisfies the price portion of this filter.
+    fn matches_price(&self, restaurant: &Restaurant) -> bool {
+        match (self.max_price, restaurant.price()) {
+            (Some(max), Some(price)) => price <= max,
+            _ => true,
+        }
+    }
+
+    /// Returns this filter as a predicate, for use in iterator chains (e.g. with `filter` or
+    /// `partition`).
+    pub fn predicate<'a>(&'a self) -> impl Fn(&&Restaurant) -> bool + 'a {
+        move |restaurant| self.matches(restaurant)
+    }
+
+    /// Returns the restaurants that satisfy this filter, preserving their order.
+    pub fn apply<'a>(&self, restaurants: &'a [Restaurant]) -> Vec<&'a Restaurant> {
+        restaurants.iter().filter(|r| self.matches(r)).collect()
+    }
+}
+
+/// The storage key under which the preferred part of campus is kept.
+pub const ZONE_KEY: &str = "eat-ou:zone";
+
+/// Chooses the part of campus to filter restaurants by (see `RestaurantFilter::zone`).
+///
+/// A zone given in the URL's query string (e.g. `?zone=research`) is used and remembered for
+/// later visits; otherwise, the remembered zone (if any) is used. An empty zone (`?zone=`)
+/// forgets the remembered one.
+pub fn preferred_zone(query: &str, storage: &impl Storage) -> Option<String> {
+    if let Some(zone) = query::get(query, "zone") {
+        storage.set(ZONE_KEY, zone.trim());
+    }
+    storage.get(ZONE_KEY).filter(|zone| !zone.is_empty())
+}
@@ -0,0 +1,501 @@
+use day_map::DayMap;
+use schedule::{Day, Hours, Time, TimeRange};
+use std::{error::Error, fmt};
+
+/// Represents a part of a readable hours string that couldn't be understood.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportError {
+    /// The part of the string at fault (e.g. `"Fry"`).
+    pub fragment: String,
+    /// What was expected instead.
+    pub reason: &'static str,
+}
+
+impl ImportError {
+    fn new(fragment: &str, reason: &'static str) -> Self {
+        Self {
+            fragment: fragment.trim().to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "couldn't read {:?}: {}", self.fragment, self.reason)
+    }
+}
+
+impl Error for ImportError {}
+
+/// The characters separating the two ends of a range of days or times.
+const DASHES: &[char] = &['-', '–', '—'];
+
+/// Converts hours written for people, as listed by Google Maps and the like, into hours for each
+/// day.
+///
+/// Days are listed one after another, separated by semicolons or new lines, each followed by its
+/// hours (e.g. `"Mon–Fri 11 AM–2 PM, 5–9 PM; Sat 11 AM–11 PM; Sun Closed"`):
+///
+/// - Days may be written in full or abbreviated (e.g. `"Tue"` or `"Tues"`), and given as ranges
+///   (`"Mon–Fri"`) or lists (`"Sat, Sun"`).
+/// - Hours are a time range (`"11 AM–9 PM"`), several separated by commas for a day with breaks,
+///   `"Closed"`, or `"Open 24 hours"`.
+/// - Times are on a 12-hour clock, with or without minutes (`"11 AM"`, `"11:30 PM"`, `"noon"`),
+///   or on a 24-hour clock (`"17:00"`). The start of a range may leave out AM or PM if it matches
+///   the end (`"5–9 PM"`), and a range ending after midnight runs into the next day.
+///
+/// Days that aren't mentioned are closed.
+///
+/// # Examples
+/// ```
+/// use eat_ou::{from_readable_hours, Day, Hours, Time, TimeRange};
+///
+/// let hours = from_readable_hours("Mon–Fri 11 AM–2 PM, 5–9 PM; Sat 11 AM–11 PM; Sun Closed")
+///     .unwrap();
+/// assert_eq!(
+///     hours.get(Day::Tuesday),
+///     Some(&Hours::new(Time::new(11, 0), Time::new(21, 0))
+///         .with_break(TimeRange::new(Time::new(14, 0), Time::new(17, 0))))
+/// );
+/// assert_eq!(hours.get(Day::Sunday), None);
+/// ```
+///
+/// # Errors
+/// Returns `Err` naming the first fragment (e.g. a day or time) that couldn't be understood.
+pub fn from_readable_hours(s: &str) -> Result<DayMap<Hours>, ImportError> {
+    // Copied hours often use narrow or non-breaking spaces (e.g. before "AM").
+    let s = s.replace(['\u{a0}', '\u{2009}', '\u{202f}'], " ");
+    let mut map = DayMap::new();
+    for line in s.split(['\n', ';']).filter(|l| !l.trim().is_empty()) {
+        let (days, hours) = split_line(line)?;
+        let days = parse_days(days)?;
+        let hours = parse_hours(hours)?;
+        for day in days {
+            match hours {
+                Some(ref hours) => map.insert(day, hours.clone()),
+                None => map.remove(day),
+            };
+        }
+    }
+    Ok(map)
+}
+
+/// Splits a line into its days and its hours, at the first word that starts the hours.
+fn split_line(line: &str) -> Result<(&str, &str), ImportError> {
+    let starts_hours = |rest: &str| {
+        let rest = rest.to_lowercase();
+        rest.starts_with(|c: char| c.is_ascii_digit())
+            || ["closed", "open", "noon", "midnight"]
+                .iter()
+                .any(|word| rest.starts_with(word))
+    };
+    let mut previous = ' ';
+    for (index, c) in line.char_indices() {
+        if !previous.is_alphanumeric() && starts_hours(&line[index..]) {
+            let days = line[..index].trim().trim_end_matches([':', ',']);
+            if days.is_empty() {
+                return Err(ImportError::new(line, "expected days before the hours"));
+            }
+            return Ok((days, &line[index..]));
+        }
+        previous = c;
+    }
+    Err(ImportError::new(line, "expected hours after the days"))
+}
+
+/// Parses a day, written in full or abbreviated to at least three letters (e.g. `"Tue"`).
+fn parse_day(s: &str) -> Result<Day, ImportError> {
+    let lower = s.trim().trim_end_matches('.').to_lowercase();
+    Day::all()
+        .find(|day| lower.len() >= 3 && day.key().starts_with(&*lower))
+        .ok_or_else(|| ImportError::new(s, "expected a day of the week"))
+}
+
+/// Parses a list of days and ranges of days (e.g. `"Mon–Wed, Fri"`).
+fn parse_days(s: &str) -> Result<Vec<Day>, ImportError> {
+    let mut days = Vec::new();
+    for part in s.split(',') {
+        match split_range(part) {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+                let count = first.days_until(last) + 1;
+                days.extend(first.upcoming().take(count.into()));
+            }
+            None => days.push(parse_day(part)?),
+        }
+    }
+    Ok(days)
+}
+
+/// Splits a range at its dash (or `"to"`), if it has one.
+fn split_range(s: &str) -> Option<(&str, &str)> {
+    match s.find(DASHES) {
+        Some(index) => {
+            let dash = s[index..].chars().next()?.len_utf8();
+            Some((&s[..index], &s[index + dash..]))
+        }
+        None => s.find(" to ").map(|index| (&s[..index], &s[index + 4..])),
+    }
+}
+
+/// Parses a day's hours, returning `None` if it's closed.
+fn parse_hours(s: &str) -> Result<Option<Hours>, ImportError> {
+    let lower = s.trim().to_lowercase();
+    if lower == "closed" {
+        return Ok(None);
+    }
+    if lower == "open 24 hours" {
+        return Ok(Some(Hours::new(Time::new(0, 0), Time::new(24, 0))));
+    }
+    let ranges = s
+        .split(',')
+        .map(parse_range)
+        .collect::<Result<Vec<_>, _>>()?;
+    from_ranges(s, ranges).map(Some)
+}
+
+/// Combines a day's ranges of minutes past midnight into hours, with breaks between the ranges.
+fn from_ranges(s: &str, mut ranges: Vec<(u32, u32)>) -> Result<Hours, ImportError> {
+    ranges.sort_by_key(|&(start, _)| start);
+    let time = |minutes: u32| {
+        Time::try_new((minutes / 60) as i32, (minutes % 60) as i32)
+            .ok_or_else(|| ImportError::new(s, "expected hours ending by the next morning"))
+    };
+    let (start, _) = ranges[0];
+    let (_, end) = ranges[ranges.len() - 1];
+    let mut hours = Hours::new(time(start)?, time(end)?);
+    for pair in ranges.windows(2) {
+        if pair[1].0 < pair[0].1 {
+            return Err(ImportError::new(s, "expected ranges that don't overlap"));
+        }
+        hours = hours.with_break(TimeRange::new(time(pair[0].1)?, time(pair[1].0)?));
+    }
+    Ok(hours)
+}
+
+/// Whether a time was given as AM or PM.
+#[derive(Clone, Copy, PartialEq)]
+enum Meridiem {
+    Am,
+    Pm,
+}
+
+/// Parses a range of times (e.g. `"5–9 PM"`) into minutes past midnight, the end running into
+/// the next day if it's at or before the start.
+fn parse_range(s: &str) -> Result<(u32, u32), ImportError> {
+    let (start, end) = split_range(s).ok_or_else(|| {
+        ImportError::new(
+            s,
+            "expected a range of times, as in 11 AM–9 PM, or \"Closed\"",
+        )
+    })?;
+    let (start_hours, start_minutes, start_meridiem) = parse_clock(start)?;
+    let (end_hours, end_minutes, end_meridiem) = parse_clock(end)?;
+    let (start, end) = match (start_meridiem, end_meridiem) {
+        // Take "5–9 PM" to mean 5 PM, but "11–2 PM" to mean 11 AM.
+        (None, Some(meridiem)) => {
+            let end = to_minutes(end_hours, end_minutes, Some(meridiem));
+            let start = to_minutes(start_hours, start_minutes, Some(meridiem));
+            if start < end {
+                (start, end)
+            } else {
+                (
+                    to_minutes(start_hours, start_minutes, Some(Meridiem::Am)),
+                    end,
+                )
+            }
+        }
+        // Likewise, take "5 PM–9" to mean 9 PM, but "11 AM–2" to mean 2 PM.
+        (Some(meridiem), None) => {
+            let start = to_minutes(start_hours, start_minutes, Some(meridiem));
+            let end = to_minutes(end_hours, end_minutes, Some(meridiem));
+            if start < end {
+                (start, end)
+            } else {
+                (
+                    start,
+                    to_minutes(end_hours, end_minutes, Some(Meridiem::Pm)),
+                )
+            }
+        }
+        (start_meridiem, end_meridiem) => (
+            to_minutes(start_hours, start_minutes, start_meridiem),
+            to_minutes(end_hours, end_minutes, end_meridiem),
+        ),
+    };
+    Ok((start, if end <= start { end + 24 * 60 } else { end }))
+}
+
+/// Converts a time on a 12-hour clock (or a 24-hour one, without a meridiem) to minutes past
+/// midnight.
+fn to_minutes(hours: u32, minutes: u32, meridiem: Option<Meridiem>) -> u32 {
+    let hours = match meridiem {
+        Some(Meridiem::Am) => hours % 12,
+        Some(Meridiem::Pm) => hours % 12 + 12,
+        None => hours,
+    };
+    hours * 60 + minutes
+}
+
+/// Parses a time of day (e.g. `"11 AM"`, `"11:30pm"`, `"noon"`, or `"17:00"`) into its hours,
+/// minutes, and meridiem, if given.
+fn parse_clock(s: &str) -> Result<(u32, u32, Option<Meridiem>), ImportError> {
+    let invalid = || ImportError::new(s, "expected a time, as in 11 AM or 5:30 PM");
+    let compact = s
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '.')
+        .collect::<String>()
+        .to_lowercase();
+    match compact.as_str() {
+        "noon" => return Ok((12, 0, Some(Meridiem::Pm))),
+        "midnight" => return Ok((12, 0, Some(Meridiem::Am))),
+        _ => {}
+    }
+    let (time, meridiem) = if let Some(time) = compact.strip_suffix("am") {
+        (time, Some(Meridiem::Am))
+    } else if let Some(time) = compact.strip_suffix("pm") {
+        (time, Some(Meridiem::Pm))
+    } else {
+        (compact.as_str(), None)
+    };
+    let mut parts = time.splitn(2, ':');
+    let hours = parts
+        .next()
+        .and_then(|h| h.parse::<u32>().ok())
+        .ok_or_else(invalid)?;
+    let minutes = match parts.next() {
+        Some(m) if m.len() == 2 => m.parse::<u32>().map_err(|_| invalid())?,
+        Some(_) => return Err(invalid()),
+        None => 0,
+    };
+    let max_hours = if meridiem.is_some() { 12 } else { 24 };
+    if (meridiem.is_some() && hours == 0) || hours > max_hours || minutes > 59 {
+        return Err(invalid());
+    }
+    Ok((hours, minutes, meridiem))
+}
+
+/// Constructs in OpenStreetMap hours that aren't supported, and why.
+const UNSUPPORTED_OSM: &[(&str, &str)] = &[
+    ("||", "fallback rules aren't supported"),
+    ("\"", "comments aren't supported"),
+    ("PH", "public holidays aren't supported"),
+    ("SH", "school holidays aren't supported"),
+    ("week", "week numbers aren't supported"),
+    ("[", "particular weeks of the month aren't supported"),
+    ("+", "hours without a set closing time aren't supported"),
+    ("sunrise", "hours relative to the sun aren't supported"),
+    ("sunset", "hours relative to the sun aren't supported"),
+    ("dawn", "hours relative to the sun aren't supported"),
+    ("dusk", "hours relative to the sun aren't supported"),
+];
+
+/// The months of the year, as abbreviated in OpenStreetMap hours.
+const OSM_MONTHS: &[&str] = &[
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Converts hours written in OpenStreetMap's `opening_hours` syntax into hours for each day.
+///
+/// The common subset of the syntax is understood: rules separated by semicolons, each giving
+/// days (`"Mo-Fr"`, `"Sa,Su"`, or none for every day) followed by one or more time ranges
+/// (`"11:00-14:00,17:00-21:00"`) or `"off"`, as well as `"24/7"` on its own. As in
+/// OpenStreetMap, later rules replace earlier ones for the days they share, days that aren't
+/// mentioned are closed, and a range ending at or before it starts runs into the next day.
+///
+/// # Examples
+/// ```
+/// use eat_ou::{from_opening_hours, Day, Hours, Time};
+///
+/// let hours = from_opening_hours("Mo-Th 11:00-21:00; Fr-Sa 11:00-23:00; Su off").unwrap();
+/// assert_eq!(
+///     hours.get(Day::Friday),
+///     Some(&Hours::new(Time::new(11, 0), Time::new(23, 0)))
+/// );
+/// assert_eq!(hours.get(Day::Sunday), None);
+/// ```
+///
+/// # Errors
+/// Returns `Err` naming the first fragment that couldn't be understood, including constructs
+/// outside the supported subset (e.g. months or public holidays), rather than guessing at them.
+pub fn from_opening_hours(s: &str) -> Result<DayMap<Hours>, ImportError> {
+    for &(construct, reason) in UNSUPPORTED_OSM {
+        if let Some(index) = s.find(construct) {
+            let fragment = s[index..].split(';').next().unwrap_or_default();
+            return Err(ImportError::new(fragment, reason));
+        }
+    }
+    let mut map = DayMap::new();
+    for rule in s.split(';').filter(|r| !r.trim().is_empty()) {
+        let rule = rule.trim();
+        if rule == "24/7" {
+            for day in Day::all() {
+                map.insert(day, Hours::new(Time::new(0, 0), Time::new(24, 0)));
+            }
+            continue;
+        }
+        let index = rule
+            .find(|c: char| c.is_ascii_digit())
+            .or_else(|| rule.rfind(' ').map(|space| space + 1))
+            .unwrap_or(0);
+        let (days, times) = rule.split_at(index);
+        let days = if days.trim().is_empty() {
+            Day::all().collect()
+        } else {
+            parse_osm_days(days)?
+        };
+        if times.trim().is_empty() {
+            return Err(ImportError::new(rule, "expected times after the days"));
+        }
+        let hours = parse_osm_times(times)?;
+        for day in days {
+            match hours {
+                Some(ref hours) => map.insert(day, hours.clone()),
+                None => map.remove(day),
+            };
+        }
+    }
+    Ok(map)
+}
+
+/// Parses a list of days and ranges of days as written in OpenStreetMap hours (e.g.
+/// `"Mo-We,Fr"`).
+fn parse_osm_days(s: &str) -> Result<Vec<Day>, ImportError> {
+    let parse_day = |s: &str| {
+        let s = s.trim();
+        if OSM_MONTHS.iter().any(|month| s.starts_with(month)) {
+            return Err(ImportError::new(s, "months aren't supported"));
+        }
+        Day::all()
+            .find(|day| s.len() == 2 && day.abbreviation().starts_with(s))
+            .ok_or_else(|| ImportError::new(s, "expected a day of the week, as in Mo or Fr"))
+    };
+    let mut days = Vec::new();
+    for part in s.split(',') {
+        match part.find('-') {
+            Some(index) => {
+                let (first, last) = (parse_day(&part[..index])?, parse_day(&part[index + 1..])?);
+                let count = first.days_until(last) + 1;
+                days.extend(first.upcoming().take(count.into()));
+            }
+            None => days.push(parse_day(part)?),
+        }
+    }
+    Ok(days)
+}
+
+/// Parses a day's hours as written in OpenStreetMap hours (e.g. `"11:00-14:00,17:00-21:00"`),
+/// returning `None` for `"off"` or `"closed"`.
+pub(crate) fn parse_osm_times(s: &str) -> Result<Option<Hours>, ImportError> {
+    match s.trim() {
+        "off" | "closed" => Ok(None),
+        times => {
+            let ranges = times
+                .split(',')
+                .map(parse_osm_range)
+                .collect::<Result<Vec<_>, _>>()?;
+            from_ranges(times, ranges).map(Some)
+        }
+    }
+}
+
+/// Parses a range of 24-hour times as written in OpenStreetMap hours (e.g. `"17:00-02:00"`)
+/// into minutes past midnight, the end running into the next day if it's at or before the start.
+fn parse_osm_range(s: &str) -> Result<(u32, u32), ImportError> {
+    let invalid = || ImportError::new(s, "expected a range of times, as in 11:00-21:00");
+    let mut ends = s.trim().splitn(2, '-').map(|time| {
+        let mut parts = time.splitn(2, ':');
+        match (parts.next(), parts.next()) {
+            (Some(hours), Some(minutes)) if hours.len() == 2 && minutes.len() == 2 => {
+                let hours = hours.parse::<u32>().map_err(|_| invalid())?;
+                let minutes = minutes.parse::<u32>().map_err(|_| invalid())?;
+                if hours > 48 || minutes > 59 {
+                    return Err(invalid());
+                }
+                Ok(hours * 60 + minutes)
+            }
+            _ => Err(invalid()),
+        }
+    });
+    let start = ends.next().ok_or_else(invalid)??;
+    let end = ends.next().ok_or_else(invalid)??;
+    Ok((start, if end <= start { end + 24 * 60 } else { end }))
+}
+
+/// Writes hours for each day in OpenStreetMap's `opening_hours` syntax (see
+/// `from_opening_hours`), for contributing them upstream.
+///
+/// Days with the same hours are grouped together, days that are closed are left out, and hours
+/// open around the clock every day are written as `"24/7"`. Notes on the hours aren't included.
+///
+/// # Examples
+/// ```
+/// use eat_ou::{from_opening_hours, to_opening_hours};
+///
+/// let hours = from_opening_hours("Mo-Fr 11:00-14:00,17:00-21:00; Sa 11:00-23:00").unwrap();
+/// assert_eq!(
+///     to_opening_hours(&hours),
+///     "Mo-Fr 11:00-14:00,17:00-21:00; Sa 11:00-23:00"
+/// );
+/// ```
+pub fn to_opening_hours(hours: &DayMap<Hours>) -> String {
+    // OpenStreetMap weeks start on Monday.
+    let week = Day::Monday.upcoming().collect::<Vec<_>>();
+    if week
+        .iter()
+        .all(|&day| hours.get(day).is_some_and(|h| h.is_all_day()))
+    {
+        return "24/7".to_string();
+    }
+    let time = |minutes: u32| {
+        let minutes = if minutes > 24 * 60 {
+            minutes - 24 * 60
+        } else {
+            minutes
+        };
+        format!("{:02}:{:02}", minutes / 60, minutes % 60)
+    };
+    let mut rules: Vec<(Vec<usize>, String)> = Vec::new();
+    for (index, &day) in week.iter().enumerate() {
+        let times = match hours.get(day) {
+            Some(hours) => hours
+                .open_spans()
+                .into_iter()
+                .map(|(start, end)| format!("{}-{}", time(start), time(end)))
+                .collect::<Vec<_>>()
+                .join(","),
+            None => continue,
+        };
+        match rules.iter_mut().find(|(_, t)| *t == times) {
+            Some((days, _)) => days.push(index),
+            None => rules.push((vec![index], times)),
+        }
+    }
+    rules
+        .into_iter()
+        .map(|(days, times)| {
+            let mut runs: Vec<(usize, usize)> = Vec::new();
+            for index in days {
+                match runs.last_mut() {
+                    Some(run) if run.1 + 1 == index => run.1 = index,
+                    _ => runs.push((index, index)),
+                }
+            }
+            let short = |index: usize| &week[index].abbreviation()[..2];
+            let days = runs
+                .into_iter()
+                .map(|(first, last)| {
+                    if first == last {
+                        short(first).to_string()
+                    } else {
+                        format!("{}-{}", short(first), short(last))
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("{} {}", days, times)
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
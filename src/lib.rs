@@ -4,19 +4,85 @@
 #[macro_use]
 extern crate serde_derive;
 extern crate serde;
+#[cfg(feature = "toml-data")]
+extern crate toml;
 
 #[macro_use]
 extern crate stdweb;
 
 mod schedule;
-pub use schedule::{Day, Restaurant, Time};
+pub use schedule::{
+    format_iso8601_minutes, get_viable_at, open_counts_by_hour, search, slugify, soonest_opening,
+    viable_now, Date, DateRange, Day, FromStrError, Hours, HoursFromStrError, Restaurant, Time,
+    TimeRange, Viability, ViabilityOptions, CLOSING_SOON_THRESHOLD, DEFAULT_MIN_REMAINING,
+    DEFAULT_TRAVEL_TIME, DEFAULT_WEIGHT,
+};
+
+mod builder;
+pub use builder::{BuildError, RestaurantBuilder};
+
+mod clock;
+pub use clock::{BrowserClock, Clock, FixedClock};
+
+mod data;
+pub use data::{
+    fetch_restaurants, load, load_extra_restaurants, merge_restaurants, parse_validated, DataError,
+    Dataset, DATA_VERSION, EXTRA_RESTAURANTS_KEY, FETCH_TIMEOUT,
+};
+
+mod day_map;
+pub use day_map::DayMap;
+
+mod dietary;
+pub use dietary::Dietary;
+
+mod export;
+pub use export::{export_viable, ExportedRestaurant};
+
+mod filter;
+pub use filter::{preferred_zone, RestaurantFilter, ZONE_KEY};
+
+mod import;
+pub use import::{from_opening_hours, from_readable_hours, to_opening_hours, ImportError};
+
+mod listing;
+pub use listing::{
+    filter_by_name, group_by_availability, group_by_cuisine, Availability, Listing, CLOSED_TODAY,
+    OPENS_LATER, OPEN_NOW,
+};
+
+mod location;
+pub use location::{compare_by_distance, Coordinates};
+
+mod price;
+pub use price::Price;
+
+mod storage;
+pub use storage::{LocalStorage, MemoryStorage, Storage};
+
+mod theme;
+pub use theme::{ParseThemeError, Theme, THEME_KEY};
+
+mod validation;
+pub use validation::{validate, Severity, ValidationIssue};
+
+/// Parses URL query strings.
+pub mod query;
 
 /// Manages the application user interface.
 pub mod ui;
 
+/// Drives the suggestion cycle independently of the DOM.
+pub mod app;
+
+/// Orders restaurants for display.
+pub mod sort;
+pub use sort::SortBy;
+
 extern crate serde_json;
 
 #[test]
 fn test_json() {
-    let _: Vec<Restaurant> = serde_json::from_str(include_str!("../food.json")).unwrap();
+    let restaurants = Restaurant::get_list().unwrap();
+    assert!(!restaurants.is_empty());
 }
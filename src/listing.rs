@@ -0,0 +1,170 @@
+use price::Price;
+use schedule::{name_matches, Day, Restaurant, Time};
+use sort::by_name;
+
+/// Describes a restaurant as shown in the list view.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Listing {
+    /// The restaurant's identifier (see `Restaurant::id`), telling apart restaurants sharing a
+    /// name.
+    pub id: String,
+    /// The restaurant's name.
+    pub name: String,
+    /// The restaurant's hours today, formatted for display (see `Restaurant::describe_hours`).
+    pub hours: String,
+    /// Whether the restaurant is open right now.
+    pub open: bool,
+    /// The kind of food the restaurant serves (e.g. `"Mexican"`), if known.
+    pub cuisine: Option<String>,
+    /// How expensive the restaurant is, if known.
+    pub price: Option<Price>,
+    /// Whether the restaurant is closed until further notice, and so shown greyed out.
+    pub temporarily_closed: bool,
+    /// The note shown alongside the restaurant (e.g. `"Cash only"`), in full, if any.
+    ///
+    /// Temporarily closed restaurants give their note in place of their hours instead.
+    pub note: Option<String>,
+    /// The address of the restaurant's website, if known, to link its name to.
+    pub url: Option<String>,
+    /// The restaurant's phone number, if known.
+    pub phone: Option<String>,
+}
+
+impl Listing {
+    /// Describes the given restaurant with the given hours (already formatted for display) and
+    /// whether it's open right now.
+    pub fn new(restaurant: &Restaurant, hours: String, open: bool) -> Self {
+        Self {
+            id: restaurant.id().into(),
+            name: restaurant.name.clone(),
+            hours,
+            open,
+            cuisine: restaurant.cuisine().map(String::from),
+            price: restaurant.price(),
+            temporarily_closed: restaurant.is_temporarily_closed(),
+            note: restaurant
+                .note()
+                .filter(|_| !restaurant.is_temporarily_closed())
+                .map(String::from),
+            url: restaurant.url().map(String::from),
+            phone: restaurant.phone().map(String::from),
+        }
+    }
+}
+
+/// The heading for listings without a cuisine.
+const OTHER: &str = "Other";
+
+/// Groups listings by cuisine, for display under section headers.
+///
+/// Groups are ordered by cuisine name, with listings lacking a cuisine grouped under "Other" at
+/// the end. Listings keep their relative order within each group.
+pub fn group_by_cuisine(listings: Vec<Listing>) -> Vec<(String, Vec<Listing>)> {
+    let mut groups: Vec<(String, Vec<Listing>)> = Vec::new();
+    let mut other = Vec::new();
+    for listing in listings {
+        let cuisine = match listing.cuisine {
+            Some(ref cuisine) => cuisine.clone(),
+            None => {
+                other.push(listing);
+                continue;
+            }
+        };
+        match groups.iter_mut().find(|(name, _)| *name == cuisine) {
+            Some((_, group)) => group.push(listing),
+            None => groups.push((cuisine, vec![listing])),
+        }
+    }
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
+    if !other.is_empty() {
+        groups.push((OTHER.into(), other));
+    }
+    groups
+}
+
+/// Narrows listings to those whose names contain the given query, ignoring case and surrounding
+/// whitespace.
+///
+/// An empty query matches every listing. Listings don't carry aliases; to match those too,
+/// filter the restaurants with `Restaurant::matches_name` before listing them.
+pub fn filter_by_name(listings: &[Listing], query: &str) -> Vec<Listing> {
+    listings
+        .iter()
+        .filter(|listing| name_matches(&listing.name, query))
+        .cloned()
+        .collect()
+}
+
+/// The heading for restaurants open at the moment of listing.
+pub const OPEN_NOW: &str = "Open now";
+/// The heading for restaurants opening later on the day of listing.
+pub const OPENS_LATER: &str = "Opens later today";
+/// The heading for restaurants with no hours left on the day of listing.
+pub const CLOSED_TODAY: &str = "Closed today";
+
+/// Restaurants grouped by whether they're open at a given moment, for display under section
+/// headers.
+pub struct Availability<'a> {
+    /// The restaurants open at that moment, soonest closing first (with those open 24 hours
+    /// last).
+    pub open_now: Vec<&'a Restaurant>,
+    /// The restaurants opening later that day, with when they open, earliest first.
+    pub opens_later: Vec<(&'a Restaurant, Time)>,
+    /// The restaurants with no hours left that day, alphabetically.
+    pub closed_today: Vec<&'a Restaurant>,
+}
+
+impl<'a> Availability<'a> {
+    /// Returns the groups that have any restaurants, under their headings, in the order they're
+    /// shown.
+    pub fn sections(&self) -> Vec<(&'static str, Vec<&'a Restaurant>)> {
+        let sections = vec![
+            (OPEN_NOW, self.open_now.clone()),
+            (
+                OPENS_LATER,
+                self.opens_later.iter().map(|&(r, _)| r).collect(),
+            ),
+            (CLOSED_TODAY, self.closed_today.clone()),
+        ];
+        sections
+            .into_iter()
+            .filter(|(_, restaurants)| !restaurants.is_empty())
+            .collect()
+    }
+}
+
+/// Groups restaurants by whether they're open at the given moment (see `Availability`).
+///
+/// A restaurant on a break counts as opening later, when its break ends. Ties within the first
+/// two groups keep the order the restaurants are given in, so a caller can break them (e.g. by
+/// distance) by sorting the restaurants first.
+pub fn group_by_availability<'a, I>(restaurants: I, day: Day, time: Time) -> Availability<'a>
+where
+    I: IntoIterator<Item = &'a Restaurant>,
+{
+    let mut availability = Availability {
+        open_now: Vec::new(),
+        opens_later: Vec::new(),
+        closed_today: Vec::new(),
+    };
+    for restaurant in restaurants {
+        if restaurant.is_open_at(day, time) {
+            availability.open_now.push(restaurant);
+        } else if let Some(opens) = restaurant.opens_later(day, time) {
+            availability.opens_later.push((restaurant, opens));
+        } else {
+            availability.closed_today.push(restaurant);
+        }
+    }
+    availability
+        .open_now
+        .sort_by_key(|r| match r.closes_in(day, time) {
+            Some(Some(closes_in)) => closes_in.as_secs(),
+            _ => u64::MAX,
+        });
+    availability
+        .opens_later
+        .sort_by_key(|&(_, opens)| opens.total_minutes());
+    availability.closed_today.sort_by(|a, b| by_name()(a, b));
+    availability
+}
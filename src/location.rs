@@ -0,0 +1,50 @@
+use schedule::Restaurant;
+use sort::by_name;
+use std::cmp::Ordering;
+
+/// The mean radius of the Earth, in kilometers.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Represents a point on the Earth's surface, in degrees.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Coordinates {
+    /// Creates a new set of coordinates from the given latitude and longitude (in degrees).
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Returns the great-circle distance to the given coordinates, in kilometers.
+    ///
+    /// # Notes
+    /// Uses the haversine formula, which treats the Earth as a sphere; the error is well under a
+    /// percent, which is plenty for comparing walking distances.
+    pub fn distance_km(self, other: Coordinates) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (other.longitude - self.longitude).to_radians();
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+    }
+}
+
+/// Compares two restaurants by their distance from the given coordinates, nearest first.
+///
+/// Restaurants without coordinates sort after those with them; ties (including two restaurants
+/// without coordinates) are broken by name (see `sort::by_name`).
+pub fn compare_by_distance(a: &Restaurant, b: &Restaurant, from: Coordinates) -> Ordering {
+    match (a.distance_km(from), b.distance_km(from)) {
+        (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+    .then_with(|| by_name()(a, b))
+}
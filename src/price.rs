@@ -0,0 +1,102 @@
+use serde::de::{Deserialize, Deserializer, Error as DeserializationError, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+/// The highest valid price level.
+const MAX_LEVEL: u8 = 4;
+
+/// Represents how expensive a restaurant is, from 1 (`$`) to 4 (`$$$$`).
+///
+/// Deserializes from either the level as an integer (e.g. `2`) or as dollar signs (e.g. `"$$"`),
+/// and serializes as the former.
+///
+/// # Notes
+/// Deserialization accepts any level, so that an out-of-range price is reported by `validate`
+/// (naming the restaurant) rather than preventing the data from loading.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Price(u8);
+
+impl Price {
+    /// Creates a price with the given level, if it's in range (1–4).
+    pub fn new(level: u8) -> Option<Self> {
+        if (1..=MAX_LEVEL).contains(&level) {
+            Some(Price(level))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the level of this price, from 1 (`$`) to 4 (`$$$$`).
+    pub fn level(self) -> u8 {
+        self.0
+    }
+
+    /// Returns whether this price's level is in range (1–4).
+    pub(crate) fn is_valid(self) -> bool {
+        Price::new(self.0).is_some()
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", "$".repeat(usize::from(self.0)))
+    }
+}
+
+impl Serialize for Price {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Price {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(PriceVisitor)
+    }
+}
+
+struct PriceVisitor;
+
+impl<'de> Visitor<'de> for PriceVisitor {
+    type Value = Price;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a price level from 1 to 4, or \"$\" through \"$$$$\"")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: DeserializationError,
+    {
+        if value > u64::from(u8::MAX) {
+            return Err(E::invalid_value(Unexpected::Unsigned(value), &self));
+        }
+        Ok(Price(value as u8))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: DeserializationError,
+    {
+        if value < 0 {
+            return Err(E::invalid_value(Unexpected::Signed(value), &self));
+        }
+        self.visit_u64(value as u64)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeserializationError,
+    {
+        if value.is_empty() || value.len() > usize::from(u8::MAX) || value.contains(|c| c != '$') {
+            return Err(E::invalid_value(Unexpected::Str(value), &self));
+        }
+        Ok(Price(value.len() as u8))
+    }
+}
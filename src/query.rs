@@ -0,0 +1,43 @@
+use schedule::{Day, Time};
+
+/// Looks up the value of the given key in a URL query string (e.g. `location.search`).
+///
+/// A leading `?` is ignored. Keys present without a value (e.g. `?weekend`) yield an empty
+/// string.
+pub fn get<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .map(|pair| {
+            let mut parts = pair.splitn(2, '=');
+            (
+                parts.next().unwrap_or_default(),
+                parts.next().unwrap_or_default(),
+            )
+        })
+        .find(|&(k, _)| k == key)
+        .map(|(_, v)| v)
+}
+
+/// Parses a day and time override (e.g. `?day=friday&time=23:30`) from a URL query string.
+///
+/// Returns `None` unless both parameters are present and valid.
+pub fn day_and_time(query: &str) -> Option<(Day, Time)> {
+    let day = get(query, "day")?.parse().ok()?;
+    let time = get(query, "time")?
+        .replace("%3A", ":")
+        .replace("%3a", ":")
+        .parse()
+        .ok()?;
+    Some((day, time))
+}
+
+/// Gets the comma-separated values of the given key (e.g. `?tags=vegan,halal`) from a URL
+/// query string.
+///
+/// Empty values are skipped, so a missing key yields an empty list.
+pub fn get_list<'a>(query: &'a str, key: &str) -> Vec<&'a str> {
+    get(query, key)
+        .map(|value| value.split(',').filter(|v| !v.is_empty()).collect())
+        .unwrap_or_default()
+}
@@ -1,71 +1,205 @@
+use clock::{Clock, FixedClock};
+#[cfg(feature = "toml-data")]
+use data::load_toml;
+use data::{load, load_csv, load_value, parse_entries, DataError};
+use day_map::{DayMap, DayMapSpec};
+use dietary::Dietary;
+use import::to_opening_hours;
+use location::Coordinates;
+use price::Price;
+use serde::de::value::MapAccessDeserializer;
 use serde::de::Error as DeserializationError;
-use serde::de::{Deserialize, Deserializer};
+use serde::de::{Deserialize, Deserializer, MapAccess, Unexpected, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use sort::by_name;
 use std::{
     cmp::{Ordering, PartialOrd},
+    collections::{HashMap, HashSet},
     error::Error,
     fmt,
+    hash::{Hash, Hasher},
+    io::Read,
+    iter,
     ops::{Add, Sub},
     str::FromStr,
+    time::Duration,
 };
 
 /// Represents a low-resolution point in time, relative to midnight.
-#[derive(Clone, Copy, Deserialize, PartialEq)]
+///
+/// Serializes as a string of hours and minutes (e.g. `"26:30"`), as times are written in the
+/// restaurant data.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
 pub struct Time {
     hours: u8,
     #[serde(default)]
     minutes: u8,
 }
 
+/// The latest hour a `Time` may have.
+///
+/// Hours 24–47 represent times on the following day (see `MINUTES_PER_CYCLE`).
+const MAX_HOURS: i32 = 47;
+
 impl Time {
     /// Creates a new `Time` with the given hours and minutes past midnight.
+    ///
+    /// # Panics
+    /// Panics if `hours` is outside 0–47 or `minutes` is outside 0–59. Use `try_new` to handle
+    /// out-of-range values gracefully.
     pub fn new(hours: i32, minutes: i32) -> Self {
-        Self {
+        Self::try_new(hours, minutes).expect("time out of range")
+    }
+
+    /// Creates a new `Time` with the given hours and minutes past midnight, if they're in range.
+    ///
+    /// Returns `None` if `hours` is outside 0–47 or `minutes` is outside 0–59.
+    pub fn try_new(hours: i32, minutes: i32) -> Option<Self> {
+        if !(0..=MAX_HOURS).contains(&hours) || !(0..60).contains(&minutes) {
+            return None;
+        }
+        Some(Self {
             hours: hours as u8,
             minutes: minutes as u8,
-        }
+        })
     }
 
     /// Creates a new `Time` with the given hours past midnight.
+    ///
+    /// # Panics
+    /// Panics if `hours` is greater than 47.
     pub fn with_hours(hours: u8) -> Self {
-        Self { hours, minutes: 0 }
+        Self::new(i32::from(hours), 0)
+    }
+
+    /// Rounds this time to the nearest multiple of `step` minutes past midnight, carrying into the
+    /// hours as needed (e.g. 9:50 rounds to 10:00 with a step of 30).
+    ///
+    /// # Notes
+    /// Times exactly halfway between two multiples round up. Rounding past 47:59 wraps around
+    /// (see `MINUTES_PER_CYCLE`), and a `step` of zero leaves the time unchanged.
+    pub fn round_to(self, step: u8) -> Time {
+        if step == 0 {
+            return self;
+        }
+        let step = u32::from(step);
+        let rounded = (self.total_minutes() + step / 2) / step * step;
+        Time::with_hours(0).plus_minutes(rounded)
+    }
+
+    /// Returns this time relative to the previous day (i.e. 24 hours later), if it falls before
+    /// midnight.
+    fn as_next_day(self) -> Option<Time> {
+        if self.hours < 24 {
+            Some(Time {
+                hours: self.hours + 24,
+                minutes: self.minutes,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of minutes past midnight.
+    pub(crate) fn total_minutes(self) -> u32 {
+        u32::from(self.hours) * 60 + u32::from(self.minutes)
+    }
+
+    /// Adds minutes to this time, wrapping around modulo 48 hours (see `MINUTES_PER_CYCLE`).
+    fn plus_minutes(self, minutes: u32) -> Time {
+        let total = (self.total_minutes() + minutes % MINUTES_PER_CYCLE) % MINUTES_PER_CYCLE;
+        Time {
+            hours: (total / 60) as u8,
+            minutes: (total % 60) as u8,
+        }
     }
 }
 
 /// Represents an error encountered while converting from a string to a `Time`.
 #[derive(Debug)]
 pub enum FromStrError {
-    /// No colon was present in the string.
+    /// No colon was present in the string, and it wasn't a compact time (e.g. `"0930"`) either.
     MissingColon,
+    /// The string was all digits, but not the three or four of a compact time (e.g. `"99"`).
+    MalformedCompact,
     /// After splitting on colons, too few (<2) components were present.
     InsufficientComponents,
     /// After splitting on colons, too many (>2) components were present.
     ///
     /// This is likely due to multiple colons.
     ExtraComponents,
+    /// The hours were outside the supported range (0–47).
+    HoursOutOfRange,
+    /// The minutes were outside the supported range (0–59).
+    MinutesOutOfRange,
+    /// A component wasn't a (non-negative) number.
+    InvalidNumber,
     /// Another error occurred.
     Generic,
 }
 
 impl fmt::Display for FromStrError {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(f, "Invalid time string.")
+        match *self {
+            FromStrError::MissingColon => {
+                write!(
+                    f,
+                    "expected hours and minutes separated by a colon, as in 9:30"
+                )
+            }
+            FromStrError::MalformedCompact => {
+                write!(
+                    f,
+                    "expected three or four digits without a colon, as in 0930"
+                )
+            }
+            FromStrError::InsufficientComponents => write!(f, "expected both hours and minutes"),
+            FromStrError::ExtraComponents => write!(f, "expected only hours and minutes"),
+            FromStrError::HoursOutOfRange => {
+                write!(f, "hours must be between 0 and {}", MAX_HOURS)
+            }
+            FromStrError::MinutesOutOfRange => write!(f, "minutes must be between 0 and 59"),
+            FromStrError::InvalidNumber => write!(f, "hours and minutes must be numbers"),
+            FromStrError::Generic => write!(f, "invalid time string"),
+        }
     }
 }
 
 impl Error for FromStrError {}
 
+/// Splits a compact time (e.g. `"0930"` or `"930"`) into its hours and minutes, the minutes
+/// being the last two digits.
+fn split_compact(s: &str) -> Result<Vec<u8>, FromStrError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(FromStrError::MissingColon);
+    }
+    if !(3..=4).contains(&s.len()) {
+        return Err(FromStrError::MalformedCompact);
+    }
+    let (hours, minutes) = s.split_at(s.len() - 2);
+    [hours, minutes]
+        .iter()
+        .map(|c| c.parse::<u8>().map_err(|_| FromStrError::InvalidNumber))
+        .collect()
+}
+
+/// Parses a time written as hours and minutes, either separated by a colon (e.g. `"9:30"`) or
+/// in compact form (e.g. `"0930"`, `"930"`).
 impl FromStr for Time {
     type Err = FromStrError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !s.contains(':') {
-            return Err(FromStrError::MissingColon);
-        }
-        let parts = s
-            .split(':')
-            .map(|c| c.parse::<u8>().unwrap_or_default())
-            .collect::<Vec<u8>>();
+        let parts = if s.contains(':') {
+            s.split(':')
+                .map(|c| c.trim().parse::<u8>())
+                .collect::<Result<Vec<u8>, _>>()
+                .map_err(|_| FromStrError::InvalidNumber)?
+        } else {
+            split_compact(s.trim())?
+        };
         match parts.len() {
             0..2 => Err(FromStrError::InsufficientComponents),
+            2 if i32::from(parts[0]) > MAX_HOURS => Err(FromStrError::HoursOutOfRange),
+            2 if parts[1] > 59 => Err(FromStrError::MinutesOutOfRange),
             2 => Ok(Self {
                 hours: parts[0],
                 minutes: parts[1],
@@ -75,36 +209,81 @@ impl FromStr for Time {
     }
 }
 
+impl Serialize for Time {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&format_args!("{}:{:02}", self.hours, self.minutes))
+    }
+}
+
 fn deserialize_time<'de, D>(deserializer: D) -> Result<Time, D::Error>
 where
     D: Deserializer<'de>,
 {
     let s = String::deserialize(deserializer)?;
-    Time::from_str(&s).map_err(DeserializationError::custom)
+    Time::from_str(&s)
+        .map_err(|e| DeserializationError::custom(format!("invalid time {:?}: {}", s, e)))
+}
+
+/// Deserializes the start of a span of time, noting the field in any error.
+fn deserialize_start<'de, D>(deserializer: D) -> Result<Time, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_time(deserializer)
+        .map_err(|e| DeserializationError::custom(format!("start: {}", e)))
 }
 
+/// Deserializes the end of a span of time, noting the field in any error.
+fn deserialize_end<'de, D>(deserializer: D) -> Result<Time, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_time(deserializer).map_err(|e| DeserializationError::custom(format!("end: {}", e)))
+}
+
+/// The number of minutes in the 48-hour cycle times are expressed in.
+///
+/// Times past midnight may be written as hours 24–47 (e.g. `"26:00"` for 2 AM) so that overnight
+/// hours can be expressed relative to the day they began.
+const MINUTES_PER_CYCLE: u32 = 48 * 60;
+
+/// Adds minutes to a time, wrapping around modulo 48 hours (see `MINUTES_PER_CYCLE`).
 impl Add<u8> for Time {
     type Output = Time;
     fn add(self: Time, rhs: u8) -> Self::Output {
-        let mut minutes = self.minutes + rhs;
-        let mut hours = self.hours;
-        if minutes > 60 {
-            hours += 1;
-            minutes -= 60;
-        }
-        if hours > 47 {
-            hours -= 48;
-        }
-        Time { hours, minutes }
+        self.plus_minutes(u32::from(rhs))
+    }
+}
+
+/// Adds a duration to a time, ignoring any partial minute and wrapping around modulo 48 hours.
+impl Add<Duration> for Time {
+    type Output = Time;
+    fn add(self: Time, rhs: Duration) -> Self::Output {
+        let minutes = rhs.as_secs() / 60 % u64::from(MINUTES_PER_CYCLE);
+        self.plus_minutes(minutes as u32)
+    }
+}
+
+/// Subtracts minutes from a time, wrapping around modulo 48 hours like `Add<u8>` (so `0:05` less
+/// 10 minutes is `47:55`). Adding and then subtracting the same minutes gives back the original
+/// time.
+impl Sub<u8> for Time {
+    type Output = Time;
+    fn sub(self: Time, rhs: u8) -> Self::Output {
+        self.plus_minutes(MINUTES_PER_CYCLE - u32::from(rhs))
     }
 }
 
+/// Returns the minutes from one time to another, wrapping around modulo 48 hours (see
+/// `MINUTES_PER_CYCLE`) if the second is later.
 impl Sub<Time> for Time {
     type Output = usize;
     fn sub(self: Time, rhs: Time) -> Self::Output {
-        let minutes = self.minutes - rhs.minutes;
-        let hours = self.hours - rhs.hours;
-        (hours as usize) * 60 + (minutes as usize)
+        let minutes = self.total_minutes() + MINUTES_PER_CYCLE - rhs.total_minutes();
+        (minutes % MINUTES_PER_CYCLE) as usize
     }
 }
 
@@ -151,7 +330,7 @@ impl fmt::Display for Time {
 }
 
 /// Represents a day of the week.
-#[derive(Clone, Copy, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Day {
     Sunday,
     Monday,
@@ -162,6 +341,60 @@ pub enum Day {
     Saturday,
 }
 
+impl Day {
+    /// Iterates over the days of the week, in order from Sunday to Saturday.
+    pub fn all() -> impl Iterator<Item = Day> {
+        (0..7).map(Day::from)
+    }
+
+    /// Returns the lowercase name of the day, as used for keys in the restaurant data.
+    pub fn key(self) -> &'static str {
+        match self {
+            Day::Sunday => "sunday",
+            Day::Monday => "monday",
+            Day::Tuesday => "tuesday",
+            Day::Wednesday => "wednesday",
+            Day::Thursday => "thursday",
+            Day::Friday => "friday",
+            Day::Saturday => "saturday",
+        }
+    }
+
+    /// Returns the three-letter abbreviation of the day (e.g. `"Mon"`).
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Day::Sunday => "Sun",
+            Day::Monday => "Mon",
+            Day::Tuesday => "Tue",
+            Day::Wednesday => "Wed",
+            Day::Thursday => "Thu",
+            Day::Friday => "Fri",
+            Day::Saturday => "Sat",
+        }
+    }
+
+    /// Returns the number of days from this day until the next occurrence of `other` (zero if
+    /// they're the same day).
+    pub fn days_until(self, other: Day) -> u8 {
+        (other as u8 + 7 - self as u8) % 7
+    }
+
+    /// Iterates over the coming week, starting with this day.
+    pub fn upcoming(self) -> impl Iterator<Item = Day> {
+        (0..7).map(move |n| self + n)
+    }
+
+    /// Returns whether this day falls on the weekend (Saturday or Sunday).
+    pub fn is_weekend(self) -> bool {
+        matches!(self, Day::Saturday | Day::Sunday)
+    }
+
+    /// Returns whether this day is a weekday (Monday through Friday).
+    pub fn is_weekday(self) -> bool {
+        !self.is_weekend()
+    }
+}
+
 impl From<i32> for Day {
     fn from(index: i32) -> Day {
         match index {
@@ -176,77 +409,2077 @@ impl From<i32> for Day {
     }
 }
 
-#[derive(Deserialize, Clone)]
-struct HoursMap {
-    sunday: Option<Hours>,
-    monday: Option<Hours>,
-    tuesday: Option<Hours>,
-    wednesday: Option<Hours>,
-    thursday: Option<Hours>,
-    friday: Option<Hours>,
-    saturday: Option<Hours>,
+/// Describes the forms accepted when parsing or deserializing a `Day`.
+const DAY_FORMS: &str =
+    "an integer 0–6 (Sunday = 0), a full day name, or a three-letter abbreviation";
+
+/// Represents an error encountered while converting from a string to a `Day`.
+#[derive(Debug)]
+pub struct ParseDayError(String);
+
+impl fmt::Display for ParseDayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Invalid day \"{}\"; expected {}.", self.0, DAY_FORMS)
+    }
+}
+
+impl Error for ParseDayError {}
+
+impl FromStr for Day {
+    type Err = ParseDayError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_lowercase();
+        if let Ok(index) = lower.parse::<u8>() {
+            return match index {
+                0..=6 => Ok(Day::from(i32::from(index))),
+                _ => Err(ParseDayError(s.to_string())),
+            };
+        }
+        Day::all()
+            .find(|day| lower == day.key() || (lower.len() == 3 && day.key().starts_with(&*lower)))
+            .ok_or_else(|| ParseDayError(s.to_string()))
+    }
+}
+
+impl Serialize for Day {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DayVisitor)
+    }
+}
+
+struct DayVisitor;
+
+impl<'de> Visitor<'de> for DayVisitor {
+    type Value = Day;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", DAY_FORMS)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: DeserializationError,
+    {
+        match value {
+            0..=6 => Ok(Day::from(value as i32)),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+        }
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: DeserializationError,
+    {
+        match value {
+            0..=6 => Ok(Day::from(value as i32)),
+            _ => Err(E::invalid_value(Unexpected::Signed(value), &self)),
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeserializationError,
+    {
+        Day::from_str(value).map_err(E::custom)
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Add<u8> for Day {
+    type Output = Day;
+    fn add(self, rhs: u8) -> Self::Output {
+        Day::from(((self as u32 + u32::from(rhs)) % 7) as i32)
+    }
+}
+
+/// Represents a calendar date, for schedule exceptions such as holidays.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a new date from the given year, month (1–12), and day of the month.
+    ///
+    /// # Panics
+    /// Panics if the month or day is out of range; see `try_new` for a non-panicking version.
+    pub fn new(year: u16, month: u8, day: u8) -> Self {
+        Self::try_new(year, month, day).expect("Date out of range.")
+    }
+
+    /// Creates a new date from the given year, month (1–12), and day of the month, or returns
+    /// `None` if no such date exists.
+    pub fn try_new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(Self { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the day before this one.
+    pub fn previous(self) -> Self {
+        match (self.month, self.day) {
+            (1, 1) => Self {
+                year: self.year - 1,
+                month: 12,
+                day: 31,
+            },
+            (month, 1) => Self {
+                year: self.year,
+                month: month - 1,
+                day: days_in_month(self.year, month - 1),
+            },
+            (month, day) => Self {
+                year: self.year,
+                month,
+                day: day - 1,
+            },
+        }
+    }
+
+    /// Returns the day after this one.
+    pub fn next(self) -> Self {
+        match (self.month, self.day) {
+            (12, 31) => Self {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+            },
+            (month, day) if day == days_in_month(self.year, month) => Self {
+                year: self.year,
+                month: month + 1,
+                day: 1,
+            },
+            (month, day) => Self {
+                year: self.year,
+                month,
+                day: day + 1,
+            },
+        }
+    }
+}
+
+/// Returns the number of days in the given month (1–12) of the given year.
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Represents an error encountered while converting from a string to a `Date`.
+#[derive(Debug)]
+pub struct ParseDateError(String);
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Invalid date \"{}\"; expected YYYY-MM-DD.", self.0)
+    }
+}
+
+impl Error for ParseDateError {}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.trim().split('-').collect::<Vec<_>>();
+        match parts.as_slice() {
+            [year, month, day] => match (year.parse(), month.parse(), day.parse()) {
+                (Ok(year), Ok(month), Ok(day)) => Date::try_new(year, month, day),
+                _ => None,
+            },
+            _ => None,
+        }
+        .ok_or_else(|| ParseDateError(s.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Date::from_str(&s).map_err(DeserializationError::custom)
+    }
+}
+
+impl Serialize for Date {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
 }
 
+pub(crate) type HoursMap = DayMap<Hours>;
+
 /// Represents the times that a business is open.
-#[derive(Deserialize, Clone, Copy)]
+///
+/// A business may close for breaks (e.g. between lunch and dinner service) partway through its
+/// hours, and the hours may carry a note about the day (e.g. `"Kitchen closes early"`).
+///
+/// In the data, hours are an object with a `"start"` and `"end"` (and optionally `"breaks"` and
+/// a `"note"`), or a string parsed as by `Hours::from_str` (e.g. `"9:00-17:00"`).
+#[derive(Serialize, Clone, Debug, PartialEq)]
 pub struct Hours {
-    #[serde(deserialize_with = "deserialize_time")]
     start: Time,
-    #[serde(deserialize_with = "deserialize_time")]
     end: Time,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    breaks: Vec<TimeRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// `Hours` as written in the data in object form.
+#[derive(Deserialize)]
+struct RawHours {
+    #[serde(deserialize_with = "deserialize_start")]
+    start: Time,
+    #[serde(deserialize_with = "deserialize_end")]
+    end: Time,
+    #[serde(default)]
+    breaks: Vec<TimeRange>,
+    #[serde(default)]
+    note: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for Hours {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(HoursVisitor)
+    }
+}
+
+struct HoursVisitor;
+
+impl<'de> Visitor<'de> for HoursVisitor {
+    type Value = Hours;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "an object with a start and end, or a string such as \"9:00-17:00\""
+        )
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: DeserializationError,
+    {
+        value
+            .parse()
+            .map_err(|e| E::custom(format!("invalid hours {:?}: {}", value, e)))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let raw = RawHours::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(Hours {
+            start: raw.start,
+            end: raw.end,
+            breaks: raw.breaks,
+            note: raw.note,
+        })
+    }
 }
 
 impl fmt::Display for Hours {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         let (start, end) = (format!("{}", self.start), format!("{}", self.end));
-        if start == end {
-            write!(f, "Open 24 hours")
+        // Only hours from midnight are written as "Open 24 hours", which parses back as such.
+        if start == end && self.start.total_minutes() == 0 {
+            write!(f, "Open 24 hours")?;
         } else {
-            write!(f, "{}–{}", start, end)
+            write!(f, "{}–{}", start, end)?;
+        }
+        if !self.breaks.is_empty() {
+            let breaks = self
+                .breaks
+                .iter()
+                .map(|b| format!("{}–{}", b.start, b.end))
+                .collect::<Vec<_>>();
+            write!(f, " (closed {})", breaks.join(", "))?;
         }
+        Ok(())
     }
 }
 
-/// Encapsulates a restaurant/business and its hours.
-#[derive(Deserialize, Clone)]
-pub struct Restaurant {
-    pub name: String,
-    hours: HoursMap,
+/// Represents an error encountered while converting from a string to `Hours`.
+#[derive(Debug)]
+pub enum HoursFromStrError {
+    /// No dash (`-` or `–`) separated a start from an end.
+    MissingSeparator,
+    /// The given time couldn't be parsed.
+    InvalidTime(String, FromStrError),
+    /// The given time had AM or PM, but hours outside 1–12.
+    ClockHoursOutOfRange(String),
+    /// Text following the hours wasn't a list of breaks, as `Display` writes them.
+    MalformedBreaks,
 }
 
-impl Restaurant {
-    /// Gets the static list of all restaurants.
-    pub fn get_list() -> Vec<Self> {
-        serde_json::from_str(include_str!("../food.json")).unwrap_or_default()
+impl fmt::Display for HoursFromStrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            HoursFromStrError::MissingSeparator => write!(
+                f,
+                "expected a start and end separated by a dash, as in 9:00-17:00"
+            ),
+            HoursFromStrError::InvalidTime(ref time, ref e) => {
+                write!(f, "invalid time {:?}: {}", time, e)
+            }
+            HoursFromStrError::ClockHoursOutOfRange(ref time) => write!(
+                f,
+                "invalid time {:?}: hours must be between 1 and 12 with AM or PM",
+                time
+            ),
+            HoursFromStrError::MalformedBreaks => write!(
+                f,
+                "expected breaks in parentheses, as in (closed 3:00 PM–4:00 PM)"
+            ),
+        }
     }
+}
 
-    /// Gets the hours of this restaurant on the given day.
-    pub fn get_hours(&self, day: Day) -> Option<Hours> {
-        match day {
-            Day::Sunday => self.hours.sunday,
-            Day::Monday => self.hours.monday,
-            Day::Tuesday => self.hours.tuesday,
-            Day::Wednesday => self.hours.wednesday,
-            Day::Thursday => self.hours.thursday,
-            Day::Friday => self.hours.friday,
-            Day::Saturday => self.hours.saturday,
+impl Error for HoursFromStrError {}
+
+/// Parses a time as written in hours, either as in the data (e.g. `"17:00"`) or on a 12-hour
+/// clock as `Display` writes it (e.g. `"5:00 PM"`), noting which.
+fn parse_hours_time(s: &str) -> Result<(Time, bool), HoursFromStrError> {
+    let s = s.trim();
+    let upper = s.to_ascii_uppercase();
+    let (clock, pm) = match (upper.strip_suffix("AM"), upper.strip_suffix("PM")) {
+        (Some(clock), _) => (clock, false),
+        (_, Some(clock)) => (clock, true),
+        _ => {
+            let time = s
+                .parse()
+                .map_err(|e| HoursFromStrError::InvalidTime(s.to_string(), e))?;
+            return Ok((time, false));
         }
+    };
+    let time = clock
+        .parse::<Time>()
+        .map_err(|e| HoursFromStrError::InvalidTime(s.to_string(), e))?;
+    if !(1..=12).contains(&time.hours) {
+        return Err(HoursFromStrError::ClockHoursOutOfRange(s.to_string()));
     }
+    let hours = time.hours % 12 + if pm { 12 } else { 0 };
+    Ok((Time::new(hours.into(), time.minutes.into()), true))
+}
 
-    /// Returns whether this restaurant is open on the given day.
-    pub fn is_open(&self, day: Day) -> bool {
-        self.get_hours(day).is_some()
+/// Parses a span of two times separated by a dash (e.g. `"9:00-17:00"` or `"9:00 AM–5:00 PM"`).
+///
+/// An end on a 12-hour clock before the start is taken to fall on the following day, as times past
+/// midnight are written in the data (e.g. `"9:00 PM–2:00 AM"` ends at 26:00). An end equal to the
+/// start is left as is, since equal endpoints already make 24 hours.
+fn parse_time_span(s: &str) -> Result<(Time, Time), HoursFromStrError> {
+    let mut ends = s.splitn(2, ['-', '–']);
+    let start = ends.next().unwrap_or_default();
+    let end = ends.next().ok_or(HoursFromStrError::MissingSeparator)?;
+    let (start, start_clock) = parse_hours_time(start)?;
+    let (end, end_clock) = parse_hours_time(end)?;
+    if (start_clock || end_clock) && end.total_minutes() < start.total_minutes() {
+        return Ok((start, end.as_next_day().unwrap_or(end)));
     }
+    Ok((start, end))
+}
 
-    /// Returns whether this restaurant is a suitable candidate for dining, considering
-    /// travel time and business hours.
-    pub fn is_viable(&self, day: Day, time: Time) -> bool {
-        match self.get_hours(day) {
-            None => false,
-            Some(hours) => {
-                let t = time + 10; // Account for travel time, etc.
-                hours.start < t && hours.end > t
+/// Parses hours from a single string: a start and end separated by a hyphen or en dash (e.g.
+/// `"9:00-17:00"` or `"9:00–26:00"`), or `"Open 24 hours"` (from midnight to midnight).
+///
+/// Times may also be written on a 12-hour clock, and breaks given in parentheses, so hours
+/// written by `Display` parse back into the same hours (with times past midnight in the 24–47
+/// hour band, as the data writes them, and `"Open 24 hours"` ending at 24:00). Notes aren't part
+/// of the string.
+///
+/// # Examples
+/// ```
+/// use eat_ou::{Hours, Time, TimeRange};
+///
+/// let hours = "11:00-21:00".parse::<Hours>().unwrap();
+/// assert_eq!(hours, Hours::new(Time::new(11, 0), Time::new(21, 0)));
+/// let split = hours.with_break(TimeRange::new(Time::new(15, 0), Time::new(16, 0)));
+/// assert_eq!(split.to_string().parse::<Hours>().unwrap(), split);
+/// ```
+impl FromStr for Hours {
+    type Err = HoursFromStrError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (span, breaks) = match s.find('(') {
+            Some(index) => {
+                let breaks = s[index + 1..]
+                    .trim()
+                    .strip_prefix("closed ")
+                    .and_then(|breaks| breaks.strip_suffix(')'))
+                    .ok_or(HoursFromStrError::MalformedBreaks)?;
+                (&s[..index], Some(breaks))
+            }
+            None => (s, None),
+        };
+        let mut hours = if span.trim().eq_ignore_ascii_case("open 24 hours") {
+            Hours::new(Time::new(0, 0), Time::new(24, 0))
+        } else {
+            let (start, end) = parse_time_span(span)?;
+            Hours::new(start, end)
+        };
+        for closed in breaks.into_iter().flat_map(|breaks| breaks.split(',')) {
+            let (mut start, mut end) = parse_time_span(closed)?;
+            // A break written on a clock before the hours start falls on the following day.
+            if start.total_minutes() < hours.start.total_minutes() {
+                start = start.as_next_day().unwrap_or(start);
+                end = end.as_next_day().unwrap_or(end);
+            }
+            hours = hours.with_break(TimeRange::new(start, end));
+        }
+        Ok(hours)
+    }
+}
+
+impl Hours {
+    /// Creates hours from `start` until `end`, without breaks.
+    ///
+    /// As in the data, an `end` at or before `start` falls on the following day (see
+    /// `TimeRange::contains`).
+    pub fn new(start: Time, end: Time) -> Self {
+        Self {
+            start,
+            end,
+            breaks: Vec::new(),
+            note: None,
+        }
+    }
+
+    /// Adds a break, during which the business is closed, to these hours.
+    pub fn with_break(mut self, closed: TimeRange) -> Self {
+        self.breaks.push(closed);
+        self
+    }
+
+    /// Attaches a note to these hours (e.g. `"Kitchen closes at 8"`).
+    pub fn with_note<S: Into<String>>(mut self, note: S) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    /// Returns the note attached to these hours, if any.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    /// Returns the time these hours start.
+    pub fn start(&self) -> Time {
+        self.start
+    }
+
+    /// Returns the time these hours end, relative to the day they start.
+    pub fn end(&self) -> Time {
+        self.end
+    }
+
+    /// Returns how long the business is open during these hours, less any breaks.
+    ///
+    /// Hours ending at or before they start run into the following day, so equal endpoints make
+    /// 24 hours. Breaks falling outside the hours (see `stray_breaks`) don't count, and
+    /// overlapping breaks are only counted once.
+    pub fn duration(&self) -> Duration {
+        let minutes = self
+            .open_spans()
+            .into_iter()
+            .map(|(opens, closes)| closes - opens)
+            .sum::<u32>();
+        Duration::from_secs(u64::from(minutes) * 60)
+    }
+
+    /// Returns the span of these hours, ignoring any breaks.
+    fn range(&self) -> TimeRange {
+        TimeRange::new(self.start, self.end)
+    }
+
+    /// Returns the start and end of these hours in minutes past midnight of the day they begin
+    /// (see `TimeRange::bounds`), ignoring any breaks.
+    pub(crate) fn bounds(&self) -> (u32, u32) {
+        self.range().bounds()
+    }
+
+    /// Returns the spans the business is open during these hours, in order, as minutes past
+    /// midnight of the day they begin. Breaks falling outside the hours are ignored.
+    pub(crate) fn open_spans(&self) -> Vec<(u32, u32)> {
+        let (start, end) = self.bounds();
+        let mut breaks = self
+            .break_bounds()
+            .filter(|&(break_start, break_end)| start <= break_start && break_end <= end)
+            .collect::<Vec<_>>();
+        breaks.sort();
+        let mut spans = Vec::new();
+        let mut opens = start;
+        for (break_start, break_end) in breaks {
+            if break_start > opens {
+                spans.push((opens, break_start));
+            }
+            opens = opens.max(break_end);
+        }
+        if end > opens {
+            spans.push((opens, end));
+        }
+        spans
+    }
+
+    /// Returns the start and end of each break in minutes past midnight of the day these hours
+    /// begin.
+    ///
+    /// Breaks starting before the hours do are taken to fall on the following day, so a break in
+    /// overnight hours may be written either way (e.g. 1:00–1:30 or 25:00–25:30).
+    fn break_bounds<'a>(&'a self) -> impl Iterator<Item = (u32, u32)> + 'a {
+        let (start, _) = self.range().bounds();
+        self.breaks.iter().map(move |b| {
+            let (break_start, break_end) = b.bounds();
+            if break_start < start {
+                (break_start + 24 * 60, break_end + 24 * 60)
+            } else {
+                (break_start, break_end)
             }
+        })
+    }
+
+    /// Returns whether the business is open at the given time, relative to the day these hours
+    /// begin (see `TimeRange::contains`).
+    pub fn is_open_at(&self, time: Time) -> bool {
+        let minutes = time.total_minutes();
+        self.range().contains(time)
+            && !self
+                .break_bounds()
+                .any(|(start, end)| start <= minutes && minutes < end)
+    }
+
+    /// Returns how long after the given time the business closes (for the day or for a break),
+    /// or `None` if it is closed at that time.
+    pub(crate) fn remaining_after(&self, time: Time) -> Option<Duration> {
+        if !self.is_open_at(time) {
+            return None;
+        }
+        let minutes = time.total_minutes();
+        let (_, end) = self.range().bounds();
+        let closes = self
+            .break_bounds()
+            .map(|(start, _)| start)
+            .filter(|&start| start > minutes)
+            .fold(end, u32::min);
+        Some(Duration::from_secs(u64::from(closes - minutes) * 60))
+    }
+
+    /// Returns when the business opens (for the day or after a break) after the given time, or
+    /// `None` if it doesn't open again that day.
+    ///
+    /// Breaks are compared as `break_bounds` places them, so a break in overnight hours written
+    /// either way (e.g. 1:00–1:30 or 25:00–25:30) ends at 25:30.
+    pub(crate) fn opens_after(&self, time: Time) -> Option<Time> {
+        let (start, _) = self.bounds();
+        iter::once(start)
+            .chain(self.break_bounds().map(|(_, end)| end))
+            .filter(|&minutes| minutes > time.total_minutes())
+            .map(|minutes| Time::with_hours(0).plus_minutes(minutes))
+            .filter(|&t| self.is_open_at(t))
+            .min_by_key(|t| t.total_minutes())
+    }
+
+    /// Returns whether the business is open around the clock, without breaks.
+    pub(crate) fn is_all_day(&self) -> bool {
+        self.range().is_all_day() && self.breaks.is_empty()
+    }
+
+    /// Returns the breaks that don't fall entirely within these hours, and so have no effect.
+    pub(crate) fn stray_breaks(&self) -> Vec<TimeRange> {
+        let (start, end) = self.range().bounds();
+        self.breaks
+            .iter()
+            .zip(self.break_bounds())
+            .filter(|&(_, (break_start, break_end))| break_start < start || break_end > end)
+            .map(|(b, _)| *b)
+            .collect()
+    }
+}
+
+/// Represents a span of time within a day, possibly extending past midnight.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub struct TimeRange {
+    #[serde(deserialize_with = "deserialize_start")]
+    pub start: Time,
+    #[serde(deserialize_with = "deserialize_end")]
+    pub end: Time,
+}
+
+impl TimeRange {
+    /// Creates a new range from `start` until `end`.
+    pub fn new(start: Time, end: Time) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the start and end of the range in minutes past midnight of the day it begins.
+    ///
+    /// An end at or before the start is taken to fall on the following day, so equal endpoints
+    /// span a full 24 hours.
+    fn bounds(self) -> (u32, u32) {
+        let start = self.start.total_minutes();
+        let mut end = self.end.total_minutes();
+        if end <= start {
+            end += 24 * 60;
         }
+        (start, end)
+    }
+
+    /// Returns whether this range spans a full day.
+    fn is_all_day(self) -> bool {
+        let (start, end) = self.bounds();
+        end - start >= 24 * 60
+    }
+
+    /// Returns whether the given time falls within this range.
+    ///
+    /// The start is inclusive and the end is exclusive, so a business is open at the minute it
+    /// opens but not at the minute it closes. Times are relative to the day the range begins, so
+    /// an early-morning time on the following day should be given as hours 24–47 (e.g.
+    /// `Time::new(25, 30)` for 1:30 AM); overnight ranges may be written either way (e.g.
+    /// 21:00–2:00 or 21:00–26:00).
+    pub fn contains(&self, time: Time) -> bool {
+        let (start, end) = self.bounds();
+        let time = time.total_minutes();
+        start <= time && time < end
+    }
+}
+
+impl From<Hours> for TimeRange {
+    fn from(hours: Hours) -> Self {
+        hours.range()
+    }
+}
+
+/// The time allowed for getting to a restaurant when no other allowance is configured.
+pub const DEFAULT_TRAVEL_TIME: Duration = Duration::from_secs(10 * 60);
+
+/// How soon before closing a restaurant is considered to be closing soon.
+pub const CLOSING_SOON_THRESHOLD: Duration = Duration::from_secs(15 * 60);
+
+/// The weight of a restaurant that doesn't specify one (see `Restaurant::weight`).
+pub const DEFAULT_WEIGHT: f64 = 1.0;
+
+/// How long a restaurant must stay open after arrival, when no other minimum is configured.
+pub const DEFAULT_MIN_REMAINING: Duration = Duration::from_secs(25 * 60);
+
+/// Describes what it takes for a restaurant to be a realistic option, beyond being open.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViabilityOptions {
+    /// The time allowed for getting to the restaurant.
+    pub travel: Duration,
+    /// How long the restaurant must stay open once there, so there's time to eat.
+    pub min_remaining: Duration,
+}
+
+impl Default for ViabilityOptions {
+    fn default() -> Self {
+        Self {
+            travel: DEFAULT_TRAVEL_TIME,
+            min_remaining: DEFAULT_MIN_REMAINING,
+        }
+    }
+}
+
+/// Describes whether a restaurant is open at a given moment, and for how much longer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Viability {
+    /// The restaurant is open, and will close after the given time (or not at all, if it is open
+    /// 24 hours).
+    Open { closes_in: Option<Duration> },
+    /// The restaurant is open, but will close within `CLOSING_SOON_THRESHOLD`.
+    ClosingSoon { closes_in: Duration },
+    /// The restaurant is closed.
+    Closed,
+}
+
+/// Replaces a restaurant's weekly hours over a span of dates (e.g. a holiday).
+#[derive(Clone, Debug, PartialEq)]
+struct Exception {
+    first: Date,
+    last: Date,
+    hours: Option<Hours>,
+}
+
+impl Exception {
+    /// Returns whether this exception applies on the given date.
+    fn covers(&self, date: Date) -> bool {
+        self.first <= date && date <= self.last
+    }
+}
+
+/// Exceptions are written back as they're read: a single `"date"` if the exception covers one
+/// day, or `"from"` and `"to"` otherwise, with `"hours"` omitted if the restaurant is closed.
+impl Serialize for Exception {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        if self.first == self.last {
+            map.serialize_entry("date", &self.first)?;
+        } else {
+            map.serialize_entry("from", &self.first)?;
+            map.serialize_entry("to", &self.last)?;
+        }
+        if let Some(ref hours) = self.hours {
+            map.serialize_entry("hours", hours)?;
+        }
+        map.end()
+    }
+}
+
+/// An exception as written in the data: either a single `"date"`, or a span `"from"` one date
+/// `"to"` another (inclusive). Missing or `null` hours mean the restaurant is closed.
+#[derive(Deserialize)]
+pub(crate) struct RawException {
+    #[serde(default)]
+    pub(crate) date: Option<Date>,
+    #[serde(default)]
+    pub(crate) from: Option<Date>,
+    #[serde(default)]
+    pub(crate) to: Option<Date>,
+    #[serde(default)]
+    pub(crate) hours: Option<Hours>,
+}
+
+impl RawException {
+    /// Determines the span of dates this exception covers.
+    fn resolve(self) -> Result<Exception, String> {
+        let (first, last) = match (self.date, self.from, self.to) {
+            (Some(date), None, None) => (date, date),
+            (None, Some(from), Some(to)) if from <= to => (from, to),
+            (None, Some(from), Some(to)) => {
+                return Err(format!(
+                    "exception ends ({}) before it starts ({})",
+                    to, from
+                ))
+            }
+            _ => return Err("exception needs either \"date\" or \"from\" and \"to\"".into()),
+        };
+        Ok(Exception {
+            first,
+            last,
+            hours: self.hours,
+        })
+    }
+}
+
+/// Represents a span of days that recurs every year (e.g. a summer break from May 15 to August
+/// 1), inclusive of both ends.
+///
+/// A range whose end comes before its start in the calendar wraps around the new year (e.g.
+/// December 20 to January 6).
+///
+/// Deserializes from an object with `"from"` and `"to"` keys, each written as `MM-DD`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateRange {
+    from: (u8, u8),
+    to: (u8, u8),
+}
+
+impl DateRange {
+    /// Creates a range from the first to the last (month, day) pair, inclusive, if both exist in
+    /// some year (so February 29 is allowed).
+    pub fn try_new(from: (u8, u8), to: (u8, u8)) -> Option<Self> {
+        if is_month_day(from) && is_month_day(to) {
+            Some(Self { from, to })
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the given date falls within this range, in any year.
+    pub fn contains(&self, date: Date) -> bool {
+        let date = (date.month, date.day);
+        if self.from <= self.to {
+            self.from <= date && date <= self.to
+        } else {
+            self.from <= date || date <= self.to
+        }
+    }
+}
+
+/// Returns whether the given (month, day) pair exists in some year.
+fn is_month_day((month, day): (u8, u8)) -> bool {
+    // 2000 was a leap year, so February 29 passes.
+    Date::try_new(2000, month, day).is_some()
+}
+
+/// Parses a month and day written as `MM-DD`.
+fn parse_month_day(s: &str) -> Option<(u8, u8)> {
+    let mut parts = s.trim().splitn(2, '-');
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    Some((month, day)).filter(|&md| is_month_day(md))
+}
+
+impl Serialize for DateRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let format = |(month, day): (u8, u8)| format!("{:02}-{:02}", month, day);
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("from", &format(self.from))?;
+        map.serialize_entry("to", &format(self.to))?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for DateRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawDateRange {
+            from: String,
+            to: String,
+        }
+        let raw = RawDateRange::deserialize(deserializer)?;
+        let parse = |s: &str| {
+            parse_month_day(s).ok_or_else(|| {
+                DeserializationError::custom(format!("invalid date \"{}\"; expected MM-DD", s))
+            })
+        };
+        Ok(DateRange {
+            from: parse(&raw.from)?,
+            to: parse(&raw.to)?,
+        })
+    }
+}
+
+/// Encapsulates a restaurant/business and its hours.
+///
+/// Serializes in the same layout as the restaurant data, with its schedule fully resolved (so
+/// without shorthand keys or named schedules) and with empty or absent fields omitted.
+#[derive(Clone, Serialize)]
+pub struct Restaurant {
+    pub name: String,
+    id: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+    hours: HoursMap,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    walk_minutes: Option<u8>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cuisine: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    coordinates: Option<Coordinates>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    dietary: Vec<Dietary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price: Option<Price>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    exceptions: Vec<Exception>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    closed_ranges: Vec<DateRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight: Option<f64>,
+    /// Left unset (rather than `false`) when the data doesn't say, so that `merge` can tell the
+    /// two apart.
+    #[serde(skip_serializing_if = "is_unset")]
+    temporarily_closed: Option<bool>,
+}
+
+/// Returns whether a flag is unset (or explicitly off), so that it can be left out when
+/// serializing.
+fn is_unset(flag: &Option<bool>) -> bool {
+    *flag != Some(true)
+}
+
+/// A restaurant as written in the data, before its schedule is resolved.
+#[derive(Deserialize)]
+pub(crate) struct RawRestaurant {
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) id: Option<String>,
+    #[serde(default)]
+    pub(crate) aliases: Vec<String>,
+    pub(crate) hours: DayMapSpec<Hours>,
+    #[serde(default)]
+    pub(crate) walk_minutes: Option<u8>,
+    #[serde(default)]
+    pub(crate) tags: Vec<String>,
+    #[serde(default)]
+    pub(crate) cuisine: Option<String>,
+    #[serde(default)]
+    pub(crate) zone: Option<String>,
+    #[serde(default)]
+    pub(crate) url: Option<String>,
+    #[serde(default)]
+    pub(crate) phone: Option<String>,
+    #[serde(default)]
+    pub(crate) coordinates: Option<Coordinates>,
+    #[serde(default)]
+    pub(crate) dietary: Vec<Dietary>,
+    #[serde(default)]
+    pub(crate) price: Option<Price>,
+    #[serde(default)]
+    pub(crate) exceptions: Vec<RawException>,
+    #[serde(default)]
+    pub(crate) closed_ranges: Vec<DateRange>,
+    #[serde(default, alias = "notes")]
+    pub(crate) note: Option<String>,
+    #[serde(default)]
+    pub(crate) weight: Option<f64>,
+    #[serde(default)]
+    pub(crate) temporarily_closed: Option<bool>,
+}
+
+/// Returns whether a name contains the given query, ignoring case and surrounding whitespace.
+///
+/// An empty query matches every name.
+pub(crate) fn name_matches(name: &str, query: &str) -> bool {
+    name.to_lowercase().contains(&query.trim().to_lowercase())
+}
+
+/// How closely a name matches a search query (see `search`), from loosest to closest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchScore {
+    /// The query's characters all appear in the name, in order (e.g. "chp" in "Chipotle").
+    Subsequence,
+    /// The query appears somewhere in the name (e.g. "canes" in "Raising Cane's").
+    Substring,
+    /// The name starts with the query (e.g. "qdoba" in "Qdoba Mexican Eats").
+    Prefix,
+}
+
+/// Reduces a name or query to its lowercased letters and digits, so that searches ignore case,
+/// spacing, and punctuation (e.g. "chick fil a" matches "Chick-fil-A").
+fn search_key(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Scores how closely a name matches a query, both already reduced by `search_key`, or returns
+/// `None` if it doesn't match at all.
+fn match_score(name: &str, query: &str) -> Option<MatchScore> {
+    if name.starts_with(query) {
+        return Some(MatchScore::Prefix);
+    }
+    if name.contains(query) {
+        return Some(MatchScore::Substring);
+    }
+    let mut remaining = name.chars();
+    if query.chars().all(|c| remaining.any(|n| n == c)) {
+        Some(MatchScore::Subsequence)
+    } else {
+        None
+    }
+}
+
+/// Finds the restaurants whose names or aliases match the given query, ignoring case and
+/// punctuation, best matches first.
+///
+/// Names starting with the query rank above those merely containing it, which rank above those
+/// containing its characters in order with others in between (e.g. "chkfla" for
+/// "Chick-fil-A"). A restaurant is ranked by its best-matching name, and ties are broken by
+/// name. An empty query matches every restaurant.
+pub fn search<'a>(restaurants: &'a [Restaurant], query: &str) -> Vec<&'a Restaurant> {
+    let query = search_key(query);
+    let mut matches = restaurants
+        .iter()
+        .filter_map(|r| {
+            iter::once(&r.name)
+                .chain(&r.aliases)
+                .filter_map(|name| match_score(&search_key(name), &query))
+                .max()
+                .map(|score| (score, r))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| by_name()(a.1, b.1)));
+    matches.into_iter().map(|(_, r)| r).collect()
+}
+
+/// Generates an ID from a restaurant's name: lowercased, with apostrophes dropped and any other
+/// runs of punctuation or whitespace replaced by single hyphens (e.g. "Raising Cane's" becomes
+/// `raising-canes`).
+pub fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    let mut pending_hyphen = false;
+    for c in name.chars().filter(|&c| c != '\'' && c != '’') {
+        if c.is_alphanumeric() {
+            if pending_hyphen && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_hyphen = false;
+            slug.extend(c.to_lowercase());
+        } else {
+            pending_hyphen = true;
+        }
+    }
+    if slug.is_empty() {
+        slug.push_str("restaurant");
+    }
+    slug
+}
+
+/// Gives every restaurant in a list without an explicit ID one generated from its name.
+///
+/// Generated IDs avoid those already taken (explicitly or by an earlier restaurant) by appending
+/// `-2`, `-3`, and so on, in list order. Explicit IDs are left alone, so collisions between them
+/// are left for `validate` to report.
+pub(crate) fn assign_ids(restaurants: &mut [RawRestaurant]) {
+    let mut taken = restaurants
+        .iter()
+        .filter_map(|r| r.id.clone())
+        .collect::<HashSet<_>>();
+    for restaurant in restaurants.iter_mut().filter(|r| r.id.is_none()) {
+        let slug = slugify(&restaurant.name);
+        let id = (1..)
+            .map(|n| match n {
+                1 => slug.clone(),
+                n => format!("{}-{}", slug, n),
+            })
+            .find(|id| !taken.contains(id))
+            .expect("ran out of IDs");
+        taken.insert(id.clone());
+        restaurant.id = Some(id);
+    }
+}
+
+impl RawRestaurant {
+    /// Resolves the restaurant's schedule, looking up any schedule it extends by name.
+    ///
+    /// # Errors
+    /// Returns a message naming the restaurant if its schedule refers to an unknown schedule or
+    /// uses conflicting shorthand keys.
+    pub(crate) fn resolve(
+        self,
+        schedules: &HashMap<String, HoursMap>,
+    ) -> Result<Restaurant, String> {
+        let RawRestaurant {
+            name,
+            id,
+            aliases,
+            hours,
+            walk_minutes,
+            tags,
+            cuisine,
+            zone,
+            url,
+            phone,
+            coordinates,
+            dietary,
+            price,
+            exceptions,
+            closed_ranges,
+            note,
+            weight,
+            temporarily_closed,
+        } = self;
+        let present = |s: &String| !s.trim().is_empty();
+        let base = match hours.extends() {
+            Some(schedule) => match schedules.get(schedule) {
+                Some(base) => Some(base),
+                None => return Err(format!("{}: unknown schedule \"{}\"", name, schedule)),
+            },
+            None => None,
+        };
+        let exceptions = exceptions
+            .into_iter()
+            .map(RawException::resolve)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("{}: {}", name, e))?;
+        match hours.resolve(base) {
+            Ok(hours) => Ok(Restaurant {
+                id: id.unwrap_or_else(|| slugify(&name)),
+                name,
+                aliases,
+                hours,
+                walk_minutes,
+                tags,
+                cuisine,
+                zone,
+                url: url.filter(present),
+                phone: phone.filter(present),
+                coordinates,
+                dietary,
+                price,
+                exceptions,
+                closed_ranges,
+                note,
+                weight,
+                temporarily_closed,
+            }),
+            Err(e) => Err(format!("{}: {}", name, e)),
+        }
+    }
+}
+
+/// Restaurants are identified by their IDs (see `Restaurant::id`).
+///
+/// # Notes
+/// Restaurants don't implement `Ord`: an order by name can't agree with equality by ID. Sort them
+/// with `sort::by_name` (or `SortBy::Name`) instead, which is deterministic even for chains
+/// sharing a name.
+impl PartialEq for Restaurant {
+    fn eq(&self, other: &Restaurant) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Restaurant {}
+
+impl Hash for Restaurant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<'de> Deserialize<'de> for Restaurant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        RawRestaurant::deserialize(deserializer)?
+            .resolve(&HashMap::new())
+            .map_err(DeserializationError::custom)
+    }
+}
+
+impl Restaurant {
+    /// Gets the static list of all restaurants.
+    ///
+    /// # Errors
+    /// Returns `Err` if the bundled data can't be parsed, or contains no restaurants.
+    pub fn get_list() -> Result<Vec<Self>, DataError> {
+        let restaurants = Self::from_json_str(include_str!("../food.json"))?;
+        if restaurants.is_empty() {
+            Err(DataError::Empty)
+        } else {
+            Ok(restaurants)
+        }
+    }
+
+    /// Gets the static list of all restaurants, or an empty list if it can't be loaded.
+    ///
+    /// # Notes
+    /// Prefer `get_list` where a failure to load the data can be reported.
+    pub fn get_list_or_empty() -> Vec<Self> {
+        Self::get_list().unwrap_or_default()
+    }
+
+    /// Returns how many restaurants are in the bundled list, or zero if it can't be loaded.
+    pub fn count() -> usize {
+        Self::get_list_or_empty().len()
+    }
+
+    /// Parses a list of restaurants from JSON.
+    ///
+    /// The JSON may be either a bare array of restaurants or an object with a `"restaurants"`
+    /// array and a `"schedules"` object of named schedules, which restaurants may refer to in
+    /// place of (or as a base for) their own hours.
+    ///
+    /// This is the single entry point for parsing restaurant data; the bundled list is loaded
+    /// through it too.
+    ///
+    /// # Examples
+    /// ```
+    /// use eat_ou::{Day, Restaurant, Time};
+    ///
+    /// let restaurants = Restaurant::from_json_str(r#"[
+    ///     { "name": "Crossroads", "hours": { "daily": { "start": "7:00", "end": "26:00" } } },
+    ///     { "name": "Baja Fresh", "hours": { "weekdays": { "start": "10:30", "end": "21:00" } } }
+    /// ]"#).unwrap();
+    /// assert_eq!(restaurants.len(), 2);
+    /// let open = restaurants
+    ///     .iter()
+    ///     .filter(|r| r.is_open_at(Day::Saturday, Time::new(12, 0)))
+    ///     .map(|r| r.name.as_str())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(open, vec!["Crossroads"]);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `DataError::Parse` if the JSON is malformed or doesn't describe a list of
+    /// restaurants; the error names the offending restaurant (and day, where applicable). Data in
+    /// a newer version of the format gives `DataError::UnsupportedVersion` (see `load`).
+    pub fn from_json_str(s: &str) -> Result<Vec<Self>, DataError> {
+        Ok(load(s)?.restaurants)
+    }
+
+    /// Parses a list of restaurants from JSON, reporting every invalid entry rather than just the
+    /// first.
+    ///
+    /// Accepts the same layouts as `from_json_str`, and is meant for checking hand-edited data.
+    ///
+    /// # Errors
+    /// Returns the (zero-based) index of each invalid entry in the `restaurants` array, along with
+    /// the reason it's invalid. Problems that can't be pinned on an entry (malformed JSON, or a
+    /// broken named schedule) are reported alone, at index 0.
+    ///
+    /// # Notes
+    /// Only problems that prevent loading are reported; see `validate` for problems with
+    /// otherwise-valid data (e.g. duplicate names).
+    pub fn validate_list(json: &str) -> Result<Vec<Self>, Vec<(usize, String)>> {
+        parse_entries(json)
+    }
+
+    /// Parses a list of restaurants from an already-parsed JSON value.
+    ///
+    /// Accepts the same layouts as `from_json_str`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate serde_json;
+    /// # extern crate eat_ou;
+    /// # fn main() {
+    /// use eat_ou::{Day, Restaurant};
+    ///
+    /// let restaurants = Restaurant::from_json_value(json!({
+    ///     "schedules": { "union": { "weekdays": { "start": "8:00", "end": "15:00" } } },
+    ///     "restaurants": [
+    ///         { "name": "Chick-fil-A", "hours": "union" },
+    ///         { "name": "Panda Express", "hours": "union" }
+    ///     ]
+    /// })).unwrap();
+    /// assert!(restaurants.iter().all(|r| !r.has_hours(Day::Sunday)));
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `DataError::Parse` if the value doesn't describe a list of restaurants, or
+    /// `DataError::UnsupportedVersion` if it's in a newer version of the format (see `load`).
+    pub fn from_json_value(value: serde_json::Value) -> Result<Vec<Self>, DataError> {
+        Ok(load_value(value)?.restaurants)
+    }
+
+    /// Parses a list of restaurants from TOML, which (unlike JSON) allows comments alongside the
+    /// data (e.g. `# hours unconfirmed, called 2023-09`).
+    ///
+    /// The layout is the object form `from_json_str` accepts, with restaurants as an array of
+    /// tables and their hours as tables (dotted or inline) keyed by day. Times are strings, as in
+    /// JSON, while dates may be written either as strings or as TOML dates. The restaurants are
+    /// identical to those parsed from the equivalent JSON.
+    ///
+    /// # Examples
+    /// ```
+    /// use eat_ou::{Day, Restaurant, Time};
+    ///
+    /// let restaurants = Restaurant::from_toml_str(r#"
+    ///     [[restaurants]]
+    ///     name = "Crossroads"
+    ///     hours.daily = { start = "0:00", end = "24:00" } # during the semester
+    ///
+    ///     [[restaurants]]
+    ///     name = "Baja Fresh"
+    ///     [restaurants.hours.weekdays]
+    ///     start = "10:00"
+    ///     end = "18:00"
+    /// "#).unwrap();
+    /// assert!(restaurants[1].is_open_at(Day::Monday, Time::new(12, 0)));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `DataError::Parse` if the TOML is malformed or doesn't describe a list of
+    /// restaurants, or `DataError::UnsupportedVersion` if it's in a newer version of the format
+    /// (see `load`).
+    #[cfg(feature = "toml-data")]
+    pub fn from_toml_str(s: &str) -> Result<Vec<Self>, DataError> {
+        Ok(load_toml(s)?.restaurants)
+    }
+
+    /// Parses a list of restaurants from CSV, as kept in a spreadsheet.
+    ///
+    /// The first row is a header naming the columns: `name`, then a column for each day from
+    /// `sunday` to `saturday`. Each following row is a restaurant, with its name and then its
+    /// hours each day, written as one or more 24-hour time ranges (e.g.
+    /// `"11:00-14:00,17:00-21:00"`, quoted because of the comma) or `closed`. A range ending at
+    /// or before it starts runs into the next day. Blank rows are skipped, but quoted cells may
+    /// not span lines.
+    ///
+    /// The restaurants are loaded as if from the equivalent JSON (see `from_json_str`).
+    ///
+    /// # Examples
+    /// ```
+    /// use eat_ou::{Day, Restaurant, Time};
+    ///
+    /// let csv = "name,sunday,monday,tuesday,wednesday,thursday,friday,saturday
+    /// Crossroads,closed,\"11:00-14:00,17:00-21:00\",11:00-21:00,11:00-21:00,11:00-21:00,11:00-02:00,closed
+    /// ";
+    /// let restaurants = Restaurant::from_csv(csv.as_bytes()).unwrap();
+    /// assert!(!restaurants[0].is_open_at(Day::Monday, Time::new(15, 0)));
+    /// assert!(restaurants[0].is_open_at(Day::Saturday, Time::new(1, 0)));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `DataError::Csv`, citing the row and column, for a cell that can't be read (or a
+    /// row with the wrong number of cells), and `DataError::Empty` if there's no header. Reading
+    /// from `reader` failing gives `DataError::Fetch`.
+    pub fn from_csv<R: Read>(reader: R) -> Result<Vec<Self>, DataError> {
+        load_csv(reader)
+    }
+
+    /// Writes a list of restaurants as (pretty-printed) JSON, in the layout `from_json_str` reads.
+    ///
+    /// # Examples
+    /// ```
+    /// use eat_ou::Restaurant;
+    ///
+    /// let json = r#"[{ "name": "Crossroads", "hours": { "daily": { "start": "7:00", "end": "2:00" } } }]"#;
+    /// let restaurants = Restaurant::from_json_str(json).unwrap();
+    /// let written = Restaurant::to_json(&restaurants);
+    /// assert!(written.contains("\"saturday\""));
+    /// let reparsed = Restaurant::from_json_str(&written).unwrap();
+    /// assert_eq!(Restaurant::to_json(&reparsed), written);
+    /// ```
+    pub fn to_json(restaurants: &[Self]) -> String {
+        serde_json::to_string_pretty(restaurants).expect("restaurants always serialize to JSON")
+    }
+
+    /// Picks up to `n` distinct restaurants at random, without shuffling the whole list.
+    ///
+    /// Every restaurant is equally likely to be picked, whatever its weight (see
+    /// `app::weighted_shuffle` for that). If there are fewer than `n` restaurants, all of them are
+    /// returned, in random order.
+    ///
+    /// `rng(n)` must return a uniformly random number below `n`, as for `app::weighted_shuffle`.
+    pub fn pick_random<F>(restaurants: &[Self], n: usize, mut rng: F) -> Vec<&Self>
+    where
+        F: FnMut(u32) -> u32,
+    {
+        // A partial Fisher–Yates shuffle: only the first `n` positions are settled.
+        let mut indices = (0..restaurants.len()).collect::<Vec<_>>();
+        let count = n.min(indices.len());
+        for i in 0..count {
+            let remaining = (indices.len() - i) as u32;
+            let j = i + rng(remaining).min(remaining - 1) as usize;
+            indices.swap(i, j);
+        }
+        indices[..count].iter().map(|&i| &restaurants[i]).collect()
+    }
+
+    /// Merges a later entry for the same restaurant (e.g. from another data source) into this one.
+    ///
+    /// Fields the later entry sets (including `temporarily_closed`, if it's given at all) override
+    /// this restaurant's, with two exceptions: its hours replace these wholesale (but only if it
+    /// has hours on some day), and its tags and aliases are added to these rather than replacing
+    /// them.
+    pub fn merge(&mut self, later: Restaurant) {
+        fn replace<T>(field: &mut Vec<T>, later: Vec<T>) {
+            if !later.is_empty() {
+                *field = later;
+            }
+        }
+        if later.days_open_count() > 0 {
+            self.hours = later.hours;
+        }
+        self.name = later.name;
+        for tag in later.tags {
+            if !self.tags.contains(&tag) {
+                self.tags.push(tag);
+            }
+        }
+        for alias in later.aliases {
+            if !self.aliases.contains(&alias) {
+                self.aliases.push(alias);
+            }
+        }
+        replace(&mut self.dietary, later.dietary);
+        replace(&mut self.exceptions, later.exceptions);
+        replace(&mut self.closed_ranges, later.closed_ranges);
+        self.walk_minutes = later.walk_minutes.or(self.walk_minutes);
+        self.cuisine = later.cuisine.or_else(|| self.cuisine.take());
+        self.zone = later.zone.or_else(|| self.zone.take());
+        self.url = later.url.or_else(|| self.url.take());
+        self.phone = later.phone.or_else(|| self.phone.take());
+        self.coordinates = later.coordinates.or(self.coordinates);
+        self.price = later.price.or(self.price);
+        self.note = later.note.or_else(|| self.note.take());
+        self.weight = later.weight.or(self.weight);
+        self.temporarily_closed = later.temporarily_closed.or(self.temporarily_closed);
+    }
+
+    /// Gets the hours of this restaurant on the given day.
+    pub fn get_hours(&self, day: Day) -> Option<Hours> {
+        self.hours.get(day).cloned()
+    }
+
+    /// Iterates over the days this restaurant is open and its hours on each, in order from Sunday
+    /// to Saturday.
+    pub fn weekly_hours(&self) -> impl Iterator<Item = (Day, &Hours)> {
+        self.hours.iter()
+    }
+
+    /// Writes this restaurant's weekly hours in OpenStreetMap's `opening_hours` syntax (see
+    /// `to_opening_hours`). Exceptions and seasonal closures aren't included.
+    pub fn opening_hours(&self) -> String {
+        to_opening_hours(&self.hours)
+    }
+
+    /// Returns this restaurant's stable identifier.
+    ///
+    /// This is the `"id"` given in the data if there is one, or else one generated from the name
+    /// (see `slugify`), made unique within the list the restaurant was loaded with.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the key restaurants are ordered by (see `sort::by_name`): the name, then the first
+    /// opening of the week (in minutes since midnight on Sunday), if any, then the ID.
+    pub(crate) fn sort_key(&self) -> (&str, Option<u32>, &str) {
+        let first_opening = self
+            .weekly_hours()
+            .next()
+            .map(|(day, hours)| u32::from(day as u8) * 24 * 60 + hours.start().total_minutes());
+        (&self.name, first_opening, &self.id)
+    }
+
+    /// Returns the days of the week this restaurant has any hours, in order from Sunday to
+    /// Saturday.
+    pub fn open_days(&self) -> Vec<Day> {
+        self.weekly_hours().map(|(day, _)| day).collect()
+    }
+
+    /// Returns how many minutes a week this restaurant is open, for ranking restaurants by
+    /// availability.
+    pub fn weekly_open_minutes(&self) -> i32 {
+        self.weekly_hours()
+            .map(|(_, hours)| hours.duration().as_secs() as i32 / 60)
+            .sum()
+    }
+
+    /// Returns the number of days a week this restaurant has any hours (see `open_days`).
+    ///
+    /// Every day with hours counts, even if its breaks leave no time open at all. Hours can't be
+    /// empty (equal start and end times make 24 hours), so a day only goes uncounted if it has no
+    /// hours.
+    pub fn days_open_count(&self) -> usize {
+        self.weekly_hours().count()
+    }
+
+    /// Summarizes this restaurant's weekly schedule (e.g. "Mon–Fri 11:00 AM–9:00 PM, closed
+    /// Sat–Sun").
+    ///
+    /// Days are listed from Monday to Sunday, and consecutive days with identical hours are
+    /// collapsed into a range.
+    pub fn schedule_summary(&self) -> String {
+        let mut runs: Vec<(Day, Day, Option<Hours>)> = Vec::new();
+        for day in Day::Monday.upcoming() {
+            let hours = self.get_hours(day);
+            match runs.last_mut() {
+                Some(&mut (_, ref mut last, ref same)) if *same == hours => *last = day,
+                _ => runs.push((day, day, hours)),
+            }
+        }
+        runs.into_iter()
+            .map(|(first, last, hours)| {
+                let days = if first == last {
+                    first.abbreviation().to_string()
+                } else {
+                    format!("{}–{}", first.abbreviation(), last.abbreviation())
+                };
+                match hours {
+                    Some(hours) => format!("{} {}", days, hours),
+                    None => format!("closed {}", days),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Gets the hours of this restaurant on the given date, which falls on the given day.
+    ///
+    /// A recurring closure (e.g. summer break) covering the date means no hours at all; otherwise,
+    /// any exception covering the date (e.g. a holiday closure) takes precedence over the weekly
+    /// hours.
+    pub fn hours_on(&self, date: Date, day: Day) -> Option<Hours> {
+        if self.is_temporarily_closed() || self.is_closed_for_season(date) {
+            return None;
+        }
+        match self.exceptions.iter().find(|e| e.covers(date)) {
+            Some(exception) => exception.hours.clone(),
+            None => self.get_hours(day),
+        }
+    }
+
+    /// Returns the recurring ranges of dates (e.g. summer break) this restaurant is closed for.
+    pub fn closed_ranges(&self) -> &[DateRange] {
+        &self.closed_ranges
+    }
+
+    /// Returns whether the given date falls within one of this restaurant's recurring closures.
+    pub fn is_closed_for_season(&self, date: Date) -> bool {
+        self.closed_ranges.iter().any(|range| range.contains(date))
+    }
+
+    /// Describes problems with this restaurant's data that don't prevent it from being used, such
+    /// as breaks falling outside the hours they belong to.
+    pub fn warnings(&self) -> Vec<String> {
+        self.weekly_hours()
+            .flat_map(|(day, hours)| {
+                hours.stray_breaks().into_iter().map(move |b| {
+                    format!(
+                        "{}: {}: break {}–{} falls outside its hours",
+                        self.name,
+                        day.key(),
+                        b.start,
+                        b.end
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Gets the hours of this restaurant today, according to the given clock.
+    ///
+    /// Schedule exceptions are taken into account if the clock knows the date.
+    pub fn hours_today(&self, clock: &impl Clock) -> Option<Hours> {
+        let day = clock.today();
+        match clock.date() {
+            Some(date) => self.hours_on(date, day),
+            None => self.get_hours(day),
+        }
+    }
+
+    /// Describes this restaurant's hours on the given day for display (e.g. "11:00 AM–9:00 PM"),
+    /// or when it next opens if it's closed that day (e.g. "Closed (reopens Friday 11:00 AM)").
+    ///
+    /// Restaurants without hours on any day are simply "Closed today", and temporarily closed
+    /// restaurants say so, along with their note (e.g. "Temporarily closed: Closed for
+    /// renovation").
+    pub fn describe_hours(&self, day: Day) -> String {
+        self.describe(day, self.get_hours(day))
+    }
+
+    /// Describes this restaurant's hours today, according to the given clock (see
+    /// `describe_hours`).
+    ///
+    /// Schedule exceptions are taken into account if the clock knows the date.
+    pub fn describe_hours_today(&self, clock: &impl Clock) -> String {
+        self.describe(clock.today(), self.hours_today(clock))
+    }
+
+    /// Describes the given hours on the given day, or when this restaurant next opens if there
+    /// are none.
+    fn describe(&self, day: Day, hours: Option<Hours>) -> String {
+        if self.is_temporarily_closed() {
+            return match self.note {
+                Some(ref note) => format!("Temporarily closed: {}", note),
+                None => "Temporarily closed".into(),
+            };
+        }
+        if let Some(hours) = hours {
+            return hours.to_string();
+        }
+        // Start from the end of the day, since any hours it usually has don't apply.
+        match self.next_start(day, Time::new(47, 59)) {
+            Some((_, day, time)) => format!("Closed (reopens {} {})", day, time),
+            None => "Closed today".into(),
+        }
+    }
+
+    /// Returns whether this restaurant is a suitable candidate for dining right now, according to
+    /// the given clock (see `is_viable`).
+    ///
+    /// Schedule exceptions are taken into account if the clock knows the date.
+    pub fn is_viable_now(&self, clock: &impl Clock) -> bool {
+        let (day, time) = (clock.today(), clock.now());
+        match clock.date() {
+            Some(date) => self.is_viable_on(date, day, time),
+            None => self.is_viable(day, time),
+        }
+    }
+
+    /// Returns whether this restaurant has hours on the given day.
+    ///
+    /// # Notes
+    /// This says nothing about whether the restaurant is open at a particular time; use
+    /// `is_open_at` for that.
+    pub fn has_hours(&self, day: Day) -> bool {
+        self.get_hours(day).is_some()
+    }
+
+    /// Returns whether this restaurant is open at some point this weekend.
+    pub fn is_open_on_weekend(&self) -> bool {
+        !self.is_temporarily_closed()
+            && (self.has_hours(Day::Saturday) || self.has_hours(Day::Sunday))
+    }
+
+    /// Returns whether this restaurant is open at the given moment.
+    ///
+    /// Overnight hours that began the previous day (e.g. 21:00–26:00) are taken into account, so
+    /// a late-night place is open at 1 AM the morning after.
+    ///
+    /// # Notes
+    /// Unlike `is_viable`, this makes no allowance for travel time.
+    pub fn is_open_at(&self, day: Day, time: Time) -> bool {
+        self.open_range(day, time).is_some()
+    }
+
+    /// Returns whether this restaurant's hours on the given day extend past the given time (e.g.
+    /// for flagging places open late).
+    ///
+    /// # Notes
+    /// Hours closing after midnight count as extending past any evening threshold. A threshold
+    /// earlier than the day's opening time is taken to fall on the following day, so a threshold
+    /// of 1:00 asks whether the restaurant stays open past 1 AM that night.
+    pub fn is_open_after(&self, day: Day, threshold: Time) -> bool {
+        self.get_hours(day).is_some_and(|hours| {
+            let (start, end) = hours.range().bounds();
+            let mut threshold = threshold.total_minutes();
+            if threshold < start {
+                threshold += 24 * 60;
+            }
+            end > threshold
+        })
+    }
+
+    /// Returns whether this restaurant is open at the given moment on the given date (which falls
+    /// on the given day), taking schedule exceptions into account.
+    pub fn is_open_on(&self, date: Date, day: Day, time: Time) -> bool {
+        let today = self.hours_on(date, day);
+        let yesterday = self.hours_on(date.previous(), day + 6);
+        open_range(today, yesterday, time).is_some()
+    }
+
+    /// Returns whether this restaurant is a suitable candidate for dining on the given date
+    /// (which falls on the given day), taking schedule exceptions into account.
+    ///
+    /// # Notes
+    /// Allows the restaurant's own travel time, or `DEFAULT_TRAVEL_TIME` if it has none.
+    pub fn is_viable_on(&self, date: Date, day: Day, time: Time) -> bool {
+        let travel_time = self.travel_time().unwrap_or(DEFAULT_TRAVEL_TIME);
+        self.is_viable_on_with(date, day, time, travel_time)
+    }
+
+    /// Returns whether this restaurant will still be open on the given date after the given
+    /// travel time, taking schedule exceptions into account.
+    pub fn is_viable_on_with(&self, date: Date, day: Day, time: Time, travel: Duration) -> bool {
+        self.is_open_on(date, day, time + travel)
+    }
+
+    /// Returns whether this restaurant will be open on the given date after the travel time, and
+    /// stay open for at least the minimum remaining time, taking schedule exceptions into
+    /// account (see `is_viable_with_options`).
+    pub fn is_viable_on_with_options(
+        &self,
+        date: Date,
+        day: Day,
+        time: Time,
+        options: ViabilityOptions,
+    ) -> bool {
+        let today = self.hours_on(date, day);
+        let yesterday = self.hours_on(date.previous(), day + 6);
+        has_time_left(
+            open_range(today, yesterday, time + options.travel),
+            options.min_remaining,
+        )
+    }
+
+    /// Returns the hours during which this restaurant is open at the given moment, along with the
+    /// moment expressed relative to the day those hours began.
+    fn open_range(&self, day: Day, time: Time) -> Option<(Hours, Time)> {
+        if self.is_temporarily_closed() {
+            return None;
+        }
+        open_range(self.get_hours(day), self.get_hours(day + 6), time)
+    }
+
+    /// Returns the tags (e.g. `"vegan"`) describing this restaurant.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Returns the other names this restaurant goes by (e.g. `"the Caf"`).
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    /// Returns whether this restaurant's name or any of its aliases contains the given query,
+    /// ignoring case and surrounding whitespace (see `name_matches`).
+    pub fn matches_name(&self, query: &str) -> bool {
+        iter::once(&self.name)
+            .chain(&self.aliases)
+            .any(|name| name_matches(name, query))
+    }
+
+    /// Returns how strongly this restaurant is favored when shuffling suggestions (see
+    /// `app::weighted_shuffle`), relative to the default of 1.
+    pub fn weight(&self) -> f64 {
+        self.weight.unwrap_or(DEFAULT_WEIGHT)
+    }
+
+    /// Returns whether this restaurant is closed until further notice (e.g. for renovation).
+    ///
+    /// A temporarily closed restaurant is never open, viable, or suggested, whatever its hours
+    /// say, but is still listed among all restaurants so that people know it exists.
+    pub fn is_temporarily_closed(&self) -> bool {
+        self.temporarily_closed.unwrap_or(false)
+    }
+
+    /// Returns the note attached to this restaurant's hours on the given day (e.g. `"Kitchen
+    /// closes early"`), if any.
+    pub fn day_note(&self, day: Day) -> Option<&str> {
+        self.hours.get(day).and_then(Hours::note)
+    }
+
+    /// Returns the note shown alongside this restaurant (e.g. `"Cash only"`), if any.
+    ///
+    /// In the data, this is given as either `"note"` or `"notes"`.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    /// Returns the kind of food this restaurant serves (e.g. `"Mexican"`), if known.
+    pub fn cuisine(&self) -> Option<&str> {
+        self.cuisine.as_deref()
+    }
+
+    /// Returns the address of the restaurant's website (e.g. its menu), if known.
+    ///
+    /// The address is given as written in the data; see `validate` for a check that it looks
+    /// like one.
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Returns the restaurant's phone number, as written in the data, if known.
+    pub fn phone(&self) -> Option<&str> {
+        self.phone.as_deref()
+    }
+
+    /// Returns the part of campus this restaurant is in (e.g. `"research"`), if known.
+    pub fn zone(&self) -> Option<&str> {
+        self.zone.as_deref()
+    }
+
+    /// Returns the dietary needs this restaurant caters to, as listed in the data.
+    pub fn dietary(&self) -> &[Dietary] {
+        &self.dietary
+    }
+
+    /// Returns whether this restaurant caters to the given dietary need.
+    pub fn has_dietary(&self, option: &Dietary) -> bool {
+        self.dietary.contains(option)
+    }
+
+    /// Returns how expensive this restaurant is, if known.
+    pub fn price(&self) -> Option<Price> {
+        self.price
+    }
+
+    /// Returns where this restaurant is, if known.
+    pub fn coordinates(&self) -> Option<Coordinates> {
+        self.coordinates
+    }
+
+    /// Returns the distance from the given coordinates to this restaurant, in kilometers, if its
+    /// location is known.
+    pub fn distance_km(&self, from: Coordinates) -> Option<f64> {
+        self.coordinates.map(|c| from.distance_km(c))
+    }
+
+    /// Returns whether this restaurant has the given tag, ignoring case.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// Returns the time it takes to get to this restaurant, if the data specifies it (as
+    /// `"walk_minutes"`).
+    pub fn travel_time(&self) -> Option<Duration> {
+        self.walk_minutes
+            .map(|minutes| Duration::from_secs(u64::from(minutes) * 60))
+    }
+
+    /// Returns whether this restaurant is a suitable candidate for dining, considering
+    /// travel time and business hours.
+    ///
+    /// # Notes
+    /// Allows the restaurant's own travel time, or `DEFAULT_TRAVEL_TIME` if it has none; see
+    /// `is_viable_with` to specify another allowance.
+    pub fn is_viable(&self, day: Day, time: Time) -> bool {
+        let travel_time = self.travel_time().unwrap_or(DEFAULT_TRAVEL_TIME);
+        self.is_viable_with(day, time, travel_time)
+    }
+
+    /// Returns whether this restaurant will still be open after the given travel time.
+    ///
+    /// With no travel time, this is equivalent to `is_open_at`.
+    pub fn is_viable_with(&self, day: Day, time: Time, travel_time: Duration) -> bool {
+        self.is_open_at(day, time + travel_time)
+    }
+
+    /// Returns whether this restaurant will be open after the travel time, and stay open for at
+    /// least the minimum remaining time.
+    ///
+    /// # Notes
+    /// The boundary is inclusive: a restaurant closing in exactly the travel time plus the
+    /// minimum remaining time is viable. Remaining time runs past midnight for overnight hours,
+    /// but ends at a break. Restaurants open 24 hours are always viable.
+    pub fn is_viable_with_options(&self, day: Day, time: Time, options: ViabilityOptions) -> bool {
+        has_time_left(
+            self.open_range(day, time + options.travel),
+            options.min_remaining,
+        )
+    }
+
+    /// Returns whether this restaurant will be open after the given travel time, and stay open
+    /// for at least `min_remaining` (e.g. `DEFAULT_MIN_REMAINING`) once there.
+    ///
+    /// This is `is_viable_with_options` with the options spelled out.
+    pub fn is_viable_with_min_open(
+        &self,
+        day: Day,
+        time: Time,
+        travel: Duration,
+        min_remaining: Duration,
+    ) -> bool {
+        self.is_viable_with_options(
+            day,
+            time,
+            ViabilityOptions {
+                travel,
+                min_remaining,
+            },
+        )
+    }
+
+    /// Returns how much longer this restaurant is open at the given moment.
+    ///
+    /// Returns `None` if it is closed at that moment, and `Some(None)` if it is open 24 hours.
+    pub(crate) fn closes_in(&self, day: Day, time: Time) -> Option<Option<Duration>> {
+        let (hours, time) = self.open_range(day, time)?;
+        let remaining = hours.remaining_after(time)?;
+        Some(if hours.is_all_day() {
+            None
+        } else {
+            Some(remaining)
+        })
+    }
+
+    /// Returns whether this restaurant is open at the given moment but closes within the given
+    /// threshold (inclusive).
+    ///
+    /// # Notes
+    /// Restaurants open 24 hours never close soon.
+    pub fn closes_soon(&self, day: Day, time: Time, threshold: Duration) -> bool {
+        match self.closes_in(day, time) {
+            Some(Some(closes_in)) => closes_in <= threshold,
+            _ => false,
+        }
+    }
+
+    /// Returns whether this restaurant is open at the given moment, distinguishing places that
+    /// close within `CLOSING_SOON_THRESHOLD`.
+    pub fn viability(&self, day: Day, time: Time) -> Viability {
+        match self.closes_in(day, time) {
+            None => Viability::Closed,
+            Some(Some(closes_in)) if closes_in <= CLOSING_SOON_THRESHOLD => {
+                Viability::ClosingSoon { closes_in }
+            }
+            Some(closes_in) => Viability::Open { closes_in },
+        }
+    }
+
+    /// Returns when this restaurant is next open, starting from the given moment, or `None` if it
+    /// has no hours at all.
+    ///
+    /// If the restaurant is already open at the given moment (including overnight hours that
+    /// began the previous day, and places open 24 hours), the moment itself is returned.
+    /// Otherwise, the next day and time it opens is returned, wrapping around the week; if its
+    /// only opening this week has already passed, its opening on the same day next week is
+    /// returned.
+    pub fn next_opening(&self, from_day: Day, from_time: Time) -> Option<(Day, Time)> {
+        if self.is_open_at(from_day, from_time) {
+            return Some((from_day, from_time));
+        }
+        self.next_start(from_day, from_time)
+            .map(|(_, day, time)| (day, time))
+    }
+
+    /// Returns when this restaurant opens later on the given day, after the given time (including
+    /// reopening after a break), or `None` if it's open at that moment or has no hours left that
+    /// day.
+    pub fn opens_later(&self, day: Day, time: Time) -> Option<Time> {
+        if self.is_open_at(day, time) {
+            return None;
+        }
+        self.get_hours(day)?.opens_after(time)
+    }
+
+    /// Returns the next time this restaurant opens strictly after the given moment (including
+    /// reopening after a break that day), along with how many days away that is.
+    fn next_start(&self, from_day: Day, from_time: Time) -> Option<(u8, Day, Time)> {
+        if self.is_temporarily_closed() {
+            return None;
+        }
+        from_day
+            .upcoming()
+            .chain(Some(from_day))
+            .enumerate()
+            .filter_map(|(offset, day)| {
+                let hours = self.get_hours(day)?;
+                let start = match offset {
+                    0 => hours.opens_after(from_time)?,
+                    _ => hours.start,
+                };
+                Some((offset as u8, day, start))
+            })
+            .next()
+    }
+}
+
+/// Returns the hours that are in progress at the given time, along with the time expressed
+/// relative to the day those hours began.
+///
+/// Today's hours are preferred; failing that, the previous day's hours are consulted with the
+/// time moved into the 24–47 hour band.
+fn open_range(today: Option<Hours>, yesterday: Option<Hours>, time: Time) -> Option<(Hours, Time)> {
+    let today = today
+        .filter(|hours| hours.is_open_at(time))
+        .map(|hours| (hours, time));
+    today.or_else(|| {
+        let yesterday = yesterday?;
+        let time = time.as_next_day()?;
+        if yesterday.is_open_at(time) {
+            Some((yesterday, time))
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns whether hours open at a moment (as found by `open_range`) stay open for at least
+/// `min_remaining` afterwards.
+fn has_time_left(open: Option<(Hours, Time)>, min_remaining: Duration) -> bool {
+    match open {
+        Some((hours, time)) => {
+            hours.is_all_day()
+                || hours
+                    .remaining_after(time)
+                    .is_some_and(|remaining| remaining >= min_remaining)
+        }
+        None => false,
+    }
+}
+
+/// Returns the restaurants that are suitable candidates for dining right now, according to the
+/// given clock (see `Restaurant::is_viable_now`).
+///
+/// The clock is read once, so every restaurant is judged against the same moment.
+pub fn viable_now<'a>(restaurants: &'a [Restaurant], clock: &impl Clock) -> Vec<&'a Restaurant> {
+    let clock = FixedClock::snapshot(clock);
+    restaurants
+        .iter()
+        .filter(|r| r.is_viable_now(&clock))
+        .collect()
+}
+
+/// Returns the restaurants that are suitable candidates for dining at the given moment (see
+/// `Restaurant::is_viable`).
+///
+/// Passing a time ahead of the current one (e.g. an hour from now) includes places that open
+/// shortly, for when the diner is leaving soon but not right away.
+pub fn get_viable_at(restaurants: &[Restaurant], day: Day, time: Time) -> Vec<&Restaurant> {
+    restaurants
+        .iter()
+        .filter(|r| r.is_viable(day, time))
+        .collect()
+}
+
+/// Counts how many restaurants are open at the start of each hour of the given day (e.g. to
+/// suggest when there are the most options).
+///
+/// The counts are indexed by hour, from midnight to 11 PM. Hours carried over from the night
+/// before (e.g. until 2 AM) count toward the early hours of the day.
+pub fn open_counts_by_hour(restaurants: &[Restaurant], day: Day) -> [u32; 24] {
+    let mut counts = [0; 24];
+    for (hour, count) in counts.iter_mut().enumerate() {
+        let time = Time::new(hour as i32, 0);
+        *count = restaurants
+            .iter()
+            .filter(|r| r.is_open_at(day, time))
+            .count() as u32;
+    }
+    counts
+}
+
+/// Finds the restaurant that opens soonest after the given moment, along with the day and time
+/// it opens.
+///
+/// Ties are broken in favor of the restaurant appearing first in the list.
+pub fn soonest_opening(
+    restaurants: &[Restaurant],
+    from_day: Day,
+    from_time: Time,
+) -> Option<(&Restaurant, Day, Time)> {
+    restaurants
+        .iter()
+        .filter_map(|r| {
+            r.next_start(from_day, from_time)
+                .map(|(offset, day, time)| (r, offset, day, time))
+        })
+        .min_by_key(|&(_, offset, _, time)| u32::from(offset) * 24 * 60 + time.total_minutes())
+        .map(|(r, _, day, time)| (r, day, time))
+}
+
+/// Formats a number of minutes as an ISO 8601 duration (e.g. `PT1H30M` for 90 minutes), for
+/// machine-readable output.
+///
+/// Days aren't used, since a day isn't always 24 hours long (e.g. 1440 minutes is `PT24H`), and
+/// zero is `PT0M`. Negative totals are prefixed with a minus sign (e.g. `-PT45M`), as many
+/// parsers accept.
+pub fn format_iso8601_minutes(total: i32) -> String {
+    let sign = if total < 0 { "-" } else { "" };
+    let total = total.unsigned_abs();
+    let (hours, minutes) = (total / 60, total % 60);
+    match (hours, minutes) {
+        (0, minutes) => format!("{}PT{}M", sign, minutes),
+        (hours, 0) => format!("{}PT{}H", sign, hours),
+        (hours, minutes) => format!("{}PT{}H{}M", sign, hours, minutes),
     }
 }
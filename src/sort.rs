@@ -0,0 +1,133 @@
+use location::{compare_by_distance, Coordinates};
+use schedule::{Day, Restaurant, Time};
+use std::cmp::Ordering;
+
+/// Represents the order in which restaurants are listed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SortBy {
+    /// Alphabetically, by name (see `by_name`).
+    #[default]
+    Name,
+    /// Nearest first, measuring from the given coordinates.
+    Distance(Coordinates),
+    /// By when each restaurant closes, as of the given moment (see `by_closing_time`).
+    ClosingTime {
+        day: Day,
+        time: Time,
+        soonest_first: bool,
+    },
+    /// By when each restaurant opens on the given day, earliest first (see `by_opening_time`).
+    OpeningTime(Day),
+    /// By how much longer each restaurant is open, as of the given moment, longest first (see
+    /// `by_remaining_open`).
+    RemainingOpen { day: Day, time: Time },
+}
+
+impl SortBy {
+    /// Chooses the order to use given the outcome of a request for the user's position.
+    ///
+    /// If the position is unavailable (e.g. because the user declined to share it), restaurants
+    /// are sorted by name.
+    pub fn for_position(position: Option<Coordinates>) -> Self {
+        position.map_or(SortBy::Name, SortBy::Distance)
+    }
+
+    /// Compares two restaurants according to this order.
+    pub fn compare(self, a: &Restaurant, b: &Restaurant) -> Ordering {
+        match self {
+            SortBy::Name => by_name()(a, b),
+            SortBy::Distance(from) => compare_by_distance(a, b, from),
+            SortBy::ClosingTime {
+                day,
+                time,
+                soonest_first,
+            } => by_closing_time(day, time, soonest_first)(a, b),
+            SortBy::OpeningTime(day) => by_opening_time(day)(a, b),
+            SortBy::RemainingOpen { day, time } => by_remaining_open(day, time)(a, b),
+        }
+    }
+
+    /// Sorts the given restaurants according to this order.
+    pub fn sort(self, restaurants: &mut [&Restaurant]) {
+        restaurants.sort_by(|a, b| self.compare(a, b));
+    }
+}
+
+/// Compares restaurants by a key that only some of them have (e.g. a closing time on a day when
+/// not every restaurant is open), putting those without one last.
+///
+/// Ties (including two restaurants without a key) are broken by name, so that the order is
+/// deterministic.
+fn keyed_then_name<K, F>(key: F) -> impl Fn(&Restaurant, &Restaurant) -> Ordering
+where
+    K: Ord,
+    F: Fn(&Restaurant) -> Option<K>,
+{
+    move |a, b| {
+        match (key(a), key(b)) {
+            (Some(x), Some(y)) => x.cmp(&y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+        .then_with(|| by_name()(a, b))
+    }
+}
+
+/// Orders restaurants alphabetically, by name.
+///
+/// Restaurants sharing a name (e.g. a chain's locations) are ordered by when they first open in
+/// the week (from Sunday), then by ID, so that sorting is deterministic.
+pub fn by_name() -> impl Fn(&Restaurant, &Restaurant) -> Ordering {
+    |a: &Restaurant, b: &Restaurant| a.sort_key().cmp(&b.sort_key())
+}
+
+/// Orders restaurants by how soon after the given moment they close, either soonest first or
+/// latest first.
+///
+/// A restaurant open at that moment closes when its current hours end (restaurants open 24
+/// hours close latest of all); otherwise (including during a break), it closes when its hours
+/// that day end. Restaurants with no hours left that day come last either way.
+pub fn by_closing_time(
+    day: Day,
+    time: Time,
+    soonest_first: bool,
+) -> impl Fn(&Restaurant, &Restaurant) -> Ordering {
+    keyed_then_name(move |r: &Restaurant| {
+        let minutes = match r.closes_in(day, time) {
+            Some(Some(closes_in)) => closes_in.as_secs() / 60,
+            Some(None) => u64::MAX,
+            None => {
+                let (_, end) = r.get_hours(day)?.bounds();
+                let now = time.total_minutes();
+                if end <= now {
+                    return None;
+                }
+                u64::from(end - now)
+            }
+        };
+        Some(if soonest_first {
+            minutes
+        } else {
+            u64::MAX - minutes
+        })
+    })
+}
+
+/// Orders restaurants by when they open on the given day, earliest first.
+///
+/// Restaurants closed that day come last.
+pub fn by_opening_time(day: Day) -> impl Fn(&Restaurant, &Restaurant) -> Ordering {
+    keyed_then_name(move |r: &Restaurant| r.get_hours(day).map(|hours| hours.bounds().0))
+}
+
+/// Orders restaurants by how much longer they're open after the given moment, longest first
+/// (with restaurants open 24 hours ahead of all others).
+///
+/// Restaurants closed at that moment come last.
+pub fn by_remaining_open(day: Day, time: Time) -> impl Fn(&Restaurant, &Restaurant) -> Ordering {
+    keyed_then_name(move |r: &Restaurant| match r.closes_in(day, time)? {
+        Some(closes_in) => Some(u64::MAX - closes_in.as_secs()),
+        None => Some(0),
+    })
+}
@@ -0,0 +1,54 @@
+use std::{cell::RefCell, collections::HashMap};
+use stdweb::web::window;
+
+/// Persists small string values across sessions, by key.
+pub trait Storage {
+    /// Returns the value stored under the given key, if any.
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Stores the value under the given key, replacing any existing value.
+    ///
+    /// # Notes
+    /// Persistence is best-effort; a value that can't be stored (e.g. because the quota is
+    /// exhausted) is silently dropped.
+    fn set(&self, key: &str, value: &str);
+}
+
+/// Stores values in the browser's `localStorage`.
+///
+/// Depends on JavaScript APIs for persistence.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LocalStorage;
+
+impl Storage for LocalStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        window().local_storage().get(key)
+    }
+
+    fn set(&self, key: &str, value: &str) {
+        let _ = window().local_storage().insert(key, value);
+    }
+}
+
+/// Stores values in memory, forgetting them when dropped.
+///
+/// Useful for tests.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage(RefCell<HashMap<String, String>>);
+
+impl MemoryStorage {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        self.0.borrow().get(key).cloned()
+    }
+
+    fn set(&self, key: &str, value: &str) {
+        self.0.borrow_mut().insert(key.into(), value.into());
+    }
+}
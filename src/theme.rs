@@ -0,0 +1,84 @@
+use std::{error::Error, fmt, str::FromStr};
+use storage::Storage;
+
+/// The storage key under which the user's theme choice is persisted.
+pub const THEME_KEY: &str = "eat-ou:theme";
+
+/// Represents the color scheme of the user interface.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Theme {
+    /// Dark text on a light background.
+    Light,
+    /// Light text on a dark background.
+    Dark,
+    /// Whichever of the above the user's system prefers (via `prefers-color-scheme`).
+    #[default]
+    System,
+}
+
+impl Theme {
+    /// Returns the name of the theme, as persisted and as used in the `data-theme` attribute.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::System => "system",
+        }
+    }
+
+    /// Returns the theme that follows this one when cycling through themes.
+    pub fn next(self) -> Self {
+        match self {
+            Theme::Light => Theme::Dark,
+            Theme::Dark => Theme::System,
+            Theme::System => Theme::Light,
+        }
+    }
+
+    /// Reads the persisted theme from the given storage.
+    ///
+    /// # Notes
+    /// Falls back to the default (`System`) if nothing was persisted, or if the persisted value
+    /// isn't recognized.
+    pub fn load(storage: &impl Storage) -> Self {
+        storage
+            .get(THEME_KEY)
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Persists the theme to the given storage.
+    pub fn save(self, storage: &impl Storage) {
+        storage.set(THEME_KEY, self.as_str());
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Represents a failure to parse a theme name.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseThemeError(String);
+
+impl fmt::Display for ParseThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "unknown theme \"{}\"", self.0)
+    }
+}
+
+impl Error for ParseThemeError {}
+
+impl FromStr for Theme {
+    type Err = ParseThemeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "light" => Ok(Theme::Light),
+            "dark" => Ok(Theme::Dark),
+            "system" => Ok(Theme::System),
+            _ => Err(ParseThemeError(s.into())),
+        }
+    }
+}
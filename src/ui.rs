@@ -1,17 +1,31 @@
+use listing::Listing;
 use std::{error::Error, fmt};
+use stdweb::unstable::TryInto;
+use stdweb::web::error::IError;
 use stdweb::web::Element as DOMElement;
-use stdweb::web::{document, IElement, INode, INonElementParentNode};
+use stdweb::web::{document, window, IElement, INode, INonElementParentNode, Node};
+use storage::LocalStorage;
+use theme::Theme;
 
 /// Represents the current state of the user interface.
-#[derive(PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum State {
     /// The user interface is presenting a restaurant for the user's consideration.
     Presenting,
     /// The app has run out of suggestions and is shrugging at the user.
     Terminated,
+    /// Nothing was open to begin with, so the app has no suggestions to make.
+    NothingOpen,
     /// The app is showing a list of restaurants to the user, instead of its normal shuffling
     /// interfface.
     Tabulating,
+    /// The restaurant data is still loading.
+    Loading,
+    /// The restaurant data couldn't be loaded, so the app can't do anything.
+    Failed,
+    /// The restaurant data loaded, but lists no restaurants at all, so there's nothing to
+    /// suggest.
+    NoData,
 }
 
 /// Represents a uniquely identifiable HTML element.
@@ -23,205 +37,793 @@ pub enum State {
 struct Element(&'static str);
 
 impl Element {
-    /// Returns the associated `id` (no leading `#`).
-    fn id(&self) -> &str {
-        self.0
-    }
-
     /// Returns the element uniquely associated with the appropriate `id`, if it exists.
     fn get(&self) -> Option<DOMElement> {
         document().get_element_by_id(self.0)
     }
 
-    /// Sets both a glyph (emoji or other character) and an accessible alternate text for a given
-    /// element.
+    /// Returns the element uniquely associated with the appropriate `id`, or an error if it
+    /// doesn't exist.
+    fn fetch(&self) -> Result<DOMElement, UiError> {
+        self.get().ok_or_else(|| self.error())
+    }
+
+    /// Sets the element's content, managing its accessible label to match.
     ///
-    /// # Notes
-    /// Use this method to set emoji labels.
-    fn set_glyph(&self, new: &str, alt: &str) -> Result<(), GetElementError> {
+    /// Glyphs are given an `aria-label` with their alternate text, while plain text has any
+    /// existing label removed (so that the text itself is read).
+    fn set_content(&self, content: &Content) -> Result<(), UiError> {
         self.get()
-            .map(|e| {
-                e.set_text_content(new);
-                let _ = e.set_attribute("aria-label", alt);
+            .map(|e| match *content {
+                Content::Glyph { glyph, alt } => {
+                    e.set_text_content(glyph);
+                    let _ = e.set_attribute("aria-label", alt);
+                }
+                Content::Text(ref text) => {
+                    e.set_text_content(text);
+                    e.remove_attribute("aria-label");
+                }
             })
             .ok_or_else(|| self.error())
     }
 
-    /// Provides an interface with which to set plain text content for a given element.
+    /// Sets plain text content for a given element, marking it as a polite live region first so
+    /// that screen readers announce the change.
+    fn set_live_text(&self, new: &str) -> Result<(), UiError> {
+        let element = self.fetch()?;
+        if let Some(setting) = live_setting(element.get_attribute("aria-live").as_deref()) {
+            let _ = element.set_attribute("aria-live", setting);
+        }
+        self.set_content(&Content::Text(new.into()))
+    }
+
+    /// Performs the given update with the CSS class `class` added to the element, so that
+    /// stylesheets can animate the change.
     ///
-    /// # Notes
-    /// When using emoji, use `set_glyph` instead. This may become a hard error in the future.
-    fn set_text(&self, new: &str) -> Result<(), GetElementError> {
+    /// The class is removed again on the next animation frame, once the update has been rendered.
+    fn with_transition_class<F>(&self, class: &str, update: F) -> Result<(), UiError>
+    where
+        F: FnOnce() -> Result<(), UiError>,
+    {
+        let element = self.fetch()?;
+        let _ = element.class_list().add(class);
+        let result = update();
+        let class = class.to_owned();
+        window().request_animation_frame(move |_| {
+            let _ = element.class_list().remove(&class);
+        });
+        result
+    }
+
+    /// Moves keyboard focus to the element.
+    fn focus(&self) -> Result<(), UiError> {
         self.get()
             .map(|e| {
-                e.set_text_content(new);
-                e.remove_attribute("aria-label");
+                js! { @{e}.focus(); };
             })
             .ok_or_else(|| self.error())
     }
 
-    /// Set the `data-{name}` attribute of the element to `value`.
-    ///
-    /// Useful for storing state information in the DOM.
-    fn set_data_attribute(&self, name: &str, value: &str) -> Result<(), GetElementError> {
-        self.get()
-            .map(|e| e.set_attribute(&format!("data-{}", name), value).unwrap())
-            .ok_or_else(|| self.error())
+    /// Returns the error associated with the inability to fetch this element from the DOM.
+    fn error(self) -> UiError {
+        UiError::MissingElement(self.0)
     }
+}
 
-    /// Removes the `data-{name}` attribute from the element.
-    fn clear_data_attribute(&self, name: &str) -> Result<(), GetElementError> {
-        self.get()
-            .map(|e| e.remove_attribute(&format!("data-{}", name)))
-            .ok_or_else(|| self.error())
+/// Something carrying `data-*` attributes, such as a DOM element.
+pub trait DataAttributes {
+    /// Returns whether the `data-{name}` attribute is present.
+    fn has_data_attribute(&self, name: &str) -> bool;
+
+    /// Sets the `data-{name}` attribute to `value`.
+    fn set_data_attribute(&mut self, name: &str, value: &str) -> Result<(), UiError>;
+
+    /// Removes the `data-{name}` attribute.
+    fn clear_data_attribute(&mut self, name: &str) -> Result<(), UiError>;
+
+    /// Sets the `data-{name}` attribute to `value` if it's absent, or removes it if it's present,
+    /// returning whether it's now set.
+    fn toggle_data_attribute(&mut self, name: &str, value: &str) -> Result<bool, UiError> {
+        if self.has_data_attribute(name) {
+            self.clear_data_attribute(name)?;
+            Ok(false)
+        } else {
+            self.set_data_attribute(name, value)?;
+            Ok(true)
+        }
+    }
+}
+
+impl DataAttributes for DOMElement {
+    fn has_data_attribute(&self, name: &str) -> bool {
+        self.has_attribute(&format!("data-{}", name))
     }
 
-    /// Returns whether the `data-{name}` attribute exists on the element.
-    fn has_data_attribute(&self, name: &str) -> Result<bool, GetElementError> {
+    fn set_data_attribute(&mut self, name: &str, value: &str) -> Result<(), UiError> {
+        self.set_attribute(&format!("data-{}", name), value)
+            .map_err(UiError::js)
+    }
+
+    fn clear_data_attribute(&mut self, name: &str) -> Result<(), UiError> {
+        self.remove_attribute(&format!("data-{}", name));
+        Ok(())
+    }
+}
+
+/// Reflects state information in the DOM, through the element's `data-*` attributes.
+impl DataAttributes for Element {
+    fn has_data_attribute(&self, name: &str) -> bool {
         self.get()
-            .map(|e| e.has_attribute(&format!("data-{}", name)))
-            .ok_or_else(|| self.error())
+            .is_some_and(|element| element.has_data_attribute(name))
     }
 
-    /// Returns the error associated with the inability to fetch this element from the DOM.
-    fn error(self) -> GetElementError {
-        GetElementError::new(self)
+    fn set_data_attribute(&mut self, name: &str, value: &str) -> Result<(), UiError> {
+        self.fetch()?.set_data_attribute(name, value)
+    }
+
+    fn clear_data_attribute(&mut self, name: &str) -> Result<(), UiError> {
+        self.fetch()?.clear_data_attribute(name)
     }
 }
 
-/// An internal error representing a failure to fetch a particular element.
-///
-/// This error typically occurs if the requested element does not exist in the DOM.
-#[derive(Debug)]
-struct GetElementError {
-    element: Element,
+/// Something `clear` can reset (see `clear_elements`), such as an element of the page.
+pub trait Clear: DataAttributes {
+    /// Removes the content (any text and child elements) of the element.
+    fn clear_content(&mut self) -> Result<(), UiError>;
+}
+
+impl Clear for Element {
+    fn clear_content(&mut self) -> Result<(), UiError> {
+        self.set_content(&Content::Text(String::new()))
+    }
 }
 
-impl GetElementError {
-    /// Creates an error describing a generic failure to fetch the given element.
-    fn new(element: Element) -> Self {
-        Self { element }
+/// Represents an error encountered while updating the user interface.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UiError {
+    /// The element with the given ID (no leading `#`) could not be found in the DOM.
+    ///
+    /// This error typically occurs if the page's markup is missing an element the app relies on.
+    MissingElement(&'static str),
+    /// The elements with the given IDs, all of which the app requires, are missing from the DOM.
+    MissingElements(Vec<&'static str>),
+    /// A call into the DOM failed, with the given message.
+    Js(String),
+}
+
+impl UiError {
+    /// Creates an error from a failed call into the DOM.
+    fn js(error: impl IError) -> Self {
+        UiError::Js(error.message())
     }
 }
 
-impl fmt::Display for GetElementError {
+impl fmt::Display for UiError {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(
-            f,
-            "Failed to fetch element with ID \"{}\"",
-            self.element.id()
-        )
+        match *self {
+            UiError::MissingElement(id) => write!(f, "Failed to fetch element with ID \"{}\"", id),
+            UiError::MissingElements(ref ids) => {
+                let ids = ids
+                    .iter()
+                    .map(|id| format!("\"{}\"", id))
+                    .collect::<Vec<_>>();
+                write!(f, "Missing required elements with IDs {}", ids.join(", "))
+            }
+            UiError::Js(ref message) => write!(f, "DOM operation failed: {}", message),
+        }
     }
 }
 
-impl Error for GetElementError {}
+impl Error for UiError {}
+
+/// The IDs of the elements the app requires.
+pub const REQUIRED_ELEMENTS: [&str; 13] = [
+    "next",
+    "next_text",
+    "list",
+    "list_text",
+    "theme",
+    "theme_text",
+    "place",
+    "times",
+    "notes",
+    "phone",
+    "listings",
+    "search",
+    "share",
+];
+
+/// Returns the `aria-live` setting to give an element about to announce new text, given its
+/// current one (if any).
+///
+/// Elements without a setting become polite live regions; any existing setting (including `off`)
+/// is left alone, so `None` is returned.
+pub fn live_setting(current: Option<&str>) -> Option<&'static str> {
+    match current {
+        Some(_) => None,
+        None => Some("polite"),
+    }
+}
+
+/// The IDs of the elements whose content `clear` removes.
+pub const CLEARED_ELEMENTS: [&str; 6] =
+    ["place", "times", "notes", "phone", "next_text", "listings"];
+
+/// The `data-*` attributes (by element ID and attribute name, without the `data-` prefix) that
+/// reflect the state, which `clear` removes.
+pub const STATE_ATTRIBUTES: [(&str, &str); 2] =
+    [("next", "terminated"), ("listings", "tabulating")];
+
+/// Checks that every required element exists, according to the given predicate.
+///
+/// # Errors
+/// Returns `UiError::MissingElements`, naming every missing element, if any are missing.
+pub fn verify_elements<F>(exists: F) -> Result<(), UiError>
+where
+    F: Fn(&str) -> bool,
+{
+    let missing = REQUIRED_ELEMENTS
+        .iter()
+        .cloned()
+        .filter(|id| !exists(id))
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(UiError::MissingElements(missing))
+    }
+}
 
-/// Sets the state of the application user interface.
+/// Checks that every element the app requires exists in the DOM.
 ///
-/// The current application state is stored in the DOM.
+/// Call this before anything else touches the DOM, so that broken markup causes a clear failure
+/// up front rather than a panic partway through handling an event.
+///
+/// # Errors
+/// Returns `UiError::MissingElements`, naming every missing element, if any are missing.
+pub fn verify_dom() -> Result<(), UiError> {
+    verify_elements(|id| document().get_element_by_id(id).is_some())
+}
+
+/// Represents what an element displays.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Content {
+    /// A glyph (emoji or other character), along with accessible alternate text for it.
+    Glyph {
+        glyph: &'static str,
+        alt: &'static str,
+    },
+    /// Plain text, which screen readers can read as-is.
+    Text(String),
+}
+
+impl Content {
+    /// Returns the accessible label the element should carry, if any.
+    pub fn label(&self) -> Option<&str> {
+        match *self {
+            Content::Glyph { alt, .. } => Some(alt),
+            Content::Text(_) => None,
+        }
+    }
+}
+
+/// Describes the content of each element (by ID) that changes with the given state.
+///
+/// Elements not listed keep their current content.
+pub fn state_contents(state: State) -> Vec<(&'static str, Content)> {
+    let glyph = |glyph, alt| Content::Glyph { glyph, alt };
+    let text = |text: &str| Content::Text(text.into());
+    match state {
+        State::NoData => vec![
+            ("list_text", glyph("📖", "Show as list")),
+            ("place", glyph("📭", "No restaurants")),
+            (
+                "times",
+                text("There aren't any restaurants to choose from yet."),
+            ),
+        ],
+        State::Terminated => vec![
+            ("next_text", glyph("🔄", "Start over")),
+            ("list_text", glyph("📖", "Show as list")),
+            ("place", glyph("🤷", "Out of suggestions")),
+            ("times", text("You've seen them all. Try again?")),
+        ],
+        State::NothingOpen => vec![
+            ("next_text", glyph("🔄", "Check again")),
+            ("list_text", glyph("📖", "Show as list")),
+            ("place", glyph("🕒", "Nothing open")),
+            ("times", text("Nothing's open right now. Check back later?")),
+        ],
+        State::Presenting => vec![
+            ("next_text", glyph("👎", "Next suggestion")),
+            ("list_text", glyph("📖", "Show as list")),
+            ("place", text("")),
+            ("times", text("")),
+        ],
+        State::Tabulating => vec![("list_text", glyph("🔀", "Exit list mode"))],
+        State::Loading => vec![
+            ("place", glyph("⏳", "Loading")),
+            ("times", text("Finding out what's open…")),
+        ],
+        State::Failed => vec![
+            ("place", glyph("⚠️", "Something went wrong")),
+            (
+                "times",
+                text("The list of restaurants couldn't be loaded. Try again later?"),
+            ),
+        ],
+    }
+}
+
+/// Describes the content of the theme button while the given theme is in use.
+pub fn theme_content(theme: Theme) -> Content {
+    let (glyph, alt) = match theme {
+        Theme::Light => ("☀️", "Light theme"),
+        Theme::Dark => ("🌙", "Dark theme"),
+        Theme::System => ("🌓", "System theme"),
+    };
+    Content::Glyph { glyph, alt }
+}
+
+/// Returns the theme the user last chose, or `Theme::System` if they haven't chosen one.
+pub fn get_theme() -> Theme {
+    Theme::load(&LocalStorage)
+}
+
+/// Applies the given theme, and persists it for future visits.
+///
+/// The theme is reflected in the `data-theme` attribute of the root (`<html>`) element, as either
+/// `light` or `dark`; `Theme::System` is resolved to one of those using the
+/// `prefers-color-scheme` media query.
 ///
 /// # Errors
 /// This method returns `Err` if an error occurs while updating (or fetching) any elements.
-pub fn set_state(state: State) -> Result<(), impl Error> {
-    let next_text = Element("next_text");
-    let list_text = Element("list_text");
-    let place = Element("place");
-    let times = Element("times");
-    let next_button = Element("next");
-    let listings = Element("listings");
+pub fn set_theme(theme: Theme) -> Result<(), UiError> {
+    theme.save(&LocalStorage);
+    let resolved = match theme {
+        Theme::System => {
+            let dark = js! {
+                return window.matchMedia("(prefers-color-scheme: dark)").matches;
+            };
+            if dark.try_into().unwrap_or(false) {
+                Theme::Dark
+            } else {
+                Theme::Light
+            }
+        }
+        theme => theme,
+    };
+    document()
+        .document_element()
+        .ok_or_else(|| UiError::Js("the document has no root element".into()))?
+        .set_attribute("data-theme", resolved.as_str())
+        .map_err(UiError::js)?;
+    Element("theme_text").set_content(&theme_content(theme))
+}
+
+/// Updates the application user interface to reflect the given state.
+///
+/// The DOM only reflects the state (including via `data-*` attributes); the state itself is owned
+/// by `app::AppState`.
+///
+/// # Errors
+/// This method returns `Err` if an error occurs while updating (or fetching) any elements.
+pub fn set_state(state: State) -> Result<(), UiError> {
+    let mut next_button = Element("next");
+    let mut listings = Element("listings");
+    if state == State::Tabulating {
+        show_table()?;
+    }
+    for (id, content) in state_contents(state) {
+        Element(id).set_content(&content)?;
+    }
     match state {
-        State::Terminated => {
-            next_text.set_glyph("🔄", "Start over")?;
-            list_text.set_glyph("📖", "Show as list")?;
-            place.set_glyph("🤷", "Out of suggestions")?;
-            times.set_text("There aren't any places left to eat. Try again?")?;
+        State::Terminated | State::NothingOpen | State::Loading | State::Failed | State::NoData => {
             next_button.set_data_attribute("terminated", "1")?;
             listings.clear_data_attribute("tabulating")?;
         }
         State::Presenting => {
-            next_text.set_glyph("👎", "Next suggestion")?;
-            list_text.set_glyph("📖", "Show as list")?;
-            place.set_text("")?;
-            times.set_text("")?;
             next_button.clear_data_attribute("terminated")?;
             listings.clear_data_attribute("tabulating")?;
         }
         State::Tabulating => {
-            show_table()?;
-            list_text.set_glyph("🔀", "Exit list mode")?;
             listings.set_data_attribute("tabulating", "1")?;
         }
     }
-    let _consuming = state;
-    let ok: Result<(), GetElementError> = Ok(());
-    ok
+    Ok(())
 }
 
-/// Returns the state of the application user interface.
+/// Resets every element the app manages to a blank state, so nothing lingers from a previous
+/// mode: the suggestion and button text are emptied, the state attributes are removed, and the
+/// list is emptied.
 ///
-/// The current application state is stored in the DOM.
-pub fn get_state() -> Result<State, impl Error> {
-    Element("listings")
-        .has_data_attribute("tabulating")
-        .map(|a| {
-            if a {
-                State::Tabulating
-            } else {
-                Element("next")
-                    .has_data_attribute("terminated")
-                    .map(|b| {
-                        if b {
-                            State::Terminated
-                        } else {
-                            State::Presenting
-                        }
-                    })
-                    .unwrap()
-            }
-        })
+/// # Errors
+/// This method returns `Err` if an error occurs while updating (or fetching) any elements.
+pub fn clear() -> Result<(), UiError> {
+    clear_elements(Element)
+}
+
+/// Resets the elements with the IDs in `CLEARED_ELEMENTS` and `STATE_ATTRIBUTES`, as looked up
+/// by `element`: the content of each of the former is removed, as is each of the latter's state
+/// attribute.
+///
+/// This is the logic of `clear`, apart from the DOM.
+///
+/// # Errors
+/// This method returns `Err` if an error occurs while updating any element.
+pub fn clear_elements<E, F>(mut element: F) -> Result<(), UiError>
+where
+    E: Clear,
+    F: FnMut(&'static str) -> E,
+{
+    for &id in CLEARED_ELEMENTS.iter() {
+        element(id).clear_content()?;
+    }
+    for &(id, name) in STATE_ATTRIBUTES.iter() {
+        element(id).clear_data_attribute(name)?;
+    }
+    Ok(())
+}
+
+/// The CSS class applied to the suggestion while it changes, when animation is enabled.
+pub const TRANSITION_CLASS: &str = "fade";
+
+/// Checks whether the user has asked their system to minimize non-essential motion.
+///
+/// Depends on JavaScript APIs (the `prefers-reduced-motion` media query).
+pub fn prefers_reduced_motion() -> bool {
+    js! { return window.matchMedia("(prefers-reduced-motion: reduce)").matches; }
+        .try_into()
+        .unwrap_or(false)
 }
 
 /// Updates the application user interface to reflect the new suggestion.
-pub fn set_suggestion(name: &str, hours: &str) -> Result<(), impl Error> {
-    Element("place").set_text(&name)?;
-    Element("times").set_text(&hours)
+///
+/// The name links to the restaurant's website when a URL is given. The restaurant's note and
+/// phone number (if any) are shown on their own lines beneath the hours, with the note in full.
+/// The name and hours are live regions, so the new suggestion is announced by screen readers.
+///
+/// If a transition class is given, it's added to the suggestion around the update (see
+/// `Element::with_transition_class`); the animation itself is left to the stylesheet. Callers
+/// should use `app::AppState::transition_class`, which never animates for users who prefer
+/// reduced motion.
+pub fn set_suggestion(
+    name: &str,
+    url: Option<&str>,
+    hours: &str,
+    note: Option<&str>,
+    phone: Option<&str>,
+    transition: Option<&str>,
+) -> Result<(), UiError> {
+    let update = || {
+        Element("place").set_live_text(name)?;
+        if url.is_some() {
+            set_link(&Element("place").fetch()?, name, url)?;
+        }
+        Element("times").set_live_text(hours)?;
+        Element("notes").set_content(&Content::Text(note.unwrap_or("").into()))?;
+        set_link(
+            &Element("phone").fetch()?,
+            phone.unwrap_or(""),
+            phone.map(phone_link).as_deref(),
+        )
+    };
+    match transition {
+        Some(class) => Element("place").with_transition_class(class, update),
+        None => update(),
+    }
+}
+
+/// Moves keyboard focus to the current suggestion, so keyboard and screen reader users land on it.
+pub fn focus_suggestion() -> Result<(), UiError> {
+    Element("place").focus()
 }
 
-/// Shows the "next" and "list" buttons, which are hidden by default.
+/// Tells the user when the next restaurant opens, while nothing is open.
+pub fn set_next_opening(name: &str, when: &str) -> Result<(), UiError> {
+    Element("times").set_content(&Content::Text(format!("Next up: {} opens {}.", name, when)))
+}
+
+/// Shows a summary of the restaurant data (see `app::Stats`) in the footer, if the page has one.
+pub fn set_stats(text: &str) {
+    if let Some(footer) = Element("stats").get() {
+        footer.set_text_content(text);
+    }
+}
+
+/// Shows the "next", "list", and "theme" buttons, which are hidden by default.
 ///
-/// Invoked in the `start()` method, when we know script execution works.
+/// Invoked once the restaurant data has loaded, when we know script execution works.
 pub fn unhide_buttons() {
     // We can't currently change the style of an element with stdnet,
     // so call into JavaScript to unhide the button.
     js! {
         document.getElementById("next").style.display = "initial";
         document.getElementById("list").style.display = "initial";
+        document.getElementById("theme").style.display = "initial";
+        document.getElementById("share").style.display = "initial";
     }
 }
 
-pub fn tabulate(restaurants: Vec<(String, String, bool)>) {
-    let wrapper = Element("listings").get().unwrap();
-    // Clear the list first.
-    while let Some(ref node) = wrapper.first_child() {
-        wrapper.remove_child(node).unwrap();
+/// Copies the given text to the clipboard.
+///
+/// Browsers without clipboard access (or that refuse it) get the text in the console instead.
+pub fn copy_to_clipboard(text: &str) {
+    js! { @(no_return)
+        var text = @{text};
+        if (navigator.clipboard) {
+            navigator.clipboard.writeText(text).catch(function() { console.log(text); });
+        } else {
+            console.log(text);
+        }
     }
-    // Now, add restaurants to the list.
-    for restaurant in restaurants {
-        let element = document().create_element("div").unwrap();
-        element.set_attribute("class", "listing").unwrap();
-        let name = document().create_element("h2").unwrap();
-        name.set_text_content(&restaurant.0);
-        let hours = document().create_element("h3").unwrap();
-        hours.set_text_content(&restaurant.1);
-        element.append_child(&name);
-        element.append_child(&hours);
-        wrapper.append_child(&element);
+}
+
+/// Creates a new element with the given tag name.
+fn create_element(tag: &str) -> Result<DOMElement, UiError> {
+    document()
+        .create_element(tag)
+        .map_err(|e| UiError::Js(e.to_string()))
+}
+
+/// A step in updating a list of keyed rows in place, as planned by `plan_rows`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RowStep {
+    /// The existing row with the given index is already in place, and stays there.
+    Keep(usize),
+    /// The existing row with the given index is moved into place.
+    Move(usize),
+    /// A new row is created in place.
+    Insert,
+    /// The existing row with the given index is no longer wanted, and is removed.
+    Remove(usize),
+}
+
+/// Plans how to turn a list of rows with the `existing` keys into one with the `wanted` keys,
+/// reusing existing rows wherever possible.
+///
+/// The plan starts with one step per wanted key, in order: each either keeps or moves the first
+/// unused existing row with that key, or inserts a new one. Rows are placed at a cursor, which
+/// starts at the first existing row and passes over each row kept, as well as any row that isn't
+/// wanted at all; moved and inserted rows go just before it. A `Remove` step follows for each
+/// existing row left unused, so rows that are only removed leave the others where they are.
+pub fn plan_rows<K: PartialEq>(existing: &[K], wanted: &[K]) -> Vec<RowStep> {
+    let mut used = vec![false; existing.len()];
+    let found = wanted
+        .iter()
+        .map(|key| {
+            let found = (0..existing.len()).find(|&i| !used[i] && existing[i] == *key);
+            if let Some(i) = found {
+                used[i] = true;
+            }
+            found
+        })
+        .collect::<Vec<_>>();
+    // Rows no wanted key uses are only removed, so the cursor passes over them as if placed.
+    let mut passed = used.iter().map(|&used| !used).collect::<Vec<_>>();
+    let mut cursor = 0;
+    let mut steps = Vec::with_capacity(wanted.len());
+    for found in found {
+        while cursor < existing.len() && passed[cursor] {
+            cursor += 1;
+        }
+        steps.push(match found {
+            Some(i) if i == cursor => RowStep::Keep(i),
+            Some(i) => RowStep::Move(i),
+            None => RowStep::Insert,
+        });
+        if let Some(i) = found {
+            passed[i] = true;
+        }
     }
-    set_state(State::Tabulating).unwrap();
+    steps.extend(
+        (0..existing.len())
+            .filter(|&i| !used[i])
+            .map(RowStep::Remove),
+    );
+    steps
+}
+
+/// The key identifying a row of the list: a group header or a listing.
+#[derive(PartialEq)]
+enum RowKey {
+    Group(String),
+    Listing(String),
+}
+
+/// Shows the given groups of listings, each under a header naming the group.
+///
+/// The list is updated in place: headers (keyed by their `data-group` attribute) and listings
+/// (keyed by their `data-id` attribute) already shown are reused, with their text updated, and
+/// only those no longer needed are removed (see `plan_rows`). This avoids flicker when the list is
+/// shown again with little changed (e.g. after toggling a filter).
+///
+/// # Errors
+/// This method returns `Err` if an error occurs while building the list or updating the state.
+pub fn tabulate(groups: Vec<(String, Vec<Listing>)>) -> Result<(), UiError> {
+    let wrapper = Element("listings").fetch()?;
+    let mut existing = Vec::new();
+    for node in wrapper.child_nodes().iter() {
+        let element: Option<DOMElement> = node.clone().try_into().ok();
+        let key = element.as_ref().and_then(|element| {
+            element
+                .get_attribute("data-group")
+                .map(RowKey::Group)
+                .or_else(|| element.get_attribute("data-id").map(RowKey::Listing))
+        });
+        match (element, key) {
+            (Some(element), Some(key)) => existing.push((key, element)),
+            _ => {
+                wrapper.remove_child(&node).map_err(UiError::js)?;
+            }
+        }
+    }
+    let mut wanted = Vec::new();
+    for (group, listings) in &groups {
+        wanted.push(RowKey::Group(group.clone()));
+        wanted.extend(listings.iter().map(|l| RowKey::Listing(l.id.clone())));
+    }
+    let (keys, elements): (Vec<_>, Vec<_>) = existing.into_iter().unzip();
+    let mut steps = plan_rows(&keys, &wanted).into_iter();
+    // Everything before the cursor is in its final place, apart from rows about to be removed.
+    let mut cursor = wrapper.first_child();
+    for (group, listings) in groups {
+        let header = place(&wrapper, &elements, steps.next(), &mut cursor, || {
+            let header = create_element("h2")?;
+            header
+                .set_attribute("class", "group")
+                .map_err(UiError::js)?;
+            header
+                .set_attribute("data-group", &group)
+                .map_err(UiError::js)?;
+            Ok(header)
+        })?;
+        header.set_text_content(&group);
+        for listing in listings {
+            let element = place(&wrapper, &elements, steps.next(), &mut cursor, || {
+                create_listing(&listing.id)
+            })?;
+            let children = element.child_nodes().iter().collect::<Vec<_>>();
+            if let [name, hours, note, phone] = children.as_slice() {
+                set_link(name, &listing.name, listing.url.as_deref())?;
+                if let Some(price) = listing.price {
+                    name.append_child(&document().create_text_node(&format!(" · {}", price)));
+                }
+                hours.set_text_content(&listing.hours);
+                note.set_text_content(listing.note.as_deref().unwrap_or(""));
+                let number = listing.phone.as_deref();
+                set_link(
+                    phone,
+                    number.unwrap_or(""),
+                    number.map(phone_link).as_deref(),
+                )?;
+            }
+            let classes = element.class_list();
+            let _ = if listing.temporarily_closed {
+                classes.add("temporarily-closed")
+            } else {
+                classes.remove("temporarily-closed")
+            };
+        }
+    }
+    for step in steps {
+        if let RowStep::Remove(i) = step {
+            wrapper.remove_child(&elements[i]).map_err(UiError::js)?;
+        }
+    }
+    set_state(State::Tabulating)
+}
+
+/// Highlights the listing for the restaurant with the given ID (if any), with the `selected`
+/// class, removing the highlight from every other listing.
+///
+/// # Errors
+/// This method returns `Err` if the list can't be found.
+pub fn set_selected(id: Option<&str>) -> Result<(), UiError> {
+    let wrapper = Element("listings").fetch()?;
+    for node in wrapper.child_nodes().iter() {
+        let element: DOMElement = match node.try_into() {
+            Ok(element) => element,
+            Err(_) => continue,
+        };
+        let listed = match element.get_attribute("data-id") {
+            Some(listed) => listed,
+            None => continue,
+        };
+        if id == Some(listed.as_str()) {
+            let _ = element.class_list().add("selected");
+            let _ = element.set_attribute("aria-selected", "true");
+            js! { @{element}.scrollIntoView({ block: "nearest" }); };
+        } else {
+            let _ = element.class_list().remove("selected");
+            element.remove_attribute("aria-selected");
+        }
+    }
+    Ok(())
+}
+
+/// Creates an (empty) listing for the restaurant with the given ID.
+fn create_listing(id: &str) -> Result<DOMElement, UiError> {
+    let element = create_element("div")?;
+    element
+        .set_attribute("class", "listing")
+        .map_err(UiError::js)?;
+    element.set_attribute("data-id", id).map_err(UiError::js)?;
+    element.append_child(&create_element("h2")?);
+    element.append_child(&create_element("h3")?);
+    for class in &["notes", "phone"] {
+        let line = create_element("p")?;
+        line.set_attribute("class", class).map_err(UiError::js)?;
+        element.append_child(&line);
+    }
+    Ok(element)
+}
+
+/// Replaces the content of `node` with `text`, linking it to `href` if one is given.
+fn set_link<N: INode>(node: &N, text: &str, href: Option<&str>) -> Result<(), UiError> {
+    let href = match href {
+        Some(href) => href,
+        None => {
+            node.set_text_content(text);
+            return Ok(());
+        }
+    };
+    let link = create_element("a")?;
+    link.set_attribute("href", href).map_err(UiError::js)?;
+    if !href.starts_with("tel:") {
+        link.set_attribute("target", "_blank")
+            .map_err(UiError::js)?;
+        link.set_attribute("rel", "noopener").map_err(UiError::js)?;
+    }
+    link.set_text_content(text);
+    node.set_text_content("");
+    node.append_child(&link);
+    Ok(())
+}
+
+/// Returns the link that dials the given phone number.
+fn phone_link(phone: &str) -> String {
+    let number = phone
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '+')
+        .collect::<String>();
+    format!("tel:{}", number)
+}
+
+/// Carries out one step planned by `plan_rows` on the rows `elements` within `wrapper`, returning
+/// the row placed at the cursor (creating it with `create` if need be) and advancing the cursor
+/// past any row kept.
+fn place<F>(
+    wrapper: &DOMElement,
+    elements: &[DOMElement],
+    step: Option<RowStep>,
+    cursor: &mut Option<Node>,
+    create: F,
+) -> Result<DOMElement, UiError>
+where
+    F: FnOnce() -> Result<DOMElement, UiError>,
+{
+    let element = match step {
+        Some(RowStep::Keep(i)) => {
+            *cursor = elements[i].next_sibling();
+            return Ok(elements[i].clone());
+        }
+        Some(RowStep::Move(i)) => elements[i].clone(),
+        _ => create()?,
+    };
+    match *cursor {
+        Some(ref node) => {
+            wrapper
+                .insert_before(&element, node)
+                .map_err(|e| UiError::Js(e.to_string()))?;
+        }
+        None => wrapper.append_child(&element),
+    }
+    Ok(element)
 }
 
 /// Shows the list of open restaurants.
-fn show_table() -> Result<(), GetElementError> {
+fn show_table() -> Result<(), UiError> {
     Element("listings")
         .get()
         .map(|table| {
@@ -233,20 +835,22 @@ fn show_table() -> Result<(), GetElementError> {
 }
 
 /// Switches from tabulation mode to the last-used mode.
-pub fn stop_tabulation() {
-    hide_table();
-    Element("listings")
-        .clear_data_attribute("tabulating")
-        .unwrap();
+///
+/// # Errors
+/// This method returns `Err` if an error occurs while updating (or fetching) any elements.
+pub fn stop_tabulation() -> Result<(), UiError> {
+    hide_table()?;
+    Element("listings").clear_data_attribute("tabulating")
 }
 
 /// Hides the list of open restaurants.
-fn hide_table() {
-    let table = Element("listings").get().unwrap();
+fn hide_table() -> Result<(), UiError> {
+    let table = Element("listings").fetch()?;
     js! {
         @{table}.style.display = "none";
     }
-    Element("list_text")
-        .set_glyph("📖", "Show as list")
-        .unwrap();
+    Element("list_text").set_content(&Content::Glyph {
+        glyph: "📖",
+        alt: "Show as list",
+    })
 }
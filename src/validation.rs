@@ -0,0 +1,207 @@
+use schedule::{Day, Restaurant};
+use std::fmt;
+
+/// Describes how serious a problem with the restaurant data is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The data is usable, but probably not what was meant.
+    Warning,
+    /// The data is wrong, and will lead to incorrect suggestions.
+    Error,
+}
+
+/// Describes a problem found while validating the restaurant data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationIssue {
+    /// How serious the problem is.
+    pub severity: Severity,
+    /// The name of the restaurant with the problem.
+    pub restaurant: String,
+    /// The day the problem occurs on, if it's specific to one day.
+    pub day: Option<Day>,
+    /// A human-readable description of the problem.
+    pub message: String,
+}
+
+impl ValidationIssue {
+    /// Creates an issue with the given restaurant.
+    fn new(severity: Severity, restaurant: &Restaurant, day: Option<Day>, message: String) -> Self {
+        Self {
+            severity,
+            restaurant: restaurant.name.clone(),
+            day,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{:?}: {}", self.severity, self.restaurant)?;
+        if let Some(day) = self.day {
+            write!(f, " → {}", day.key())?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Checks the restaurant data for problems that don't prevent it from being loaded.
+///
+/// The following are reported:
+/// - restaurants sharing a name (an error)
+/// - restaurants with different names sharing an ID (an error)
+/// - hours ending before they start (a warning, since overnight hours should be written with
+///   hours 24–47, e.g. 21:00–26:00)
+/// - hours starting and ending at the same time (a warning, since 24-hour days should be written
+///   as 0:00–24:00)
+/// - breaks falling outside the hours they belong to (a warning)
+/// - restaurants without hours on any day (a warning)
+/// - aliases matching another restaurant's name, ignoring case (a warning, since searches for it
+///   would find both)
+/// - dietary options the app doesn't recognize (a warning)
+/// - prices outside the range `$`–`$$$$` (an error)
+/// - weights that aren't positive (an error)
+/// - URLs that don't look like web addresses (a warning)
+///
+/// # Notes
+/// Malformed times (e.g. minutes of 60 or more) are rejected when the data is loaded, so they
+/// never reach this pass.
+pub fn validate(restaurants: &[Restaurant]) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    for (index, restaurant) in restaurants.iter().enumerate() {
+        if restaurants[..index]
+            .iter()
+            .any(|other| other.name == restaurant.name)
+        {
+            issues.push(ValidationIssue::new(
+                Severity::Error,
+                restaurant,
+                None,
+                "another restaurant has the same name".into(),
+            ));
+        }
+        if let Some(other) = restaurants[..index]
+            .iter()
+            .find(|other| other.id() == restaurant.id() && other.name != restaurant.name)
+        {
+            issues.push(ValidationIssue::new(
+                Severity::Error,
+                restaurant,
+                None,
+                format!(
+                    "ID \"{}\" is already used by {}",
+                    restaurant.id(),
+                    other.name
+                ),
+            ));
+        }
+        for alias in restaurant.aliases() {
+            let collides = restaurants.iter().enumerate().any(|(other_index, other)| {
+                other_index != index && other.name.to_lowercase() == alias.to_lowercase()
+            });
+            if collides {
+                issues.push(ValidationIssue::new(
+                    Severity::Warning,
+                    restaurant,
+                    None,
+                    format!("alias \"{}\" is another restaurant's name", alias),
+                ));
+            }
+        }
+        if restaurant.days_open_count() == 0 {
+            issues.push(ValidationIssue::new(
+                Severity::Warning,
+                restaurant,
+                None,
+                "no hours on any day".into(),
+            ));
+        }
+        if let Some(price) = restaurant.price().filter(|p| !p.is_valid()) {
+            issues.push(ValidationIssue::new(
+                Severity::Error,
+                restaurant,
+                None,
+                format!(
+                    "price level {} is out of range; use 1–4 (or \"$\" through \"$$$$\")",
+                    price.level()
+                ),
+            ));
+        }
+        let weight = restaurant.weight();
+        if !(weight > 0.0 && weight.is_finite()) {
+            issues.push(ValidationIssue::new(
+                Severity::Error,
+                restaurant,
+                None,
+                format!("weight {} must be a positive number", weight),
+            ));
+        }
+        if let Some(url) = restaurant.url().filter(|url| !looks_like_url(url)) {
+            issues.push(ValidationIssue::new(
+                Severity::Warning,
+                restaurant,
+                None,
+                format!("URL \"{}\" doesn't look like a web address", url),
+            ));
+        }
+        for option in restaurant.dietary().iter().filter(|d| !d.is_known()) {
+            issues.push(ValidationIssue::new(
+                Severity::Warning,
+                restaurant,
+                None,
+                format!("unknown dietary option \"{}\"", option),
+            ));
+        }
+        for (day, hours) in restaurant.weekly_hours() {
+            let (start, end) = (hours.start(), hours.end());
+            if end == start {
+                issues.push(ValidationIssue::new(
+                    Severity::Warning,
+                    restaurant,
+                    Some(day),
+                    format!(
+                        "hours start and end at {}; write 24-hour days as 0:00–24:00",
+                        start
+                    ),
+                ));
+            } else if end < start {
+                issues.push(ValidationIssue::new(
+                    Severity::Warning,
+                    restaurant,
+                    Some(day),
+                    format!(
+                        "hours end ({}) before they start ({}); write overnight hours as 24–47 \
+                         (e.g. 26:00)",
+                        end, start
+                    ),
+                ));
+            }
+            for stray in hours.stray_breaks() {
+                issues.push(ValidationIssue::new(
+                    Severity::Warning,
+                    restaurant,
+                    Some(day),
+                    format!(
+                        "break {}–{} falls outside its hours",
+                        stray.start, stray.end
+                    ),
+                ));
+            }
+        }
+    }
+    issues
+}
+
+/// Returns whether the given URL plausibly points at a web page: an `http` or `https` address
+/// with a host and no whitespace.
+fn looks_like_url(url: &str) -> bool {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"));
+    match rest {
+        Some(rest) => {
+            !rest.is_empty() && !rest.starts_with('/') && !url.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
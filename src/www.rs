@@ -3,140 +3,280 @@ extern crate stdweb;
 
 extern crate eat_ou;
 
+use eat_ou::app::{self, AppState, Step};
 use eat_ou::*;
+use std::{cell::RefCell, collections::HashMap, rc::Rc, time::Duration};
 use stdweb::{
     unstable::TryInto,
     web::{
         document,
-        event::{ClickEvent, IKeyboardEvent, KeyUpEvent},
-        Date, IEventTarget, INonElementParentNode,
+        event::{ClickEvent, IKeyboardEvent, InputEvent, KeyUpEvent},
+        IEventTarget, INonElementParentNode,
     },
+    Once, Value,
 };
 
-/// Returns the current day as an instance of `Day`.
+/// Returns the moment the session judges restaurants at (see `app::session_clock`), an hour from
+/// now with `?soon` in the URL.
 ///
-/// Depends on JavaScript APIs for time information.
-fn today() -> Day {
-    Date::new().get_day().into()
+/// Suggestions, list mode, exports, and stats all go through this, so they agree on what's
+/// viable.
+fn session_clock(clock: &impl Clock) -> FixedClock {
+    app::session_clock(clock, query::get(&get_query(), "soon").is_some())
 }
 
-/// Returns the approximate current time as an instance of `Time`.
+/// Get viable restaurants based on the session's moment (see `session_clock`), filter, and
+/// travel time.
+fn get_viable(app: &AppState, clock: &impl Clock) -> Vec<Restaurant> {
+    let clock = session_clock(clock);
+    app::get_viable(&app.restaurants, &app.filters, app.viability, &clock)
+}
+
+/// Returns the URL's query string (e.g. `?weekend`).
+fn get_query() -> String {
+    js! { return window.location.search; }
+        .try_into()
+        .unwrap_or_default()
+}
+
+/// Builds the restaurant filter requested through the URL's query string.
 ///
-/// Depends on JavaScript APIs for time information.
-fn now() -> Time {
-    let now = Date::new();
-    Time::new(now.get_hours(), now.get_minutes())
+/// Supports `?weekend` to restrict suggestions and listings to places open this weekend,
+/// `?tags=vegan,halal` to restrict them to places with all of the given tags (adding `&untagged`
+/// lets places without any tags through), and `?exclude=pizza` to leave out places with any of the
+/// given tags. `?max_price=2` leaves out places costing more than `$$`, and `?zone=research`
+/// leaves out places known to be elsewhere on campus (see `preferred_zone`, which remembers it).
+fn get_filter() -> RestaurantFilter {
+    let search = get_query();
+    let mut filter = RestaurantFilter::new()
+        .with_tags(query::get_list(&search, "tags"))
+        .without_tags(query::get_list(&search, "exclude"));
+    if query::get(&search, "weekend").is_some() {
+        filter = filter.open_on_weekend();
+    }
+    if query::get(&search, "untagged").is_some() {
+        filter = filter.include_untagged();
+    }
+    let max_price = query::get(&search, "max_price")
+        .and_then(|level| level.parse().ok())
+        .and_then(Price::new);
+    if let Some(price) = max_price {
+        filter = filter.max_price(price);
+    }
+    if let Some(zone) = preferred_zone(&search, &LocalStorage) {
+        filter = filter.zone(zone);
+    }
+    filter
 }
-/// Get viable restaurants based on the user's local time.
+
+/// Reads the travel time allowance from the body's `data-travel-minutes` attribute.
 ///
-/// Depends on JavaScript APIs for time information.
-fn get_viable() -> Vec<Restaurant> {
-    Restaurant::get_list()
-        .into_iter()
-        .filter(|r| r.is_viable(today(), now()))
-        .collect()
+/// Falls back to `DEFAULT_TRAVEL_TIME` if the attribute is absent or invalid.
+fn get_travel_time() -> Duration {
+    let minutes: Option<String> = js! { return document.body.dataset.travelMinutes || null; }
+        .try_into()
+        .unwrap_or_default();
+    minutes
+        .and_then(|m| m.trim().parse::<u64>().ok())
+        .map_or(DEFAULT_TRAVEL_TIME, |m| Duration::from_secs(m * 60))
+}
+
+/// Determines whether animating the transition between suggestions was requested, through the
+/// body's `data-animate` attribute.
+fn get_animate() -> bool {
+    js! { return "animate" in document.body.dataset; }
+        .try_into()
+        .unwrap_or(false)
+}
+
+/// Determines whether a summary of the restaurant data was requested in the footer, through the
+/// body's `data-show-stats` attribute.
+fn get_show_stats() -> bool {
+    js! { return "showStats" in document.body.dataset; }
+        .try_into()
+        .unwrap_or(false)
 }
 
-/// Performs an in-place naïve Fisher-Yates shuffle.
+/// Returns a random number below `n`, for `app::weighted_shuffle`.
 ///
 /// Depends on JavaScript APIs for random number generation.
-fn shuffle<T>(vec: &mut Vec<T>) {
-    let len = vec.len() as u32;
-
-    for i in 0..len {
-        let j = len - i;
-        // Use JavaScript's Math.random() instead of using the rand crate,
-        // due to current limitations.
-        let index: u32 = js!{ return Math.floor(Math.random() * @{j}); }
-            .try_into()
-            .unwrap();
-        vec.swap(index as usize, (j - 1) as usize);
-    }
+fn random_below(n: u32) -> u32 {
+    // Use JavaScript's Math.random() instead of using the rand crate,
+    // due to current limitations.
+    js! { return Math.floor(Math.random() * @{n}); }
+        .try_into()
+        .unwrap()
 }
 
-fn tuplify(vec: &[Restaurant]) -> Vec<(String, String, bool)> {
-    let mut viable = vec
-        .iter()
-        .filter(|r| r.is_viable(today(), now()))
-        .collect::<Vec<_>>();
-    let mut not = vec
+/// Builds the sections of the list view (see `group_by_availability`), keeping only restaurants
+/// whose names or aliases match the search query (see `search`).
+///
+/// Ties within a section (e.g. restaurants closing at the same time) are broken by the given
+/// order. The list shows whether each restaurant is open right now, so no travel time is allowed
+/// for.
+fn get_listings(
+    vec: &[Restaurant],
+    sort_by: SortBy,
+    search: &str,
+    clock: &impl Clock,
+) -> Vec<(String, Vec<Listing>)> {
+    // Read the clock once, so that every row reflects the same moment.
+    let clock = FixedClock::snapshot(clock);
+    let (today, now) = (clock.today(), clock.now());
+    let mut sorted = eat_ou::search(vec, search);
+    sort_by.sort(&mut sorted);
+    let availability = group_by_availability(sorted, today, now);
+    let listing = |r: &Restaurant, hours: String| Listing::new(r, hours, r.is_open_at(today, now));
+    let opening_times = availability
+        .opens_later
         .iter()
-        .filter(|r| !r.is_viable(today(), now()))
-        .collect::<Vec<_>>();
-    viable.sort_by_key(|r| r.name.clone());
-    not.sort_by_key(|r| r.name.clone());
-    viable.append(&mut not);
-    let vec = viable;
-    vec.iter()
-        .map(|r| {
-            (
-                r.name.clone(),
-                format!("{}", r.get_hours(today()).unwrap()),
-                r.is_viable(today(), now()),
-            )
+        .map(|&(r, opens)| (r.id(), opens))
+        .collect::<HashMap<_, _>>();
+    availability
+        .sections()
+        .into_iter()
+        .map(|(heading, restaurants)| {
+            let listings = restaurants
+                .into_iter()
+                .map(|r| match opening_times.get(r.id()) {
+                    Some(opens) => listing(r, format!("Opens at {}", opens)),
+                    None => listing(r, r.describe_hours_today(&clock)),
+                })
+                .collect::<Vec<_>>();
+            (heading.to_string(), listings)
         })
-        .collect::<Vec<_>>()
+        .collect()
 }
 
-/// Binds event listener to the "next" button.
-///
-/// The associated callback forwards the invocation to the `next` function.
+/// Binds an event listener to the "next" button.
 ///
-/// Depends on JavaScript APIs to attach a single-use event listener.
-fn add_event_listener(restaurants: &mut Vec<Restaurant>) {
-    // TODO: Remove clone if possible
-    let mut restaurants = restaurants.clone();
-    let callback = move |_: ClickEvent| {
-        next(&mut restaurants);
-    };
-    // stdweb doesn't support the options argument to addEventListener, so use JavaScript
-    js! {
-        document.getElementById("next").addEventListener("click", @{callback}, { once: true });
-    }
+/// The listener is bound once and shares the session state with the rest of the app. The
+/// associated callback forwards the invocation to the `next` function.
+fn bind_next(app: Rc<RefCell<AppState>>, clock: impl Clock + 'static) {
+    document()
+        .get_element_by_id("next")
+        .unwrap()
+        .add_event_listener::<ClickEvent, _>(move |_| {
+            next(&app, &clock);
+        });
 }
 
 /// Progresses to the next restaurant recommendation.
 ///
 /// If there are no more restaurants, progresses to the end state.
 /// If already in the end state, calls `start` and begins the cycle anew.
-fn next(restaurants: &mut Vec<Restaurant>) {
-    match ui::get_state() {
-        Ok(ui::State::Presenting) | Ok(ui::State::Terminated) => {
-            if let Some(restaurant) = restaurants.pop() {
-                suggest(&restaurant);
-                add_event_listener(restaurants);
-            } else if ui::get_state().unwrap() == ui::State::Terminated {
-                start()
-            } else {
-                end();
-                add_event_listener(restaurants);
-            }
+fn next(app: &RefCell<AppState>, clock: &impl Clock) {
+    // Release the borrow before acting, since restarting refills the queue.
+    let step = app.borrow_mut().advance();
+    match step {
+        Step::Suggest(restaurant) => {
+            let (focus, transition) = {
+                let app = app.borrow();
+                (app.focus_suggestions, app.transition_class())
+            };
+            suggest(&restaurant, focus, transition, clock)
         }
-        _ => add_event_listener(restaurants), // Re-bind event listener
+        Step::Exhaust => end(),
+        Step::Restart => start(app, clock),
+        Step::Ignore => {}
+    }
+}
+
+/// Shows the restaurants from the session's list source in list mode.
+fn list(app: &RefCell<AppState>, clock: &impl Clock) {
+    let clock = session_clock(clock);
+    let groups = {
+        let app = app.borrow();
+        let restaurants = app.listed(&clock);
+        get_listings(&restaurants, app.sort_by, &app.search, &clock)
     };
+    let ids = groups
+        .iter()
+        .flat_map(|(_, listings)| listings.iter().map(|l| l.id.clone()))
+        .collect();
+    app.borrow_mut().show_listings(ids);
+    ui::tabulate(groups).unwrap();
 }
 
-fn list() {
-    let restaurants = get_viable(); // Restaurant::get_list()
-    ui::tabulate(tuplify(&restaurants));
+/// Presents the restaurant highlighted in list mode, if any, leaving list mode.
+fn choose_selected(app: &RefCell<AppState>, clock: &impl Clock) {
+    let chosen = app.borrow_mut().choose_selected();
+    if let Some(restaurant) = chosen {
+        ui::stop_tabulation().unwrap();
+        ui::set_state(ui::State::Presenting).unwrap();
+        let transition = app.borrow().transition_class();
+        suggest(&restaurant, true, transition, clock);
+    }
 }
 
-/// Presents a restaurant for the user's consideration.
-fn suggest(restaurant: &Restaurant) {
-    match restaurant.get_hours(today()) {
-        Some(hours) => ui::set_suggestion(&restaurant.name, &format!("{}", hours)).unwrap(),
-        None => ui::set_suggestion(&restaurant.name, &"").unwrap(),
+/// Presents a restaurant for the user's consideration, optionally moving focus to it and
+/// animating the change with the given transition class.
+fn suggest(restaurant: &Restaurant, focus: bool, transition: Option<&str>, clock: &impl Clock) {
+    let mut details = restaurant.describe_hours_today(clock);
+    if let Some(hours) = restaurant.hours_today(clock) {
+        if let Some(note) = hours.note() {
+            details.push_str(" · ");
+            details.push_str(note);
+        }
+    }
+    if let Some(travel_time) = restaurant.travel_time() {
+        details.push_str(&format!(" · {} min walk", travel_time.as_secs() / 60));
+    }
+    ui::set_suggestion(
+        &restaurant.name,
+        restaurant.url(),
+        &details,
+        restaurant.note(),
+        restaurant.phone(),
+        transition,
+    )
+    .unwrap();
+    if focus {
+        ui::focus_suggestion().unwrap();
     }
 }
 
-/// Starts the suggestion cycle, generating and shuffling a new list of restaurants.
+/// Starts the suggestion cycle from a blank interface, generating and shuffling a new list of
+/// restaurants.
 ///
-/// Calls `next` to begin presenting options.
-fn start() {
-    let mut restaurants = get_viable();
-    shuffle(&mut restaurants);
-    ui::set_state(ui::State::Presenting).unwrap();
-    next(&mut restaurants);
+/// Calls `next` to begin presenting options, unless nothing is open (or there are no restaurants
+/// at all).
+fn start(app: &RefCell<AppState>, clock: &impl Clock) {
+    ui::clear().unwrap();
+    app.borrow_mut().start_with(|app| {
+        let mut restaurants = get_viable(app, clock);
+        app::weighted_shuffle(&mut restaurants, random_below);
+        // Present places closing soon last (the queue is consumed from the back).
+        let (day, time) = (clock.today(), clock.now());
+        restaurants.sort_by_key(|r| !r.closes_soon(day, time, CLOSING_SOON_THRESHOLD));
+        restaurants
+    });
+    let mode = app.borrow().mode;
+    ui::set_state(mode).unwrap();
+    match mode {
+        ui::State::Presenting => next(app, clock),
+        ui::State::NothingOpen => show_next_opening(&app.borrow(), clock),
+        _ => {}
+    }
+}
+
+/// Tells the user which restaurant opens next, if any.
+fn show_next_opening(app: &AppState, clock: &impl Clock) {
+    let restaurants = app
+        .restaurants
+        .iter()
+        .filter(|r| app.filters.matches(r))
+        .cloned()
+        .collect::<Vec<_>>();
+    let (day, time) = (clock.today(), clock.now());
+    if let Some((restaurant, opens, at)) = soonest_opening(&restaurants, day, time) {
+        let when = if opens == day && at > time {
+            format!("today at {}", at)
+        } else {
+            format!("{} at {}", opens, at)
+        };
+        ui::set_next_opening(&restaurant.name, &when).unwrap();
+    }
 }
 
 /// Stops the suggestion cycle, presenting the end screen.
@@ -145,47 +285,281 @@ fn end() {
 }
 
 /// Binds an event listener to the spacebar, forwarding keyup events to the next button.
-/// Also binds an event listener to the l key, forwarding keyup events to the list button.
-/// stdweb doesn't yet support click(), so we use JavaScript.
-fn bind_keyboard() {
+/// Also binds an event listener to the l and t keys, forwarding keyup events to the list and theme
+/// buttons respectively. stdweb doesn't yet support click(), so we use JavaScript.
+///
+/// Once the spacebar is used, subsequent suggestions receive keyboard focus; mouse users are
+/// left alone.
+///
+/// The c key copies the current suggestion to the clipboard, as the share button does, and the e
+/// key copies the viable restaurants as JSON (see `export`).
+///
+/// The a key switches list mode between viable restaurants and all restaurants. In list mode, the
+/// up and down arrow keys move a highlight through the listings, and Enter presents the
+/// highlighted restaurant. While typing in the search box, only those list mode keys apply.
+fn bind_keyboard(app: Rc<RefCell<AppState>>, clock: impl Clock + 'static) {
     document().add_event_listener::<KeyUpEvent, _>(move |event| match event.key().as_str() {
+        " " | "l" | "t" | "a" | "e" | "c" if is_searching() => {}
         " " => {
+            app.borrow_mut().focus_suggestions = true;
             js! { document.getElementById("next").click(); };
         }
         "l" => {
             js! { document.getElementById("list").click(); };
         }
+        "t" => {
+            js! { document.getElementById("theme").click(); };
+        }
+        "e" => export(&app.borrow(), &clock),
+        "c" => share(&app.borrow(), &clock),
+        "a" => {
+            app.borrow_mut().toggle_list_source();
+            if app.borrow().mode == ui::State::Tabulating {
+                list(&app, &clock);
+            }
+        }
+        "ArrowDown" | "ArrowUp" if app.borrow().mode == ui::State::Tabulating => {
+            let offset = if event.key() == "ArrowDown" { 1 } else { -1 };
+            let id = app.borrow_mut().move_selection(offset).map(String::from);
+            ui::set_selected(id.as_deref()).unwrap();
+        }
+        "Enter" if app.borrow().mode == ui::State::Tabulating => choose_selected(&app, &clock),
         _ => {}
     });
 }
 
-fn toggle_list_mode() {
-    match ui::get_state() {
-        Ok(ui::State::Terminated) | Ok(ui::State::Presenting) => {
-            list();
-        }
-        Ok(ui::State::Tabulating) => {
-            ui::stop_tabulation();
-        }
-        Err(_) => {} // TODO: Handle error
+/// Copies the restaurants viable right now (or soon; see `session_clock`) to the clipboard, as
+/// JSON (see `export_viable`).
+fn export(app: &AppState, clock: &impl Clock) {
+    let clock = session_clock(clock);
+    let viable = app::get_viable(&app.restaurants, &app.filters, app.viability, &clock);
+    let json = export_viable(&viable, clock.today(), clock.now());
+    ui::copy_to_clipboard(&json);
+}
+
+/// Copies a message suggesting the restaurant being presented (if any) to the clipboard (see
+/// `app::share_message`).
+fn share(app: &AppState, clock: &impl Clock) {
+    let restaurant = match app.current() {
+        Some(restaurant) => restaurant,
+        None => return,
+    };
+    let clock = FixedClock::snapshot(clock);
+    let message = match restaurant.hours_today(&clock) {
+        Some(hours) => app::share_message(&restaurant.name, &hours, clock.today(), clock.now()),
+        None => format!("Let's eat at {}", restaurant.name),
     };
+    ui::copy_to_clipboard(&message);
+}
+
+/// Binds an event listener to the share button, copying the current suggestion to the clipboard.
+fn bind_share(app: Rc<RefCell<AppState>>, clock: impl Clock + 'static) {
+    document()
+        .get_element_by_id("share")
+        .unwrap()
+        .add_event_listener::<ClickEvent, _>(move |_| {
+            share(&app.borrow(), &clock);
+        });
+}
+
+/// Returns whether the search box has keyboard focus (i.e. the user is typing a query).
+fn is_searching() -> bool {
+    js! {
+        const active = document.activeElement;
+        return active !== null && active.id === "search";
+    }
+    .try_into()
+    .unwrap_or(false)
+}
+
+/// Binds an event listener to the search box, narrowing list mode to the restaurants whose names
+/// match what's typed.
+fn bind_search(app: Rc<RefCell<AppState>>, clock: impl Clock + 'static) {
+    let search = document().get_element_by_id("search").unwrap();
+    let input = search.clone();
+    search.add_event_listener::<InputEvent, _>(move |_| {
+        let query: String = js! { return @{&input}.value; }
+            .try_into()
+            .unwrap_or_default();
+        app.borrow_mut().search = query;
+        if app.borrow().mode == ui::State::Tabulating {
+            list(&app, &clock);
+        }
+    });
+}
+
+/// Switches into list mode, or back out of it to the last-used mode.
+fn toggle_list_mode(app: &RefCell<AppState>, clock: &impl Clock) {
+    let mode = app.borrow_mut().toggle_list_mode();
+    match mode {
+        ui::State::Tabulating => list(app, clock),
+        _ => ui::stop_tabulation().unwrap(),
+    }
 }
 
 /// Binds an event listener to the list button, enabling the button to switch view modes.
-fn bind_list() {
+fn bind_list(app: Rc<RefCell<AppState>>, clock: impl Clock + 'static) {
     document()
         .get_element_by_id("list")
         .unwrap()
-        .add_event_listener::<ClickEvent, _>(|_| {
-            toggle_list_mode();
+        .add_event_listener::<ClickEvent, _>(move |_| {
+            toggle_list_mode(&app, &clock);
+        });
+}
+
+/// Binds an event listener to the theme button, cycling through the themes.
+fn bind_theme() {
+    document()
+        .get_element_by_id("theme")
+        .unwrap()
+        .add_event_listener::<ClickEvent, _>(move |_| {
+            ui::set_theme(ui::get_theme().next()).unwrap();
         });
 }
 
+/// Reads the URL of remote restaurant data from the body's `data-restaurants-url` attribute.
+///
+/// Without one, the bundled list is used.
+fn get_data_url() -> Option<String> {
+    js! { return document.body.dataset.restaurantsUrl || null; }
+        .try_into()
+        .unwrap_or_default()
+}
+
+/// Loads the restaurant list, passing it to `callback` once it's available.
+///
+/// Remote data is preferred if configured, but the bundled list is used instead if the remote
+/// data can't be fetched in time, can't be parsed, or fails validation.
+fn load_restaurants<F>(callback: F)
+where
+    F: FnOnce(Result<Vec<Restaurant>, DataError>) + 'static,
+{
+    match get_data_url() {
+        Some(url) => fetch_restaurants(&url, FETCH_TIMEOUT, move |fetched| {
+            callback(fetched.or_else(|e| {
+                js! { console.warn(@{format!("{}; using the bundled list instead", e)}); }
+                Restaurant::get_list()
+            }))
+        }),
+        None => callback(Restaurant::get_list()),
+    }
+}
+
+/// Merges in the extra restaurants the user has stored locally, if any, along with a warning
+/// for each restaurant merged with one already listed.
+///
+/// Stored data that can't be used is ignored (with a warning), rather than breaking startup.
+fn with_extra_restaurants(restaurants: Vec<Restaurant>) -> (Vec<Restaurant>, Vec<ValidationIssue>) {
+    match load_extra_restaurants(&LocalStorage) {
+        Ok(extra) => merge_restaurants(restaurants, extra),
+        Err(e) => {
+            js! { console.warn(@{format!("Ignoring extra restaurants: {}", e)}); }
+            (restaurants, Vec::new())
+        }
+    }
+}
+
+/// Asks the browser for the user's position, passing it to `callback` if it's granted.
+///
+/// If the user declines (or the browser can't tell), the callback receives `None`.
+fn request_position<F>(callback: F)
+where
+    F: FnOnce(Option<Coordinates>) + 'static,
+{
+    let callback = move |position: Value| {
+        let position: Option<Vec<f64>> = position.try_into().ok();
+        callback(position.map(|p| Coordinates::new(p[0], p[1])))
+    };
+    js! { @(no_return)
+        var callback = @{Once(callback)};
+        if ("geolocation" in navigator) {
+            navigator.geolocation.getCurrentPosition(
+                function(position) {
+                    callback([position.coords.latitude, position.coords.longitude]);
+                },
+                function() { callback(null); }
+            );
+        } else {
+            callback(null);
+        }
+    }
+}
+
+/// Sorts listings by distance once the user's position is known, falling back to sorting by
+/// name if it isn't shared.
+fn sort_by_position(app: Rc<RefCell<AppState>>, clock: impl Clock + 'static) {
+    request_position(move |position| {
+        app.borrow_mut().sort_by = SortBy::for_position(position);
+        if app.borrow().mode == ui::State::Tabulating {
+            list(&app, &clock);
+        }
+    });
+}
+
+/// Starts the app with the given restaurants, reading the day and time from the given clock.
+fn run(restaurants: Vec<Restaurant>, clock: impl Clock + Copy + 'static) {
+    let app = Rc::new(RefCell::new(AppState::new()));
+    app.borrow_mut().restaurants = restaurants;
+    app.borrow_mut().filters = get_filter();
+    app.borrow_mut().viability.travel = get_travel_time();
+    app.borrow_mut().animate_suggestions = get_animate();
+    app.borrow_mut().reduced_motion = ui::prefers_reduced_motion();
+    ui::unhide_buttons();
+    if get_show_stats() {
+        ui::set_stats(&app.borrow().stats(&session_clock(&clock)).to_string());
+    }
+    start(&app, &clock);
+    bind_next(app.clone(), clock);
+    bind_keyboard(app.clone(), clock);
+    sort_by_position(app.clone(), clock);
+    bind_search(app.clone(), clock);
+    bind_share(app.clone(), clock);
+    bind_list(app, clock);
+}
+
+/// Shows the loading state, then starts the app once the restaurant list has loaded.
+fn launch(clock: impl Clock + Copy + 'static) {
+    ui::set_state(ui::State::Loading).unwrap();
+    load_restaurants(move |result| match result {
+        Ok(restaurants) => {
+            let (restaurants, merges) = with_extra_restaurants(restaurants);
+            report_warnings(&restaurants, merges);
+            run(restaurants, clock);
+        }
+        // Data without restaurants isn't broken, just empty; `start` says so (unless the user
+        // has stored restaurants of their own).
+        Err(DataError::Empty) => {
+            let (restaurants, _) = with_extra_restaurants(Vec::new());
+            run(restaurants, clock);
+        }
+        // Broken data shouldn't masquerade as nothing being open.
+        Err(e) => {
+            js! { console.error(@{e.to_string()}); }
+            ui::set_state(ui::State::Failed).unwrap();
+        }
+    });
+}
+
+/// Reports problems with the restaurant data to the browser console, after any found while
+/// loading it (e.g. merged duplicates).
+fn report_warnings(restaurants: &[Restaurant], found: Vec<ValidationIssue>) {
+    for issue in found.into_iter().chain(validate(restaurants)) {
+        js! { console.warn(@{issue.to_string()}); }
+    }
+}
+
 fn main() {
     stdweb::initialize();
-    ui::unhide_buttons();
-    start();
-    bind_keyboard();
-    bind_list();
+    if let Err(e) = ui::verify_dom() {
+        js! { console.error(@{e.to_string()}); }
+        return;
+    }
+    ui::set_theme(ui::get_theme()).unwrap();
+    bind_theme();
+    // Allow overriding the clock (e.g. `?day=friday&time=23:30`) for demos.
+    match query::day_and_time(&get_query()) {
+        Some((day, time)) => launch(FixedClock::new(day, time)),
+        None => launch(BrowserClock),
+    }
     stdweb::event_loop();
 }
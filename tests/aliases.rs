@@ -0,0 +1,59 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::{validate, Restaurant, Severity};
+
+fn restaurants() -> Vec<Restaurant> {
+    serde_json::from_value(json!([
+        { "name": "Couch Restaurants", "aliases": ["the Caf", "Couch"], "hours": {} },
+        { "name": "Crossroads", "aliases": ["Xroads"], "hours": {} }
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_aliases() {
+    let restaurants = restaurants();
+    assert_eq!(restaurants[0].aliases(), &["the Caf", "Couch"]);
+    assert_eq!(restaurants[1].aliases(), &["Xroads"]);
+}
+
+#[test]
+fn test_matches_alias() {
+    let caf = &restaurants()[0];
+    assert!(caf.matches_name("caf"));
+    assert!(caf.matches_name("THE CAF "));
+    assert!(!caf.matches_name("crossroads"));
+}
+
+#[test]
+fn test_matches_primary_name() {
+    let restaurants = restaurants();
+    assert!(restaurants[0].matches_name("restaurants"));
+    assert!(restaurants[1].matches_name("CROSS"));
+    assert!(restaurants[1].matches_name(""));
+    assert!(!restaurants[1].matches_name("pizza"));
+}
+
+#[test]
+fn test_alias_collision_warning() {
+    let restaurants: Vec<Restaurant> = serde_json::from_value(json!([
+        { "name": "Couch Restaurants", "aliases": ["crossroads"], "hours": {} },
+        { "name": "Crossroads", "hours": {} }
+    ]))
+    .unwrap();
+    let collisions = validate(&restaurants)
+        .into_iter()
+        .filter(|i| i.message.starts_with("alias"))
+        .collect::<Vec<_>>();
+    assert_eq!(collisions.len(), 1);
+    assert_eq!(collisions[0].severity, Severity::Warning);
+    assert_eq!(
+        collisions[0].to_string(),
+        "Warning: Couch Restaurants: alias \"crossroads\" is another restaurant's name"
+    );
+    assert!(validate(&self::restaurants())
+        .iter()
+        .all(|i| !i.message.starts_with("alias")));
+}
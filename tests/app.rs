@@ -0,0 +1,339 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::app::{get_viable, move_selection, session_clock, AppState, ListSource, Step};
+use eat_ou::ui::{State, TRANSITION_CLASS};
+use eat_ou::{Date, Day, FixedClock, Restaurant, RestaurantFilter, Time, ViabilityOptions};
+use std::cell::RefCell;
+
+fn restaurant(name: &str) -> Restaurant {
+    serde_json::from_value(json!({ "name": name, "hours": {} })).unwrap()
+}
+
+fn started(names: &[&str]) -> AppState {
+    let mut app = AppState::new();
+    app.start(names.iter().map(|name| restaurant(name)).collect());
+    app
+}
+
+#[test]
+fn test_start_resets_session() {
+    let mut app = started(&["Crossroads"]);
+    app.advance();
+    app.end();
+    app.start(vec![restaurant("Baja Fresh"), restaurant("Raising Cane's")]);
+    assert_eq!(app.mode, State::Presenting);
+    assert_eq!(app.remaining.len(), 2);
+    assert!(app.history.is_empty());
+}
+
+#[test]
+fn test_advance_consumes_shared_queue() {
+    let app = RefCell::new(started(&["Crossroads", "Raising Cane's"]));
+    let step = app.borrow_mut().advance();
+    match step {
+        Step::Suggest(r) => assert_eq!(r.name, "Raising Cane's"),
+        _ => panic!("expected a suggestion"),
+    }
+    let step = app.borrow_mut().advance();
+    match step {
+        Step::Suggest(r) => assert_eq!(r.name, "Crossroads"),
+        _ => panic!("expected a suggestion"),
+    }
+    let app = app.borrow();
+    assert!(app.remaining.is_empty());
+    let history = app
+        .history
+        .iter()
+        .map(|r| r.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(history, vec!["Raising Cane's", "Crossroads"]);
+}
+
+#[test]
+fn test_advance_exhausts_then_restarts() {
+    let mut app = started(&["Crossroads"]);
+    app.advance();
+    match app.advance() {
+        Step::Exhaust => {}
+        _ => panic!("expected exhaustion"),
+    }
+    assert_eq!(app.mode, State::Terminated);
+    match app.advance() {
+        Step::Restart => {}
+        _ => panic!("expected a restart"),
+    }
+}
+
+#[test]
+fn test_end() {
+    let mut app = started(&["Crossroads"]);
+    app.end();
+    assert_eq!(app.mode, State::Terminated);
+}
+
+#[test]
+fn test_toggle_list_mode_resumes_previous_mode() {
+    let mut app = started(&["Crossroads"]);
+    app.end();
+    assert_eq!(app.toggle_list_mode(), State::Tabulating);
+    match app.advance() {
+        Step::Ignore => {}
+        _ => panic!("expected the request to be ignored"),
+    }
+    assert_eq!(app.remaining.len(), 1);
+    assert_eq!(app.toggle_list_mode(), State::Terminated);
+    let mut app = started(&["Crossroads"]);
+    assert_eq!(app.toggle_list_mode(), State::Tabulating);
+    assert_eq!(app.toggle_list_mode(), State::Presenting);
+}
+
+#[test]
+fn test_start_with_nothing_open() {
+    let mut app = started(&[]);
+    assert_eq!(app.mode, State::NothingOpen);
+    match app.advance() {
+        Step::Restart => {}
+        _ => panic!("expected a restart"),
+    }
+    assert_eq!(app.mode, State::NothingOpen);
+}
+
+#[test]
+fn test_start_without_data() {
+    let mut app = AppState::new();
+    app.start_with(|_| panic!("nothing to shuffle"));
+    assert_eq!(app.mode, State::NoData);
+    match app.advance() {
+        Step::Ignore => {}
+        _ => panic!("expected nothing to happen"),
+    }
+    assert_eq!(app.mode, State::NoData);
+    app.restaurants = vec![restaurant("Crossroads")];
+    let clock = FixedClock::new(Day::Monday, Time::new(12, 0));
+    app.start_with(|app| get_viable(&app.restaurants, &app.filters, app.viability, &clock));
+    assert_eq!(app.mode, State::NothingOpen);
+}
+
+#[test]
+fn test_terminal_states_are_distinct() {
+    let mut exhausted = started(&["Crossroads"]);
+    exhausted.advance();
+    exhausted.advance();
+    let nothing_open = started(&[]);
+    assert_eq!(exhausted.mode, State::Terminated);
+    assert_eq!(nothing_open.mode, State::NothingOpen);
+    assert_ne!(exhausted.mode, nothing_open.mode);
+}
+
+fn open_on_monday(name: &str, start: &str, end: &str) -> Restaurant {
+    serde_json::from_value(json!({
+        "name": name,
+        "hours": { "monday": { "start": start, "end": end } }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_get_viable_at_fixed_clock() {
+    let restaurants = vec![
+        open_on_monday("Lunch Counter", "11:00", "14:00"),
+        open_on_monday("Supper Club", "17:00", "23:00"),
+    ];
+    let names = |clock: FixedClock| {
+        get_viable(
+            &restaurants,
+            &RestaurantFilter::new(),
+            ViabilityOptions::default(),
+            &clock,
+        )
+        .into_iter()
+        .map(|r| r.name)
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(FixedClock::new(Day::Monday, Time::new(12, 0))),
+        vec!["Lunch Counter"]
+    );
+    assert_eq!(
+        names(FixedClock::new(Day::Monday, Time::new(18, 30))),
+        vec!["Supper Club"]
+    );
+    assert!(names(FixedClock::new(Day::Tuesday, Time::new(12, 0))).is_empty());
+    assert!(get_viable(
+        &restaurants,
+        &RestaurantFilter::new().open_on_weekend(),
+        ViabilityOptions::default(),
+        &FixedClock::new(Day::Monday, Time::new(12, 0)),
+    )
+    .is_empty());
+}
+
+#[test]
+fn test_get_viable_after_midnight() {
+    let restaurants = vec![open_on_monday("Night Owl Diner", "21:00", "26:00")];
+    let clock = FixedClock::new(Day::Tuesday, Time::new(0, 45));
+    let viable = get_viable(
+        &restaurants,
+        &RestaurantFilter::new(),
+        ViabilityOptions::default(),
+        &clock,
+    );
+    assert_eq!(viable.len(), 1);
+}
+
+#[test]
+fn test_get_viable_with_date() {
+    let restaurants: Vec<Restaurant> = serde_json::from_value(json!([{
+        "name": "Lunch Counter",
+        "hours": { "monday": { "start": "11:00", "end": "14:00" } },
+        "exceptions": [{ "date": "2024-09-02" }]
+    }]))
+    .unwrap();
+    let clock = FixedClock::new(Day::Monday, Time::new(12, 0));
+    let viable = |clock: FixedClock| {
+        get_viable(
+            &restaurants,
+            &RestaurantFilter::new(),
+            ViabilityOptions::default(),
+            &clock,
+        )
+        .len()
+    };
+    assert_eq!(viable(clock), 1);
+    assert_eq!(viable(clock.with_date(Date::new(2024, 9, 9))), 1);
+    assert_eq!(viable(clock.with_date(Date::new(2024, 9, 2))), 0);
+}
+
+#[test]
+fn test_animation_is_opt_in() {
+    let app = AppState::new();
+    assert!(!app.animate_suggestions);
+    assert!(!app.focus_suggestions);
+}
+
+#[test]
+fn test_transition_class_respects_reduced_motion() {
+    let mut app = AppState::new();
+    assert_eq!(app.transition_class(), None);
+    app.animate_suggestions = true;
+    assert_eq!(app.transition_class(), Some(TRANSITION_CLASS));
+    app.reduced_motion = true;
+    assert_eq!(app.transition_class(), None);
+    app.animate_suggestions = false;
+    assert_eq!(app.transition_class(), None);
+}
+
+#[test]
+fn test_list_sources() {
+    let mut app = AppState::new();
+    app.restaurants = vec![
+        open_on_monday("Lunch Counter", "11:00", "14:00"),
+        open_on_monday("Supper Club", "17:00", "23:00"),
+    ];
+    let clock = FixedClock::new(Day::Monday, Time::new(12, 0));
+    let names = |app: &AppState| {
+        app.listed(&clock)
+            .into_iter()
+            .map(|r| r.name)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(app.list_source, ListSource::Viable);
+    assert_eq!(names(&app), vec!["Lunch Counter"]);
+    assert_eq!(app.toggle_list_source(), ListSource::All);
+    assert_eq!(names(&app), vec!["Lunch Counter", "Supper Club"]);
+    assert_eq!(app.toggle_list_source(), ListSource::Viable);
+}
+
+#[test]
+fn test_move_selection() {
+    assert_eq!(move_selection(None, 3, 1), Some(0));
+    assert_eq!(move_selection(None, 3, -1), Some(2));
+    assert_eq!(move_selection(Some(0), 3, 1), Some(1));
+    assert_eq!(move_selection(Some(2), 3, 1), Some(0));
+    assert_eq!(move_selection(Some(0), 3, -1), Some(2));
+    assert_eq!(move_selection(Some(1), 3, 4), Some(2));
+    assert_eq!(move_selection(Some(5), 3, 1), Some(0));
+    assert_eq!(move_selection(None, 0, 1), None);
+    assert_eq!(move_selection(Some(0), 0, -1), None);
+    assert_eq!(move_selection(None, 3, 0), None);
+}
+
+#[test]
+fn test_choose_selected() {
+    let mut app = started(&["Crossroads", "Couch"]);
+    app.restaurants = app.remaining.clone();
+    assert_eq!(app.move_selection(1), None);
+    app.toggle_list_mode();
+    app.show_listings(vec!["couch".into(), "crossroads".into()]);
+    assert_eq!(app.choose_selected().map(|r| r.name), None);
+    assert_eq!(app.move_selection(-1), Some("crossroads"));
+    assert_eq!(app.move_selection(1), Some("couch"));
+    let chosen = app.choose_selected().unwrap();
+    assert_eq!(chosen.name, "Couch");
+    assert_eq!(app.mode, State::Presenting);
+    assert_eq!(app.selected, None);
+    assert_eq!(app.history.last().map(|r| r.name.as_str()), Some("Couch"));
+}
+
+#[test]
+fn test_choose_selected_location() {
+    let location = |id: &str| -> Restaurant {
+        serde_json::from_value(json!({ "id": id, "name": "Chick-fil-A", "hours": {} })).unwrap()
+    };
+    let mut app = AppState::new();
+    app.restaurants = vec![
+        location("chick-fil-a-campus"),
+        location("chick-fil-a-lindsey"),
+    ];
+    app.toggle_list_mode();
+    app.show_listings(vec![
+        "chick-fil-a-campus".into(),
+        "chick-fil-a-lindsey".into(),
+    ]);
+    assert_eq!(app.move_selection(-1), Some("chick-fil-a-lindsey"));
+    let chosen = app.choose_selected().unwrap();
+    assert_eq!(chosen.id(), "chick-fil-a-lindsey");
+}
+
+#[test]
+fn test_choose_selected_is_not_suggested_again() {
+    let mut app = started(&["Crossroads", "Couch", "Raising Cane's"]);
+    app.restaurants = app.remaining.clone();
+    app.toggle_list_mode();
+    app.show_listings(vec!["couch".into()]);
+    app.move_selection(1);
+    assert_eq!(app.choose_selected().unwrap().name, "Couch");
+    let mut suggested = Vec::new();
+    while let Step::Suggest(restaurant) = app.advance() {
+        suggested.push(restaurant.name);
+    }
+    suggested.sort();
+    assert_eq!(suggested, vec!["Crossroads", "Raising Cane's"]);
+    assert_eq!(app.mode, State::Terminated);
+}
+
+#[test]
+fn test_current() {
+    let mut app = started(&["Crossroads", "Raising Cane's"]);
+    assert!(app.current().is_none());
+    app.advance();
+    assert_eq!(
+        app.current().map(|r| r.name.as_str()),
+        Some("Raising Cane's")
+    );
+    app.toggle_list_mode();
+    assert!(app.current().is_none());
+}
+
+#[test]
+fn test_session_clock() {
+    let clock = FixedClock::new(Day::Monday, Time::new(23, 30));
+    assert_eq!(session_clock(&clock, false), clock);
+    assert_eq!(
+        session_clock(&clock, true),
+        FixedClock::new(Day::Tuesday, Time::new(0, 30))
+    );
+}
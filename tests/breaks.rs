@@ -0,0 +1,139 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::app::share_message;
+use eat_ou::{Day, Hours, Restaurant, Time, Viability};
+use std::time::Duration;
+
+fn split_shift() -> Restaurant {
+    serde_json::from_value(json!({
+        "name": "O'Connell's",
+        "hours": {
+            "monday": {
+                "start": "11:00",
+                "end": "21:00",
+                "breaks": [{ "start": "14:00", "end": "17:00" }]
+            }
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_break_viability() {
+    let r = split_shift();
+    assert!(r.is_viable(Day::Monday, Time::new(13, 0)));
+    assert!(!r.is_viable(Day::Monday, Time::new(15, 0)));
+    assert!(r.is_viable(Day::Monday, Time::new(18, 0)));
+    assert!(!r.is_open_at(Day::Monday, Time::new(14, 0)));
+    assert!(r.is_open_at(Day::Monday, Time::new(17, 0)));
+}
+
+#[test]
+fn test_break_display() {
+    let hours = split_shift().get_hours(Day::Monday).unwrap();
+    assert_eq!(
+        format!("{}", hours),
+        "11:00 AM–9:00 PM (closed 2:00 PM–5:00 PM)"
+    );
+}
+
+#[test]
+fn test_closing_for_break() {
+    assert_eq!(
+        split_shift().viability(Day::Monday, Time::new(13, 50)),
+        Viability::ClosingSoon {
+            closes_in: Duration::from_secs(10 * 60)
+        }
+    );
+}
+
+#[test]
+fn test_overnight_break() {
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Night Owl Diner",
+        "hours": {
+            "friday": {
+                "start": "21:00",
+                "end": "3:00",
+                "breaks": [{ "start": "1:00", "end": "1:30" }]
+            }
+        }
+    }))
+    .unwrap();
+    assert!(r.is_open_at(Day::Saturday, Time::new(0, 45)));
+    assert!(!r.is_open_at(Day::Saturday, Time::new(1, 15)));
+    assert!(r.is_open_at(Day::Saturday, Time::new(1, 30)));
+    assert!(r.warnings().is_empty());
+}
+
+#[test]
+fn test_reopening_after_overnight_break() {
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Night Owl Diner",
+        "hours": {
+            "friday": {
+                "start": "18:00",
+                "end": "3:00",
+                "breaks": [{ "start": "1:00", "end": "1:30" }]
+            }
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        r.opens_later(Day::Friday, Time::new(25, 10)),
+        Some(Time::new(25, 30))
+    );
+    let hours = r.get_hours(Day::Friday).unwrap();
+    assert_eq!(
+        share_message(&r.name, &hours, Day::Friday, Time::new(25, 10)),
+        "Let's eat at Night Owl Diner (opens at 1:30 AM)"
+    );
+}
+
+#[test]
+fn test_stray_break_warning() {
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "O'Connell's",
+        "hours": {
+            "tuesday": {
+                "start": "11:00",
+                "end": "21:00",
+                "breaks": [{ "start": "20:00", "end": "22:00" }]
+            }
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        r.warnings(),
+        vec!["O'Connell's: tuesday: break 8:00 PM–10:00 PM falls outside its hours"]
+    );
+    assert!(split_shift().warnings().is_empty());
+}
+
+#[test]
+fn test_duration_with_overlapping_breaks() {
+    let hours: Hours = serde_json::from_value(json!({
+        "start": "11:00",
+        "end": "21:00",
+        "breaks": [
+            { "start": "14:00", "end": "17:00" },
+            { "start": "15:00", "end": "16:00" },
+            { "start": "16:30", "end": "18:00" }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(hours.duration(), Duration::from_secs(6 * 60 * 60));
+    let hours: Hours = serde_json::from_value(json!({
+        "start": "11:00",
+        "end": "12:00",
+        "breaks": [
+            { "start": "11:00", "end": "11:45" },
+            { "start": "11:15", "end": "12:00" }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(hours.duration(), Duration::from_secs(0));
+}
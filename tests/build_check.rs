@@ -0,0 +1,72 @@
+//! Runs the build script's data checks (see `build.rs`) against fixtures.
+
+#[macro_use]
+extern crate serde_json;
+
+include!("../build/check.rs");
+
+#[test]
+fn test_bundled_data_passes() {
+    let data = serde_json::from_str(include_str!("../food.json")).unwrap();
+    assert_eq!(check(&data), Ok(()));
+}
+
+#[test]
+fn test_rejects_bad_time() {
+    let data = json!([{
+        "name": "Raising Cane's",
+        "hours": { "tuesday": { "start": "1O:30", "end": "22:00" } }
+    }]);
+    assert_eq!(
+        check(&data),
+        Err("Raising Cane's → tuesday → start: invalid time \"1O:30\": hours and minutes must be numbers".into())
+    );
+}
+
+#[test]
+fn test_accepts_string_hours() {
+    let data = json!({
+        "schedules": { "lunch": { "weekdays": "11:00 AM–2:00 PM" } },
+        "restaurants": [{
+            "name": "Pepe Delgado's",
+            "hours": {
+                "extends": "lunch",
+                "monday": "11:00-21:00 (closed 15:00-16:00)",
+                "saturday": "Open 24 hours",
+                "sunday": { "start": "12:00", "end": "20:00", "note": "Brunch" }
+            },
+            "exceptions": [{ "date": "2024-12-25", "hours": "10:00-14:00" }]
+        }]
+    });
+    assert_eq!(check(&data), Ok(()));
+}
+
+#[test]
+fn test_rejects_bad_string_hours() {
+    let data = json!([{ "name": "Couch", "hours": { "monday": "9:0o-17:00" } }]);
+    let error = check(&data).unwrap_err();
+    assert!(
+        error.starts_with("Couch → monday: invalid hours \"9:0o-17:00\""),
+        "{}",
+        error
+    );
+    let data = json!([{ "name": "Couch", "hours": { "monday": "9:00 to 17:00" } }]);
+    assert!(check(&data).unwrap_err().contains("hyphen"));
+    let data = json!({
+        "schedules": { "lunch": { "daily": "11:00-14:00 (15:00-16:00)" } },
+        "restaurants": []
+    });
+    assert!(check(&data)
+        .unwrap_err()
+        .starts_with("schedule \"lunch\" → daily"));
+    let data = json!([{
+        "name": "Couch",
+        "exceptions": [{ "date": "2024-12-25", "hours": "10:00-25:75" }]
+    }]);
+    let error = check(&data).unwrap_err();
+    assert!(
+        error.starts_with("Couch → exceptions: invalid hours"),
+        "{}",
+        error
+    );
+}
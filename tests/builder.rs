@@ -0,0 +1,98 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::{BuildError, Date, Day, Hours, Restaurant, RestaurantBuilder, Time, TimeRange};
+
+fn parsed() -> Restaurant {
+    serde_json::from_value(json!({
+        "name": "Pepe Delgado's",
+        "hours": {
+            "monday": {
+                "start": "11:00",
+                "end": "21:00",
+                "breaks": [{ "start": "15:00", "end": "16:00" }]
+            },
+            "friday": { "start": "11:00", "end": "26:00" }
+        },
+        "walk_minutes": 12,
+        "tags": ["mexican"]
+    }))
+    .unwrap()
+}
+
+fn built() -> Restaurant {
+    RestaurantBuilder::new()
+        .name("Pepe Delgado's")
+        .hours(
+            Day::Monday,
+            Hours::new(Time::new(11, 0), Time::new(21, 0))
+                .with_break(TimeRange::new(Time::new(15, 0), Time::new(16, 0))),
+        )
+        .hours(Day::Friday, Hours::new(Time::new(11, 0), Time::new(26, 0)))
+        .walk_minutes(12)
+        .tag("mexican")
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_matches_parsed() {
+    let (built, parsed) = (built(), parsed());
+    assert_eq!(built.id(), parsed.id());
+    for day in Day::all() {
+        let display = |r: &Restaurant| r.get_hours(day).map(|h| format!("{}", h));
+        assert_eq!(display(&built), display(&parsed));
+        for hour in 0..24 {
+            for &minutes in &[0, 30] {
+                let time = Time::new(hour, minutes);
+                assert_eq!(built.is_viable(day, time), parsed.is_viable(day, time));
+            }
+        }
+    }
+    assert!(built.is_viable(Day::Saturday, Time::new(1, 0)));
+    assert!(!built.is_viable(Day::Monday, Time::new(15, 30)));
+    assert_eq!(
+        serde_json::to_value(&built).unwrap(),
+        serde_json::to_value(&parsed).unwrap()
+    );
+}
+
+#[test]
+fn test_build_errors() {
+    let missing = RestaurantBuilder::new().tag("pizza").build();
+    assert_eq!(missing.err().unwrap(), BuildError::MissingName);
+    let backwards = RestaurantBuilder::new()
+        .name("Crossroads")
+        .exception(Date::new(2024, 12, 26), Date::new(2024, 12, 24), None)
+        .build();
+    assert_eq!(
+        backwards.err().unwrap().to_string(),
+        "Crossroads: exception ends (2024-12-24) before it starts (2024-12-26)"
+    );
+}
+
+#[test]
+fn test_open() {
+    let restaurant = RestaurantBuilder::new()
+        .name("Crossroads")
+        .open(Day::Monday, "9:00", "17:00")
+        .open(Day::Friday, "21:00", "26:00")
+        .build()
+        .unwrap();
+    assert_eq!(
+        restaurant.get_hours(Day::Monday),
+        Some(Hours::new(Time::new(9, 0), Time::new(17, 0)))
+    );
+    assert!(restaurant.is_open_at(Day::Saturday, Time::new(1, 0)));
+    assert!(!restaurant.has_hours(Day::Tuesday));
+    let invalid = RestaurantBuilder::new()
+        .name("Crossroads")
+        .open(Day::Monday, "9:00", "5 PM")
+        .build();
+    assert_eq!(
+        invalid.err().unwrap().to_string(),
+        "Crossroads → monday → end: invalid time \"5 PM\": expected hours and minutes separated \
+         by a colon, as in 9:30"
+    );
+}
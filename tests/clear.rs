@@ -0,0 +1,119 @@
+extern crate eat_ou;
+
+use eat_ou::ui::{
+    clear_elements, Clear, DataAttributes, UiError, CLEARED_ELEMENTS, REQUIRED_ELEMENTS,
+    STATE_ATTRIBUTES,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Stands in for a DOM element's content and `data-*` attributes.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ShimElement {
+    content: String,
+    data: HashMap<String, String>,
+}
+
+/// Stands in for the page, by element ID.
+type Page = RefCell<HashMap<&'static str, ShimElement>>;
+
+/// Stands in for a handle to one of the page's elements.
+struct Shim<'a>(&'a Page, &'static str);
+
+impl<'a> DataAttributes for Shim<'a> {
+    fn has_data_attribute(&self, name: &str) -> bool {
+        self.0.borrow()[self.1].data.contains_key(name)
+    }
+
+    fn set_data_attribute(&mut self, name: &str, value: &str) -> Result<(), UiError> {
+        let mut page = self.0.borrow_mut();
+        let element = page.get_mut(self.1).unwrap();
+        element.data.insert(name.into(), value.into());
+        Ok(())
+    }
+
+    fn clear_data_attribute(&mut self, name: &str) -> Result<(), UiError> {
+        self.0
+            .borrow_mut()
+            .get_mut(self.1)
+            .unwrap()
+            .data
+            .remove(name);
+        Ok(())
+    }
+}
+
+impl<'a> Clear for Shim<'a> {
+    fn clear_content(&mut self) -> Result<(), UiError> {
+        self.0.borrow_mut().get_mut(self.1).unwrap().content.clear();
+        Ok(())
+    }
+}
+
+/// Builds a page with content and state attributes on every element.
+fn page() -> Page {
+    let mut data = HashMap::new();
+    data.insert("theme".to_string(), "dark".to_string());
+    for &(_, name) in STATE_ATTRIBUTES.iter() {
+        data.insert(name.to_string(), "1".to_string());
+    }
+    let element = ShimElement {
+        content: "Crossroads".into(),
+        data,
+    };
+    RefCell::new(
+        REQUIRED_ELEMENTS
+            .iter()
+            .map(|&id| (id, element.clone()))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_clear_empties_elements() {
+    let page = page();
+    assert_eq!(clear_elements(|id| Shim(&page, id)), Ok(()));
+    let page = page.borrow();
+    for id in CLEARED_ELEMENTS.iter() {
+        assert_eq!(page[id].content, "", "{} wasn't emptied", id);
+    }
+    assert!(REQUIRED_ELEMENTS
+        .iter()
+        .filter(|id| !CLEARED_ELEMENTS.contains(id))
+        .all(|id| page[id].content == "Crossroads"));
+}
+
+#[test]
+fn test_clear_removes_state_attributes() {
+    let page = page();
+    clear_elements(|id| Shim(&page, id)).unwrap();
+    for &(id, name) in STATE_ATTRIBUTES.iter() {
+        assert!(!Shim(&page, id).has_data_attribute(name), "{}", name);
+        assert!(Shim(&page, id).has_data_attribute("theme"));
+    }
+}
+
+#[test]
+fn test_clear_reports_errors() {
+    struct Missing;
+    impl DataAttributes for Missing {
+        fn has_data_attribute(&self, _: &str) -> bool {
+            false
+        }
+        fn set_data_attribute(&mut self, _: &str, _: &str) -> Result<(), UiError> {
+            Err(UiError::MissingElement("next"))
+        }
+        fn clear_data_attribute(&mut self, _: &str) -> Result<(), UiError> {
+            Err(UiError::MissingElement("next"))
+        }
+    }
+    impl Clear for Missing {
+        fn clear_content(&mut self) -> Result<(), UiError> {
+            Ok(())
+        }
+    }
+    assert_eq!(
+        clear_elements(|_| Missing),
+        Err(UiError::MissingElement("next"))
+    );
+}
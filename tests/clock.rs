@@ -0,0 +1,33 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{viable_now, Date, Day, FixedClock, Restaurant, Time};
+
+#[test]
+fn test_advance_within_day() {
+    let clock = FixedClock::new(Day::Monday, Time::new(11, 30)).advance(60);
+    assert_eq!(clock, FixedClock::new(Day::Monday, Time::new(12, 30)));
+}
+
+#[test]
+fn test_advance_past_midnight() {
+    let clock = FixedClock::new(Day::Saturday, Time::new(23, 30))
+        .with_date(Date::new(2024, 12, 31))
+        .advance(60);
+    assert_eq!(clock.day, Day::Sunday);
+    assert_eq!(clock.time, Time::new(0, 30));
+    assert_eq!(clock.date, Some(Date::new(2025, 1, 1)));
+}
+
+#[test]
+fn test_advance_finds_next_day_hours() {
+    let restaurants: Vec<Restaurant> = serde_json::from_value(json!([
+        { "name": "Early Bird", "hours": { "tuesday": { "start": "0:00", "end": "6:00" } } }
+    ]))
+    .unwrap();
+    let clock = FixedClock::new(Day::Monday, Time::new(23, 30));
+    assert!(viable_now(&restaurants, &clock).is_empty());
+    assert_eq!(viable_now(&restaurants, &clock.advance(60)).len(), 1);
+}
@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{Date, DateRange, Day, Restaurant, Time};
+
+fn restaurant() -> Restaurant {
+    serde_json::from_value(json!({
+        "name": "Couch Cafeteria",
+        "hours": { "daily": { "start": "7:00", "end": "20:00" } },
+        "closed_ranges": [
+            { "from": "05-15", "to": "08-01" },
+            { "from": "12-20", "to": "01-06" }
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_date_range_contains() {
+    let summer = DateRange::try_new((5, 15), (8, 1)).unwrap();
+    assert!(summer.contains(Date::new(2018, 7, 4)));
+    assert!(summer.contains(Date::new(2018, 5, 15)));
+    assert!(summer.contains(Date::new(2018, 8, 1)));
+    assert!(!summer.contains(Date::new(2018, 5, 14)));
+    assert!(!summer.contains(Date::new(2018, 8, 2)));
+}
+
+#[test]
+fn test_date_range_wraps_year() {
+    let winter = DateRange::try_new((12, 20), (1, 6)).unwrap();
+    assert!(winter.contains(Date::new(2018, 12, 31)));
+    assert!(winter.contains(Date::new(2019, 1, 1)));
+    assert!(winter.contains(Date::new(2018, 12, 20)));
+    assert!(winter.contains(Date::new(2019, 1, 6)));
+    assert!(!winter.contains(Date::new(2018, 12, 19)));
+    assert!(!winter.contains(Date::new(2019, 1, 7)));
+    assert!(!winter.contains(Date::new(2019, 7, 1)));
+}
+
+#[test]
+fn test_date_range_validates() {
+    assert!(DateRange::try_new((2, 29), (3, 1)).is_some());
+    assert!(DateRange::try_new((2, 30), (3, 1)).is_none());
+    assert!(DateRange::try_new((13, 1), (1, 1)).is_none());
+    let error = serde_json::from_value::<Restaurant>(json!({
+        "name": "Couch Cafeteria",
+        "hours": {},
+        "closed_ranges": [{ "from": "5/15", "to": "08-01" }]
+    }))
+    .err()
+    .unwrap();
+    assert!(
+        error.to_string().contains("invalid date \"5/15\""),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_closed_ranges_remove_hours() {
+    let restaurant = restaurant();
+    let noon = Time::new(12, 0);
+    // July 4, 2018 was a Wednesday.
+    let july = Date::new(2018, 7, 4);
+    assert!(restaurant.is_closed_for_season(july));
+    assert!(restaurant.hours_on(july, Day::Wednesday).is_none());
+    assert!(!restaurant.is_open_on(july, Day::Wednesday, noon));
+    assert!(!restaurant.is_viable_on(july, Day::Wednesday, noon));
+    // So was September 5, 2018.
+    let september = Date::new(2018, 9, 5);
+    assert!(!restaurant.is_closed_for_season(september));
+    assert!(restaurant.is_viable_on(september, Day::Wednesday, noon));
+}
+
+#[test]
+fn test_closed_range_boundaries() {
+    let restaurant = restaurant();
+    let noon = Time::new(12, 0);
+    let open = |date: Date, day: Day| restaurant.is_open_on(date, day, noon);
+    assert!(open(Date::new(2018, 5, 14), Day::Monday));
+    assert!(!open(Date::new(2018, 5, 15), Day::Tuesday));
+    assert!(!open(Date::new(2018, 8, 1), Day::Wednesday));
+    assert!(open(Date::new(2018, 8, 2), Day::Thursday));
+    assert!(open(Date::new(2018, 12, 19), Day::Wednesday));
+    assert!(!open(Date::new(2018, 12, 20), Day::Thursday));
+    assert!(!open(Date::new(2019, 1, 6), Day::Sunday));
+    assert!(open(Date::new(2019, 1, 7), Day::Monday));
+}
@@ -0,0 +1,101 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::{validate, Listing, Restaurant, RestaurantBuilder, Severity};
+
+#[test]
+fn test_round_trip() {
+    let value = json!({
+        "name": "Pepe Delgado's",
+        "id": "pepe-delgados",
+        "hours": {},
+        "url": "https://pepedelgados.com/menu",
+        "phone": "(405) 321-6232"
+    });
+    let restaurant: Restaurant = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(restaurant.url(), Some("https://pepedelgados.com/menu"));
+    assert_eq!(restaurant.phone(), Some("(405) 321-6232"));
+    assert_eq!(serde_json::to_value(&restaurant).unwrap(), value);
+}
+
+#[test]
+fn test_missing_or_blank() {
+    let restaurants: Vec<Restaurant> = serde_json::from_value(json!([
+        { "name": "Crossroads", "hours": {} },
+        { "name": "Couch", "hours": {}, "url": "", "phone": "  " }
+    ]))
+    .unwrap();
+    for restaurant in &restaurants {
+        assert_eq!(restaurant.url(), None);
+        assert_eq!(restaurant.phone(), None);
+        let value = serde_json::to_value(restaurant).unwrap();
+        assert!(value.get("url").is_none() && value.get("phone").is_none());
+    }
+}
+
+#[test]
+fn test_listing() {
+    let restaurant = RestaurantBuilder::new()
+        .name("Pepe Delgado's")
+        .url("https://pepedelgados.com/menu")
+        .phone("(405) 321-6232")
+        .cuisine("Mexican")
+        .build()
+        .unwrap();
+    let listing = Listing::new(&restaurant, "11:00–21:00".into(), true);
+    assert_eq!(
+        listing,
+        Listing {
+            id: "pepe-delgados".into(),
+            name: "Pepe Delgado's".into(),
+            hours: "11:00–21:00".into(),
+            open: true,
+            cuisine: Some("Mexican".into()),
+            price: None,
+            temporarily_closed: false,
+            note: None,
+            url: Some("https://pepedelgados.com/menu".into()),
+            phone: Some("(405) 321-6232".into()),
+        }
+    );
+    let plain = RestaurantBuilder::new().name("Crossroads").build().unwrap();
+    let listing = Listing::new(&plain, "Closed today".into(), false);
+    assert_eq!((listing.url, listing.phone), (None, None));
+}
+
+#[test]
+fn test_malformed_urls_are_warnings() {
+    let restaurants: Vec<Restaurant> = serde_json::from_value(json!([
+        { "name": "Crossroads", "hours": {}, "url": "http://ou.edu/crossroads" },
+        { "name": "Couch", "hours": {}, "url": "ou.edu/couch" },
+        { "name": "Xcetera", "hours": {}, "url": "https://ou.edu/x cetera" },
+        { "name": "Taco Bell", "hours": {}, "url": "https://" }
+    ]))
+    .unwrap();
+    let warnings = validate(&restaurants)
+        .into_iter()
+        .filter(|i| i.message.starts_with("URL"))
+        .map(|i| {
+            assert_eq!(i.severity, Severity::Warning);
+            (i.restaurant, i.message)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        vec![
+            (
+                "Couch".into(),
+                "URL \"ou.edu/couch\" doesn't look like a web address".into()
+            ),
+            (
+                "Xcetera".into(),
+                "URL \"https://ou.edu/x cetera\" doesn't look like a web address".into()
+            ),
+            (
+                "Taco Bell".into(),
+                "URL \"https://\" doesn't look like a web address".into()
+            ),
+        ]
+    );
+}
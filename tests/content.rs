@@ -0,0 +1,59 @@
+extern crate eat_ou;
+
+use eat_ou::ui::{state_contents, Content, State};
+
+const STATES: [State; 7] = [
+    State::Presenting,
+    State::Terminated,
+    State::NothingOpen,
+    State::Tabulating,
+    State::Loading,
+    State::Failed,
+    State::NoData,
+];
+
+#[test]
+fn test_content_label() {
+    let glyph = Content::Glyph {
+        glyph: "🤷",
+        alt: "Out of suggestions",
+    };
+    assert_eq!(glyph.label(), Some("Out of suggestions"));
+    assert_eq!(Content::Text("Chipotle".into()).label(), None);
+}
+
+#[test]
+fn test_state_contents_labels() {
+    for &state in &STATES {
+        let contents = state_contents(state);
+        assert!(!contents.is_empty());
+        for (id, content) in contents {
+            match content {
+                Content::Glyph { alt, .. } => {
+                    assert!(!alt.is_empty(), "{:?}: #{} has an empty label", state, id)
+                }
+                Content::Text(_) => assert!(content.label().is_none()),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_state_contents_list_button() {
+    // The buttons stay hidden until the data has loaded.
+    let loaded = STATES
+        .iter()
+        .filter(|&&state| state != State::Loading && state != State::Failed);
+    for &state in loaded {
+        let list_text = state_contents(state)
+            .into_iter()
+            .find(|&(id, _)| id == "list_text")
+            .map(|(_, content)| content);
+        let expected = if state == State::Tabulating {
+            "Exit list mode"
+        } else {
+            "Show as list"
+        };
+        assert_eq!(list_text.as_ref().and_then(Content::label), Some(expected));
+    }
+}
@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::{DataError, Restaurant};
+
+const CSV: &str = "\
+Name,Sunday,Monday,Tuesday,Wednesday,Thursday,Friday,Saturday
+Crossroads,closed,07:00-21:00,07:00-21:00,07:00-21:00,07:00-21:00,07:00-02:00,10:00-02:00
+\"Pepe Delgado's\",closed,\"11:00-15:00,16:00-21:00\",closed,closed,closed,11:00-22:00,closed
+
+Coffee Cart,08:00-12:00,08:00-12:00,08:00-12:00,08:00-12:00,08:00-12:00,08:00-12:00,08:00-12:00
+";
+
+fn equivalent_json() -> Vec<Restaurant> {
+    Restaurant::from_json_value(json!([
+        {
+            "name": "Crossroads",
+            "hours": {
+                "weekdays": { "start": "7:00", "end": "21:00" },
+                "friday": { "start": "7:00", "end": "26:00" },
+                "saturday": { "start": "10:00", "end": "26:00" }
+            }
+        },
+        {
+            "name": "Pepe Delgado's",
+            "hours": {
+                "monday": {
+                    "start": "11:00",
+                    "end": "21:00",
+                    "breaks": [{ "start": "15:00", "end": "16:00" }]
+                },
+                "friday": { "start": "11:00", "end": "22:00" }
+            }
+        },
+        {
+            "name": "Coffee Cart",
+            "hours": { "daily": { "start": "8:00", "end": "12:00" } }
+        }
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_matches_json() {
+    let from_csv = Restaurant::from_csv(CSV.as_bytes()).unwrap();
+    assert!(from_csv == equivalent_json());
+    assert_eq!(
+        serde_json::to_value(&from_csv).unwrap(),
+        serde_json::to_value(equivalent_json()).unwrap()
+    );
+    assert_eq!(from_csv[1].id(), "pepe-delgados");
+}
+
+fn error(csv: &str) -> (usize, usize, String) {
+    match Restaurant::from_csv(csv.as_bytes()) {
+        Err(DataError::Csv {
+            row,
+            column,
+            reason,
+        }) => (row, column, reason),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("expected an error"),
+    }
+}
+
+#[test]
+fn test_errors_cite_row_and_column() {
+    let header = "name,sunday,monday,tuesday,wednesday,thursday,friday,saturday\n";
+    let (row, column, reason) = error(&format!(
+        "{}Crossroads,closed,closed,closed,9-5,closed,closed,closed\n",
+        header
+    ));
+    assert_eq!((row, column), (2, 5));
+    assert!(
+        reason.starts_with("wednesday: couldn't read \"9-5\""),
+        "{}",
+        reason
+    );
+    let (row, column, _) = error(&format!("{}\nCrossroads,closed,closed\n", header));
+    assert_eq!((row, column), (3, 4));
+    let (row, column, _) = error(&format!("{}Crossroads,,,,,,,\n", header));
+    assert_eq!((row, column), (2, 2));
+    let (row, column, reason) = error("name,monday,tuesday\n");
+    assert_eq!((row, column), (1, 2));
+    assert_eq!(reason, "expected a column named \"sunday\", not \"monday\"");
+    assert_eq!(
+        DataError::Csv {
+            row: 4,
+            column: 1,
+            reason: "expected a name".into(),
+        }
+        .to_string(),
+        "The restaurant data is invalid at row 4, column 1: expected a name"
+    );
+}
+
+#[test]
+fn test_empty() {
+    match Restaurant::from_csv("".as_bytes()) {
+        Err(DataError::Empty) => {}
+        _ => panic!("expected no data"),
+    }
+}
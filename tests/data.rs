@@ -0,0 +1,192 @@
+extern crate eat_ou;
+
+use eat_ou::{
+    load_extra_restaurants, merge_restaurants, parse_validated, DataError, Day, MemoryStorage,
+    Restaurant, Severity, Storage, Time, EXTRA_RESTAURANTS_KEY,
+};
+use std::time::Duration;
+
+#[test]
+fn test_parse_validated_accepts_warnings() {
+    let json = r#"[
+        { "name": "Crossroads", "hours": { "daily": { "start": "7:00", "end": "26:00" } } },
+        { "name": "Coming Soon", "hours": {} }
+    ]"#;
+    assert_eq!(parse_validated(json).unwrap().len(), 2);
+}
+
+#[test]
+fn test_parse_validated_rejects_errors() {
+    let json = r#"[
+        { "name": "Crossroads", "hours": {} },
+        { "name": "Crossroads", "hours": {} }
+    ]"#;
+    match parse_validated(json) {
+        Err(DataError::Invalid(issues)) => {
+            assert!(!issues.is_empty());
+            assert!(issues.iter().all(|i| i.severity == Severity::Error));
+        }
+        other => panic!("unexpected result: {:?}", other.map(|r| r.len())),
+    }
+}
+
+#[test]
+fn test_parse_validated_rejects_empty_and_malformed() {
+    match parse_validated("[]") {
+        Err(DataError::Empty) => {}
+        other => panic!("unexpected result: {:?}", other.map(|r| r.len())),
+    }
+    match parse_validated("<html>") {
+        Err(DataError::Parse(_)) => {}
+        other => panic!("unexpected result: {:?}", other.map(|r| r.len())),
+    }
+}
+
+#[test]
+fn test_fetch_error_message() {
+    let error = DataError::Fetch("timed out".into());
+    assert_eq!(
+        error.to_string(),
+        "Failed to fetch restaurant data: timed out"
+    );
+}
+
+fn names(restaurants: &[Restaurant]) -> Vec<&str> {
+    restaurants.iter().map(|r| r.name.as_str()).collect()
+}
+
+fn base() -> Vec<Restaurant> {
+    Restaurant::from_json_str(
+        r#"[
+            { "name": "Crossroads", "hours": { "daily": { "start": "7:00", "end": "26:00" } } },
+            { "name": "Baja Fresh", "hours": { "daily": { "start": "10:30", "end": "21:00" } } }
+        ]"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_merge_appends_extra_restaurants() {
+    let extra = Restaurant::from_json_str(r#"[{ "name": "Taco Shop", "hours": {} }]"#).unwrap();
+    let (merged, _) = merge_restaurants(base(), extra);
+    assert_eq!(
+        names(&merged),
+        vec!["Crossroads", "Baja Fresh", "Taco Shop"]
+    );
+}
+
+#[test]
+fn test_merge_replaces_same_id() {
+    let extra = Restaurant::from_json_str(
+        r#"[{ "name": "Crossroads", "hours": { "daily": { "start": "9:00", "end": "17:00" } } }]"#,
+    )
+    .unwrap();
+    let (merged, _) = merge_restaurants(base(), extra);
+    assert_eq!(names(&merged), vec!["Crossroads", "Baja Fresh"]);
+    assert_eq!(
+        merged[0].get_hours(Day::Monday).map(|h| h.start()),
+        Some(Time::new(9, 0))
+    );
+}
+
+#[test]
+fn test_merge_overrides_fields() {
+    let base = Restaurant::from_json_str(
+        r#"[{
+            "name": "Crossroads",
+            "hours": { "daily": { "start": "7:00", "end": "26:00" } },
+            "walk_minutes": 5,
+            "cuisine": "American"
+        }]"#,
+    )
+    .unwrap();
+    let extra = Restaurant::from_json_str(
+        r#"[{ "name": "Crossroads", "hours": {}, "cuisine": "Diner", "note": "Cash only" }]"#,
+    )
+    .unwrap();
+    let (merged, issues) = merge_restaurants(base, extra);
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[0].cuisine(), Some("Diner"));
+    assert_eq!(merged[0].note(), Some("Cash only"));
+    assert_eq!(merged[0].travel_time(), Some(Duration::from_secs(300)));
+    // An entry without hours leaves them alone.
+    assert_eq!(merged[0].days_open_count(), 7);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, Severity::Warning);
+    assert_eq!(
+        issues[0].to_string(),
+        "Warning: Crossroads: merged with an earlier entry with the same ID (\"crossroads\")"
+    );
+}
+
+#[test]
+fn test_merge_keeps_temporarily_closed() {
+    let base = Restaurant::from_json_str(
+        r#"[{ "name": "Crossroads", "hours": {}, "temporarily_closed": true }]"#,
+    )
+    .unwrap();
+    let extra =
+        Restaurant::from_json_str(r#"[{ "name": "Crossroads", "hours": {}, "zone": "south" }]"#)
+            .unwrap();
+    let (merged, _) = merge_restaurants(base.clone(), extra);
+    assert!(merged[0].is_temporarily_closed());
+    let reopened = Restaurant::from_json_str(
+        r#"[{ "name": "Crossroads", "hours": {}, "temporarily_closed": false }]"#,
+    )
+    .unwrap();
+    let (merged, _) = merge_restaurants(base, reopened);
+    assert!(!merged[0].is_temporarily_closed());
+}
+
+#[test]
+fn test_merge_unions_tags() {
+    let base = Restaurant::from_json_str(
+        r#"[{ "name": "Crossroads", "hours": {}, "tags": ["late-night", "cheap"] }]"#,
+    )
+    .unwrap();
+    let extra = Restaurant::from_json_str(
+        r#"[{ "name": "Crossroads", "hours": {}, "tags": ["cheap", "diner"] }]"#,
+    )
+    .unwrap();
+    let (merged, _) = merge_restaurants(base, extra);
+    assert_eq!(merged[0].tags(), &["late-night", "cheap", "diner"]);
+}
+
+#[test]
+fn test_merge_collapses_exact_duplicates() {
+    let (merged, issues) = merge_restaurants(base(), base());
+    assert_eq!(names(&merged), vec!["Crossroads", "Baja Fresh"]);
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_load_extra_restaurants() {
+    let storage = MemoryStorage::new();
+    assert_eq!(load_extra_restaurants(&storage).unwrap().len(), 0);
+    storage.set(
+        EXTRA_RESTAURANTS_KEY,
+        r#"[{ "name": "Taco Shop", "hours": {} }]"#,
+    );
+    assert_eq!(
+        names(&load_extra_restaurants(&storage).unwrap()),
+        vec!["Taco Shop"]
+    );
+}
+
+#[test]
+fn test_load_invalid_extra_restaurants() {
+    let storage = MemoryStorage::new();
+    storage.set(EXTRA_RESTAURANTS_KEY, "[{ \"name\": ");
+    match load_extra_restaurants(&storage) {
+        Err(DataError::Parse(_)) => {}
+        other => panic!("unexpected result: {:?}", other.map(|r| r.len())),
+    }
+    storage.set(
+        EXTRA_RESTAURANTS_KEY,
+        r#"[{ "name": "Taco Shop", "hours": {} }, { "name": "Taco Shop", "hours": {} }]"#,
+    );
+    match load_extra_restaurants(&storage) {
+        Err(DataError::Invalid(_)) => {}
+        other => panic!("unexpected result: {:?}", other.map(|r| r.len())),
+    }
+}
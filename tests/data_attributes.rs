@@ -0,0 +1,40 @@
+extern crate eat_ou;
+
+use eat_ou::ui::{DataAttributes, UiError};
+use std::collections::HashMap;
+
+/// Stands in for a DOM element's `data-*` attributes.
+#[derive(Default)]
+struct Shim(HashMap<String, String>);
+
+impl DataAttributes for Shim {
+    fn has_data_attribute(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    fn set_data_attribute(&mut self, name: &str, value: &str) -> Result<(), UiError> {
+        self.0.insert(name.into(), value.into());
+        Ok(())
+    }
+
+    fn clear_data_attribute(&mut self, name: &str) -> Result<(), UiError> {
+        self.0.remove(name);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_toggle_sets_when_absent() {
+    let mut shim = Shim::default();
+    assert_eq!(shim.toggle_data_attribute("tabulating", "1"), Ok(true));
+    assert_eq!(shim.0.get("tabulating").map(String::as_str), Some("1"));
+}
+
+#[test]
+fn test_toggle_clears_when_present() {
+    let mut shim = Shim::default();
+    shim.set_data_attribute("tabulating", "1").unwrap();
+    assert_eq!(shim.toggle_data_attribute("tabulating", "1"), Ok(false));
+    assert!(!shim.has_data_attribute("tabulating"));
+    assert_eq!(shim.toggle_data_attribute("tabulating", "1"), Ok(true));
+}
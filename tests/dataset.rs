@@ -0,0 +1,83 @@
+extern crate eat_ou;
+
+use eat_ou::{load, DataError, Date, Day, Restaurant, Time, DATA_VERSION};
+
+#[test]
+fn test_bare_array() {
+    let dataset = load(
+        r#"[{ "name": "Crossroads", "hours": { "daily": { "start": "7:00", "end": "26:00" } } }]"#,
+    )
+    .unwrap();
+    assert_eq!(dataset.version, 1);
+    assert_eq!(dataset.updated, None);
+    assert_eq!(dataset.restaurants.len(), 1);
+    assert!(dataset.restaurants[0].is_open_at(Day::Saturday, Time::new(1, 0)));
+}
+
+#[test]
+fn test_wrapped_current_version() {
+    let json = format!(
+        r#"{{
+            "version": {},
+            "updated": "2024-09-01",
+            "schedules": {{ "union": {{ "weekdays": {{ "start": "8:00", "end": "15:00" }} }} }},
+            "restaurants": [
+                {{ "name": "Chick-fil-A", "hours": {{ "extends": "union" }} }},
+                {{ "name": "Crossroads", "hours": {{ "daily": {{ "start": "7:00", "end": "26:00" }} }} }}
+            ]
+        }}"#,
+        DATA_VERSION
+    );
+    let dataset = load(&json).unwrap();
+    assert_eq!(dataset.version, DATA_VERSION);
+    assert_eq!(dataset.updated, Some(Date::new(2024, 9, 1)));
+    let names = dataset
+        .restaurants
+        .iter()
+        .map(|r| r.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Chick-fil-A", "Crossroads"]);
+    assert!(Restaurant::from_json_str(&json).unwrap() == dataset.restaurants);
+}
+
+#[test]
+fn test_wrapped_without_version() {
+    let dataset = load(r#"{ "restaurants": [{ "name": "Crossroads", "hours": {} }] }"#).unwrap();
+    assert_eq!(dataset.version, 1);
+}
+
+#[test]
+fn test_future_version_is_rejected() {
+    // Restaurants in a newer format may not parse at all; the version is checked first.
+    let json = r#"{
+        "version": 3,
+        "restaurants": [{ "name": "Crossroads", "hours": "always" }]
+    }"#;
+    match load(json) {
+        Err(DataError::UnsupportedVersion(3)) => {}
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("expected an error"),
+    }
+    let error = Restaurant::from_json_str(json).err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "The restaurant data is in version 3 of the format, but only versions up to 2 are \
+         understood (reloading may update the app)"
+    );
+    assert!(Restaurant::validate_list(json).is_err());
+}
+
+#[test]
+fn test_invalid_metadata() {
+    for json in &[
+        r#"{ "version": "two", "restaurants": [] }"#,
+        r#"{ "version": 0, "restaurants": [] }"#,
+        r#"{ "updated": "September", "restaurants": [] }"#,
+    ] {
+        match load(json) {
+            Err(DataError::Parse(_)) => {}
+            Err(e) => panic!("unexpected error for {}: {}", json, e),
+            Ok(_) => panic!("expected an error for {}", json),
+        }
+    }
+}
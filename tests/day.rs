@@ -0,0 +1,59 @@
+extern crate eat_ou;
+extern crate serde_json;
+
+use eat_ou::Day;
+
+#[test]
+fn test_day_from_str() {
+    assert_eq!("Monday".parse::<Day>().unwrap(), Day::Monday);
+    assert_eq!("FRIDAY".parse::<Day>().unwrap(), Day::Friday);
+    assert_eq!("sat".parse::<Day>().unwrap(), Day::Saturday);
+    assert!("Mondays".parse::<Day>().is_err());
+    assert!("th".parse::<Day>().is_err());
+}
+
+#[test]
+fn test_day_from_index() {
+    assert_eq!("0".parse::<Day>().unwrap(), Day::Sunday);
+    assert_eq!(" 3 ".parse::<Day>().unwrap(), Day::Wednesday);
+    assert_eq!("6".parse::<Day>().unwrap(), Day::Saturday);
+    let error = "7".parse::<Day>().unwrap_err().to_string();
+    assert!(error.contains("integer 0–6"), "{}", error);
+    assert!("-1".parse::<Day>().is_err());
+    let day: Day = serde_json::from_str(r#""5""#).unwrap();
+    assert_eq!(day, Day::Friday);
+}
+
+#[test]
+fn test_deserialize_day_forms() {
+    let days: Vec<Day> =
+        serde_json::from_str(r#"[0, 6, "Sunday", "wednesday", "THU", "tue"]"#).unwrap();
+    assert_eq!(
+        days,
+        vec![
+            Day::Sunday,
+            Day::Saturday,
+            Day::Sunday,
+            Day::Wednesday,
+            Day::Thursday,
+            Day::Tuesday,
+        ]
+    );
+}
+
+#[test]
+fn test_deserialize_day_out_of_range() {
+    let error = serde_json::from_str::<Day>("7").unwrap_err().to_string();
+    assert!(error.contains("integer 0–6"), "{}", error);
+    let error = serde_json::from_str::<Day>("-1").unwrap_err().to_string();
+    assert!(error.contains("three-letter abbreviation"), "{}", error);
+}
+
+#[test]
+fn test_deserialize_day_bad_name() {
+    let error = serde_json::from_str::<Day>(r#""Funday""#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("Funday"), "{}", error);
+    assert!(error.contains("full day name"), "{}", error);
+}
@@ -0,0 +1,103 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{Day, DayMap, Hours, Restaurant};
+
+#[test]
+fn test_get_and_insert() {
+    let mut map = DayMap::new();
+    assert_eq!(map.get(Day::Monday), None);
+    assert_eq!(map.insert(Day::Monday, "tacos"), None);
+    assert_eq!(map.insert(Day::Monday, "pizza"), Some("tacos"));
+    assert_eq!(map.get(Day::Monday), Some(&"pizza"));
+    assert_eq!(map.get(Day::Tuesday), None);
+}
+
+#[test]
+fn test_iter_in_day_order() {
+    let mut map = DayMap::new();
+    map.insert(Day::Saturday, 6);
+    map.insert(Day::Sunday, 0);
+    map.insert(Day::Wednesday, 3);
+    let days = map.iter().collect::<Vec<_>>();
+    assert_eq!(
+        days,
+        vec![(Day::Sunday, &0), (Day::Wednesday, &3), (Day::Saturday, &6)]
+    );
+}
+
+#[test]
+fn test_deserialize_lowercase_keys() {
+    let map: DayMap<u8> =
+        serde_json::from_str(r#"{ "monday": 1, "friday": 5, "sunday": null, "note": "hi" }"#)
+            .unwrap();
+    assert_eq!(map.get(Day::Monday), Some(&1));
+    assert_eq!(map.get(Day::Friday), Some(&5));
+    assert_eq!(map.get(Day::Sunday), None);
+    assert_eq!(map.iter().count(), 2);
+}
+
+#[test]
+fn test_shorthand_matches_verbose_form() {
+    let shorthand: DayMap<Hours> = serde_json::from_value(json!({
+        "weekdays": { "start": "10:00", "end": "18:00" },
+        "weekends": { "start": "12:00", "end": "16:00" },
+        "friday": { "start": "10:00", "end": "17:00" }
+    }))
+    .unwrap();
+    let verbose: DayMap<Hours> = serde_json::from_value(json!({
+        "sunday": { "start": "12:00", "end": "16:00" },
+        "monday": { "start": "10:00", "end": "18:00" },
+        "tuesday": { "start": "10:00", "end": "18:00" },
+        "wednesday": { "start": "10:00", "end": "18:00" },
+        "thursday": { "start": "10:00", "end": "18:00" },
+        "friday": { "start": "10:00", "end": "17:00" },
+        "saturday": { "start": "12:00", "end": "16:00" }
+    }))
+    .unwrap();
+    assert_eq!(shorthand, verbose);
+}
+
+#[test]
+fn test_daily_with_closed_day() {
+    let shorthand: DayMap<Hours> = serde_json::from_value(json!({
+        "daily": { "start": "7:00", "end": "22:00" },
+        "sunday": null
+    }))
+    .unwrap();
+    let verbose: DayMap<Hours> = serde_json::from_value(json!({
+        "monday": { "start": "7:00", "end": "22:00" },
+        "tuesday": { "start": "7:00", "end": "22:00" },
+        "wednesday": { "start": "7:00", "end": "22:00" },
+        "thursday": { "start": "7:00", "end": "22:00" },
+        "friday": { "start": "7:00", "end": "22:00" },
+        "saturday": { "start": "7:00", "end": "22:00" }
+    }))
+    .unwrap();
+    assert_eq!(shorthand, verbose);
+}
+
+#[test]
+fn test_agreeing_shorthands() {
+    let map: DayMap<u8> =
+        serde_json::from_value(json!({ "daily": 1, "weekdays": 1, "weekends": 1 })).unwrap();
+    assert_eq!(map.iter().count(), 7);
+}
+
+#[test]
+fn test_conflicting_shorthands_name_restaurant() {
+    let error = serde_json::from_value::<Restaurant>(json!({
+        "name": "Couch Express",
+        "hours": {
+            "daily": { "start": "7:00", "end": "24:00" },
+            "weekdays": { "start": "7:00", "end": "22:00" }
+        }
+    }))
+    .err()
+    .unwrap()
+    .to_string();
+    assert!(error.contains("Couch Express"), "{}", error);
+    assert!(error.contains("weekdays"), "{}", error);
+}
@@ -0,0 +1,91 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::app::get_viable;
+use eat_ou::{
+    validate, Day, Dietary, FixedClock, Restaurant, RestaurantFilter, Severity, Time,
+    ViabilityOptions,
+};
+
+fn restaurants() -> Vec<Restaurant> {
+    serde_json::from_value(json!([
+        {
+            "name": "Veggie Grill",
+            "dietary": ["vegetarian", "vegan", "gluten_free"],
+            "hours": { "daily": { "start": "11:00", "end": "21:00" } }
+        },
+        {
+            "name": "Halal Cart",
+            "dietary": ["Halal", "kosher"],
+            "hours": { "daily": { "start": "11:00", "end": "21:00" } }
+        },
+        {
+            "name": "Steakhouse",
+            "hours": { "daily": { "start": "11:00", "end": "21:00" } }
+        },
+        {
+            "name": "Vegan Brunch",
+            "dietary": ["vegan"],
+            "hours": { "daily": { "start": "8:00", "end": "11:00" } }
+        }
+    ]))
+    .unwrap()
+}
+
+fn names(filter: &RestaurantFilter) -> Vec<String> {
+    filter
+        .apply(&restaurants())
+        .into_iter()
+        .map(|r| r.name.clone())
+        .collect()
+}
+
+#[test]
+fn test_dietary_accessors() {
+    let restaurants = restaurants();
+    assert_eq!(
+        restaurants[0].dietary(),
+        &[Dietary::Vegetarian, Dietary::Vegan, Dietary::GlutenFree]
+    );
+    assert!(restaurants[1].has_dietary(&Dietary::Halal));
+    assert!(!restaurants[1].has_dietary(&Dietary::Vegan));
+    assert!(restaurants[2].dietary().is_empty());
+}
+
+#[test]
+fn test_unknown_dietary_options_preserved_and_reported() {
+    let restaurants = restaurants();
+    assert!(restaurants[1].has_dietary(&Dietary::Unknown("kosher".into())));
+    let issues = validate(&restaurants);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, Severity::Warning);
+    assert_eq!(issues[0].restaurant, "Halal Cart");
+    assert!(issues[0].message.contains("\"kosher\""));
+}
+
+#[test]
+fn test_dietary_filter() {
+    assert_eq!(
+        names(&RestaurantFilter::new().with_dietary(vec![Dietary::Vegan])),
+        vec!["Veggie Grill", "Vegan Brunch"]
+    );
+    assert_eq!(
+        names(&RestaurantFilter::new().with_dietary(vec![Dietary::Vegan, Dietary::GlutenFree])),
+        vec!["Veggie Grill"]
+    );
+}
+
+#[test]
+fn test_dietary_filter_composes_with_viability() {
+    let filter = RestaurantFilter::new().with_dietary(vec![Dietary::Vegan]);
+    let viable = get_viable(
+        &restaurants(),
+        &filter,
+        ViabilityOptions::default(),
+        &FixedClock::new(Day::Monday, Time::new(12, 0)),
+    );
+    let names = viable.into_iter().map(|r| r.name).collect::<Vec<_>>();
+    assert_eq!(names, vec!["Veggie Grill"]);
+}
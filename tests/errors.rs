@@ -0,0 +1,81 @@
+extern crate eat_ou;
+
+use eat_ou::{DataError, FromStrError, Restaurant, Time};
+
+fn error_for(json: &str) -> String {
+    match Restaurant::from_json_str(json) {
+        Err(DataError::Parse(e)) => e.to_string(),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("expected an error"),
+    }
+}
+
+#[test]
+fn test_error_names_restaurant_and_day() {
+    let json = r#"[
+        {
+            "name": "Raising Cane's",
+            "hours": {
+                "monday": { "start": "10:30", "end": "24:00" },
+                "tuesday": { "start": "1O:30", "end": "24:00" }
+            }
+        }
+    ]"#;
+    assert!(error_for(json).starts_with("Raising Cane's → tuesday → start: invalid time \"1O:30\""));
+}
+
+#[test]
+fn test_error_names_restaurant_by_position() {
+    let json = r#"{
+        "restaurants": [
+            { "name": "Crossroads", "hours": {} },
+            { "hours": { "daily": { "start": "9:00", "end": "25:61" } } }
+        ]
+    }"#;
+    assert!(error_for(json).starts_with("restaurant #2 → daily → end: invalid time \"25:61\""));
+}
+
+#[test]
+fn test_error_names_bad_value() {
+    let json =
+        r#"[{ "name": "Couch", "hours": { "monday": { "start": "9:3o", "end": "14:00" } } }]"#;
+    let error = error_for(json);
+    assert!(error.contains("\"9:3o\""), "{}", error);
+    assert!(
+        error.starts_with(
+            "Couch → monday → start: invalid time \"9:3o\": hours and minutes must be numbers"
+        ),
+        "{}",
+        error
+    );
+    let error = error_for(
+        r#"[{ "name": "Couch", "hours": { "monday": { "start": "9:00", "end": "48:00" } } }]"#,
+    );
+    assert!(
+        error.starts_with(
+            "Couch → monday → end: invalid time \"48:00\": hours must be between 0 and 47"
+        ),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_parse_rejects_non_numeric() {
+    match "1O:30".parse::<Time>() {
+        Err(FromStrError::InvalidNumber) => {}
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_data_error() {
+    let parse = Restaurant::from_json_str("{").err().unwrap();
+    assert!(parse
+        .to_string()
+        .starts_with("Failed to parse restaurant data: "));
+    assert_eq!(
+        DataError::Empty.to_string(),
+        "The restaurant data contains no restaurants"
+    );
+}
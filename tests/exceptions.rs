@@ -0,0 +1,94 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{Date, Day, Restaurant, Time};
+
+fn with_exceptions() -> Restaurant {
+    serde_json::from_value(json!({
+        "name": "Couch Restaurants",
+        "hours": {
+            "daily": { "start": "7:00", "end": "21:00" }
+        },
+        "exceptions": [
+            { "date": "2024-11-28", "hours": null },
+            {
+                "from": "2024-12-09",
+                "to": "2024-12-13",
+                "hours": { "start": "7:00", "end": "26:00" }
+            }
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_date_parsing() {
+    assert_eq!(
+        "2024-11-28".parse::<Date>().unwrap(),
+        Date::new(2024, 11, 28)
+    );
+    assert_eq!(format!("{}", Date::new(2024, 2, 9)), "2024-02-09");
+    assert!("2024-02-29".parse::<Date>().is_ok());
+    assert!("2023-02-29".parse::<Date>().is_err());
+    assert!("2024-13-01".parse::<Date>().is_err());
+    assert!("2024-11".parse::<Date>().is_err());
+    assert!("Thanksgiving".parse::<Date>().is_err());
+}
+
+#[test]
+fn test_date_previous() {
+    assert_eq!(Date::new(2024, 3, 1).previous(), Date::new(2024, 2, 29));
+    assert_eq!(Date::new(2025, 1, 1).previous(), Date::new(2024, 12, 31));
+    assert_eq!(Date::new(2024, 11, 28).previous(), Date::new(2024, 11, 27));
+}
+
+#[test]
+fn test_date_next() {
+    assert_eq!(Date::new(2024, 2, 28).next(), Date::new(2024, 2, 29));
+    assert_eq!(Date::new(2024, 2, 29).next(), Date::new(2024, 3, 1));
+    assert_eq!(Date::new(2024, 12, 31).next(), Date::new(2025, 1, 1));
+    assert_eq!(Date::new(2024, 11, 27).next(), Date::new(2024, 11, 28));
+}
+
+#[test]
+fn test_exception_overrides_weekly_hours() {
+    let r = with_exceptions();
+    let noon = Time::new(12, 0);
+    // Thanksgiving (a Thursday) is closed, but the rest of the week isn't.
+    assert!(!r.is_viable_on(Date::new(2024, 11, 28), Day::Thursday, noon));
+    assert!(r.is_viable_on(Date::new(2024, 11, 27), Day::Wednesday, noon));
+    assert!(r.is_viable_on(Date::new(2024, 12, 5), Day::Thursday, noon));
+    assert!(r.is_viable(Day::Thursday, noon));
+    assert_eq!(r.hours_on(Date::new(2024, 11, 28), Day::Thursday), None);
+}
+
+#[test]
+fn test_exception_span_with_replacement_hours() {
+    let r = with_exceptions();
+    let late = Time::new(23, 0);
+    assert!(r.is_viable_on(Date::new(2024, 12, 9), Day::Monday, late));
+    assert!(r.is_viable_on(Date::new(2024, 12, 13), Day::Friday, late));
+    assert!(!r.is_viable_on(Date::new(2024, 12, 14), Day::Saturday, late));
+    // Finals-week hours run past midnight into the following day.
+    assert!(r.is_open_on(Date::new(2024, 12, 14), Day::Saturday, Time::new(1, 0)));
+    assert!(!r.is_open_on(Date::new(2024, 12, 9), Day::Monday, Time::new(1, 0)));
+}
+
+#[test]
+fn test_invalid_exceptions() {
+    let missing = serde_json::from_value::<Restaurant>(json!({
+        "name": "Couch Restaurants",
+        "hours": {},
+        "exceptions": [{ "hours": null }]
+    }));
+    let error = missing.err().unwrap().to_string();
+    assert!(error.contains("Couch Restaurants"));
+    let backwards = serde_json::from_value::<Restaurant>(json!({
+        "name": "Couch Restaurants",
+        "hours": {},
+        "exceptions": [{ "from": "2024-12-13", "to": "2024-12-09" }]
+    }));
+    assert!(backwards.is_err());
+}
@@ -0,0 +1,83 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::{export_viable, Day, ExportedRestaurant, Restaurant, Time};
+
+fn restaurants() -> Vec<Restaurant> {
+    serde_json::from_value(json!([
+        {
+            "name": "Lunch Counter",
+            "hours": { "monday": { "start": "11:00", "end": "14:00" } }
+        },
+        {
+            "name": "Crossroads",
+            "hours": { "daily": { "start": "0:00", "end": "24:00" } }
+        },
+        {
+            "name": "Supper Club",
+            "hours": { "monday": { "start": "17:00", "end": "23:00" } }
+        },
+        {
+            "name": "Closing Soon",
+            "hours": { "monday": { "start": "9:00", "end": "12:20" } }
+        }
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_export_viable_round_trips() {
+    let json = export_viable(&restaurants(), Day::Monday, Time::new(12, 30));
+    let exported: Vec<ExportedRestaurant> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        exported,
+        vec![
+            ExportedRestaurant {
+                name: "Lunch Counter".into(),
+                hours: "11:00 AM–2:00 PM".into(),
+                minutes_until_close: Some(90),
+                closes_in: Some("PT1H30M".into()),
+            },
+            ExportedRestaurant {
+                name: "Crossroads".into(),
+                hours: "Open 24 hours".into(),
+                minutes_until_close: None,
+                closes_in: None,
+            },
+        ]
+    );
+    assert_eq!(serde_json::to_string_pretty(&exported).unwrap(), json);
+}
+
+#[test]
+fn test_export_viable_open_all_day() {
+    let json = export_viable(&restaurants(), Day::Sunday, Time::new(1, 0));
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        value,
+        json!([{
+            "name": "Crossroads",
+            "hours": "Open 24 hours",
+            "minutes_until_close": null,
+            "closes_in": null
+        }])
+    );
+    assert_eq!(export_viable(&[], Day::Sunday, Time::new(1, 0)), "[]");
+}
+
+#[test]
+fn test_export_viable_opens_on_the_way() {
+    let restaurants: Vec<Restaurant> = serde_json::from_value(json!([{
+        "name": "Lunch Counter",
+        "walk_minutes": 10,
+        "hours": { "monday": { "start": "12:00", "end": "14:00" } }
+    }]))
+    .unwrap();
+    assert!(restaurants[0].is_viable(Day::Monday, Time::new(11, 55)));
+    let json = export_viable(&restaurants, Day::Monday, Time::new(11, 55));
+    let exported: Vec<ExportedRestaurant> = serde_json::from_str(&json).unwrap();
+    assert_eq!(exported.len(), 1);
+    assert_eq!(exported[0].minutes_until_close, Some(125));
+    assert_eq!(exported[0].closes_in, Some("PT2H5M".into()));
+}
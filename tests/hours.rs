@@ -0,0 +1,119 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::{Day, FromStrError, Hours, HoursFromStrError, Restaurant, Time, TimeRange};
+
+fn hours(start: (i32, i32), end: (i32, i32)) -> Hours {
+    Hours::new(Time::new(start.0, start.1), Time::new(end.0, end.1))
+}
+
+#[test]
+fn test_parse_separators() {
+    assert_eq!(
+        "9:00-17:00".parse::<Hours>().unwrap(),
+        hours((9, 0), (17, 0))
+    );
+    assert_eq!(
+        "9:00–17:00".parse::<Hours>().unwrap(),
+        hours((9, 0), (17, 0))
+    );
+    assert_eq!(
+        " 21:00 - 26:30 ".parse::<Hours>().unwrap(),
+        hours((21, 0), (26, 30))
+    );
+    assert_eq!(
+        "0930-1400".parse::<Hours>().unwrap(),
+        hours((9, 30), (14, 0))
+    );
+}
+
+#[test]
+fn test_display_round_trip() {
+    let examples = vec![
+        hours((9, 0), (17, 0)),
+        hours((12, 0), (24, 0)),
+        hours((0, 0), (24, 0)),
+        hours((9, 0), (9, 0)),
+        hours((21, 0), (26, 0)),
+        hours((11, 0), (21, 0)).with_break(TimeRange::new(Time::new(15, 0), Time::new(16, 30))),
+        hours((20, 0), (27, 0)).with_break(TimeRange::new(Time::new(25, 0), Time::new(25, 30))),
+    ];
+    for original in examples {
+        let written = original.to_string();
+        assert_eq!(written.parse::<Hours>().unwrap(), original, "{}", written);
+    }
+    assert_eq!(
+        "Open 24 hours".parse::<Hours>().unwrap().to_string(),
+        "Open 24 hours"
+    );
+}
+
+#[test]
+fn test_display_all_day_from_midnight_only() {
+    assert_eq!(hours((0, 0), (0, 0)).to_string(), "Open 24 hours");
+    assert_eq!(hours((9, 0), (9, 0)).to_string(), "9:00 AM–9:00 AM");
+    assert_eq!(
+        "9:00 AM–9:00 AM".parse::<Hours>().unwrap(),
+        hours((9, 0), (9, 0))
+    );
+}
+
+#[test]
+fn test_parse_malformed() {
+    match "9:00".parse::<Hours>() {
+        Err(HoursFromStrError::MissingSeparator) => {}
+        other => panic!("expected a missing separator, got {:?}", other),
+    }
+    match "9:00-noon".parse::<Hours>() {
+        Err(HoursFromStrError::InvalidTime(ref time, FromStrError::MissingColon)) => {
+            assert_eq!(time, "noon")
+        }
+        other => panic!("expected an invalid time, got {:?}", other),
+    }
+    assert_eq!(
+        "9:00-17:61".parse::<Hours>().unwrap_err().to_string(),
+        "invalid time \"17:61\": minutes must be between 0 and 59"
+    );
+    match "13:00 PM-2:00 AM".parse::<Hours>() {
+        Err(HoursFromStrError::ClockHoursOutOfRange(_)) => {}
+        other => panic!("expected hours out of range, got {:?}", other),
+    }
+    match "9:00-17:00 (lunch 12:00-13:00)".parse::<Hours>() {
+        Err(HoursFromStrError::MalformedBreaks) => {}
+        other => panic!("expected malformed breaks, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_string_hours_in_data() {
+    let restaurant: Restaurant = serde_json::from_value(json!({
+        "name": "Crossroads",
+        "hours": {
+            "weekdays": "7:00-21:00",
+            "saturday": { "start": "10:00", "end": "14:00" }
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        restaurant.get_hours(Day::Friday),
+        Some(hours((7, 0), (21, 0)))
+    );
+    assert_eq!(
+        restaurant.get_hours(Day::Saturday),
+        Some(hours((10, 0), (14, 0)))
+    );
+    let error = serde_json::from_value::<Restaurant>(json!({
+        "name": "Crossroads",
+        "hours": { "monday": "7:00 to 21:00" }
+    }))
+    .err()
+    .unwrap();
+    assert!(
+        error
+            .to_string()
+            .contains("monday → invalid hours \"7:00 to 21:00\": expected a start and end"),
+        "{}",
+        error
+    );
+}
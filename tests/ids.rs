@@ -0,0 +1,83 @@
+extern crate eat_ou;
+
+use eat_ou::{slugify, validate, Restaurant, Severity};
+use std::collections::HashSet;
+
+fn ids(restaurants: &[Restaurant]) -> Vec<&str> {
+    restaurants.iter().map(Restaurant::id).collect()
+}
+
+#[test]
+fn test_slugify() {
+    assert_eq!(slugify("Raising Cane's"), "raising-canes");
+    assert_eq!(slugify("Chick-fil-A"), "chick-fil-a");
+    assert_eq!(
+        slugify("  Couch  Restaurants (Cross Village) "),
+        "couch-restaurants-cross-village"
+    );
+    assert_eq!(slugify("Café Tacvba"), "café-tacvba");
+    assert_eq!(slugify("???"), "restaurant");
+}
+
+#[test]
+fn test_explicit_ids() {
+    let restaurants = Restaurant::from_json_str(
+        r#"[
+            { "name": "Crossroads", "id": "xroads", "hours": {} },
+            { "name": "Raising Cane's", "hours": {} }
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(ids(&restaurants), vec!["xroads", "raising-canes"]);
+}
+
+#[test]
+fn test_generated_ids_deduplicated() {
+    let restaurants = Restaurant::from_json_str(
+        r#"[
+            { "name": "Subway", "hours": {} },
+            { "name": "Jimmy John's", "id": "subway-2", "hours": {} },
+            { "name": "Subway", "hours": {} },
+            { "name": "subway", "hours": {} }
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        ids(&restaurants),
+        vec!["subway", "subway-2", "subway-3", "subway-4"]
+    );
+    let unique = restaurants.iter().collect::<HashSet<_>>();
+    assert_eq!(unique.len(), 4);
+    assert!(restaurants[0] != restaurants[2]);
+    assert!(restaurants[0] == restaurants[0].clone());
+    // Generation is deterministic.
+    let again = Restaurant::from_json_str(
+        r#"[{ "name": "Subway", "hours": {} }, { "name": "Subway", "hours": {} }]"#,
+    )
+    .unwrap();
+    assert_eq!(ids(&again), vec!["subway", "subway-2"]);
+}
+
+#[test]
+fn test_id_collision_reported() {
+    let restaurants = Restaurant::from_json_str(
+        r#"[
+            { "name": "Crossroads", "id": "crossroads", "hours": {} },
+            { "name": "Cross Roads Grill", "id": "crossroads", "hours": {} },
+            { "name": "Subway", "hours": {} },
+            { "name": "Subway Express", "id": "subway", "hours": {} }
+        ]"#,
+    )
+    .unwrap();
+    let errors = validate(&restaurants)
+        .into_iter()
+        .filter(|i| i.severity == Severity::Error)
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].restaurant, "Cross Roads Grill");
+    assert!(
+        errors[0].message.contains("\"crossroads\""),
+        "{}",
+        errors[0].message
+    );
+}
@@ -0,0 +1,102 @@
+extern crate eat_ou;
+
+use eat_ou::{from_readable_hours, Day, DayMap, Hours, ImportError, Time, TimeRange};
+
+fn hours(start: (i32, i32), end: (i32, i32)) -> Hours {
+    Hours::new(Time::new(start.0, start.1), Time::new(end.0, end.1))
+}
+
+fn days(map: &DayMap<Hours>) -> Vec<Day> {
+    map.iter().map(|(day, _)| day).collect()
+}
+
+#[test]
+fn test_single_day() {
+    let map = from_readable_hours("Monday 11 AM–9 PM").unwrap();
+    assert_eq!(days(&map), vec![Day::Monday]);
+    assert_eq!(map.get(Day::Monday), Some(&hours((11, 0), (21, 0))));
+}
+
+#[test]
+fn test_split_lunch_and_dinner() {
+    let map =
+        from_readable_hours("Mon–Fri 11 AM–2 PM, 5–9 PM; Sat 11 AM–11 PM; Sun Closed").unwrap();
+    let split =
+        hours((11, 0), (21, 0)).with_break(TimeRange::new(Time::new(14, 0), Time::new(17, 0)));
+    for day in &[Day::Monday, Day::Wednesday, Day::Friday] {
+        assert_eq!(map.get(*day), Some(&split));
+    }
+    assert_eq!(map.get(Day::Saturday), Some(&hours((11, 0), (23, 0))));
+    assert_eq!(map.get(Day::Sunday), None);
+}
+
+#[test]
+fn test_google_maps_listing() {
+    // As copied from the sidebar: one day per line, with narrow spaces before AM and PM and thin
+    // spaces around the en dash.
+    let copied = "Sunday\t11\u{202f}AM\u{2009}–\u{2009}10\u{202f}PM\n\
+                  Monday\t10:30\u{202f}AM\u{2009}–\u{2009}10\u{202f}PM\n\
+                  Tuesday\tClosed\n\
+                  Friday\t10:30\u{202f}AM\u{2009}–\u{2009}12\u{202f}AM\n\
+                  Saturday\tOpen 24 hours";
+    let map = from_readable_hours(copied).unwrap();
+    assert_eq!(
+        days(&map),
+        vec![Day::Sunday, Day::Monday, Day::Friday, Day::Saturday]
+    );
+    assert_eq!(map.get(Day::Monday), Some(&hours((10, 30), (22, 0))));
+    assert_eq!(map.get(Day::Friday), Some(&hours((10, 30), (24, 0))));
+    assert_eq!(map.get(Day::Saturday), Some(&hours((0, 0), (24, 0))));
+}
+
+#[test]
+fn test_late_night_and_lists() {
+    let map = from_readable_hours("Thu, Fri, Sat: 9 PM - 2 AM; Sun-Wed 5pm-midnight").unwrap();
+    assert_eq!(map.get(Day::Friday), Some(&hours((21, 0), (26, 0))));
+    assert_eq!(map.get(Day::Sunday), Some(&hours((17, 0), (24, 0))));
+    assert_eq!(map.get(Day::Wednesday), Some(&hours((17, 0), (24, 0))));
+}
+
+#[test]
+fn test_abbreviations_and_24_hour_times() {
+    let map = from_readable_hours("Tues–Thurs 7:00–14:30; Fri 11–2 PM").unwrap();
+    assert_eq!(
+        days(&map),
+        vec![Day::Tuesday, Day::Wednesday, Day::Thursday, Day::Friday]
+    );
+    assert_eq!(map.get(Day::Tuesday), Some(&hours((7, 0), (14, 30))));
+    assert_eq!(map.get(Day::Friday), Some(&hours((11, 0), (14, 0))));
+}
+
+#[test]
+fn test_wrapping_day_range() {
+    let map = from_readable_hours("Fri–Mon noon to 8 p.m.").unwrap();
+    assert_eq!(
+        days(&map),
+        vec![Day::Sunday, Day::Monday, Day::Friday, Day::Saturday]
+    );
+    assert_eq!(map.get(Day::Saturday), Some(&hours((12, 0), (20, 0))));
+}
+
+#[test]
+fn test_errors_name_fragment() {
+    let error = |s: &str| from_readable_hours(s).unwrap_err();
+    assert_eq!(
+        error("Mon–Fry 11 AM–9 PM"),
+        ImportError {
+            fragment: "Fry".into(),
+            reason: "expected a day of the week",
+        }
+    );
+    assert_eq!(error("Monday 11 AM–9 QM").fragment, "9 QM");
+    assert_eq!(error("Monday 11 AM–9 PM; Tuesday 11 AM").fragment, "11 AM");
+    assert_eq!(
+        error("Monday 11 AM–9 PM; 11 AM–9 PM").fragment,
+        "11 AM–9 PM"
+    );
+    assert_eq!(
+        error("Mon 13 PM–2 AM").to_string(),
+        "couldn't read \"13 PM\": expected a time, as in 11 AM or 5:30 PM"
+    );
+    assert_eq!(error("Wednesday").fragment, "Wednesday");
+}
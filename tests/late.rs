@@ -0,0 +1,42 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{Day, Restaurant, Time};
+
+fn closing_at(end: &str) -> Restaurant {
+    serde_json::from_value(json!({
+        "name": "Diner",
+        "hours": { "friday": { "start": "11:00", "end": end } }
+    }))
+    .unwrap()
+}
+
+fn ten_pm() -> Time {
+    Time::new(22, 0)
+}
+
+#[test]
+fn test_closes_before_threshold() {
+    assert!(!closing_at("21:00").is_open_after(Day::Friday, ten_pm()));
+    assert!(!closing_at("22:00").is_open_after(Day::Friday, ten_pm()));
+}
+
+#[test]
+fn test_closes_after_threshold() {
+    assert!(closing_at("23:00").is_open_after(Day::Friday, ten_pm()));
+}
+
+#[test]
+fn test_closes_after_midnight() {
+    assert!(closing_at("2:00").is_open_after(Day::Friday, ten_pm()));
+    assert!(closing_at("26:00").is_open_after(Day::Friday, ten_pm()));
+    assert!(closing_at("2:00").is_open_after(Day::Friday, Time::new(1, 0)));
+    assert!(!closing_at("23:00").is_open_after(Day::Friday, Time::new(1, 0)));
+}
+
+#[test]
+fn test_closed_that_day() {
+    assert!(!closing_at("2:00").is_open_after(Day::Saturday, ten_pm()));
+}
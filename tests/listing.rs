@@ -0,0 +1,226 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{
+    filter_by_name, group_by_availability, group_by_cuisine, slugify, Availability, Day, Hours,
+    Listing, Restaurant, RestaurantBuilder, Time, TimeRange, CLOSED_TODAY, OPENS_LATER, OPEN_NOW,
+};
+
+fn listing(name: &str, cuisine: Option<&str>) -> Listing {
+    Listing {
+        id: slugify(name),
+        name: name.into(),
+        hours: "11:00–21:00".into(),
+        open: true,
+        cuisine: cuisine.map(String::from),
+        price: None,
+        temporarily_closed: false,
+        note: None,
+        url: None,
+        phone: None,
+    }
+}
+
+fn names(group: &(String, Vec<Listing>)) -> (&str, Vec<&str>) {
+    (
+        group.0.as_str(),
+        group.1.iter().map(|l| l.name.as_str()).collect(),
+    )
+}
+
+#[test]
+fn test_group_by_cuisine() {
+    let groups = group_by_cuisine(vec![
+        listing("Pizza Hut", Some("Pizza")),
+        listing("Chipotle", Some("Mexican")),
+        listing("Crossroads", None),
+        listing("Domino's", Some("Pizza")),
+        listing("Baja Fresh", Some("Mexican")),
+    ]);
+    assert_eq!(
+        groups.iter().map(names).collect::<Vec<_>>(),
+        vec![
+            ("Mexican", vec!["Chipotle", "Baja Fresh"]),
+            ("Pizza", vec!["Pizza Hut", "Domino's"]),
+            ("Other", vec!["Crossroads"]),
+        ]
+    );
+}
+
+#[test]
+fn test_group_by_cuisine_without_other() {
+    let groups = group_by_cuisine(vec![listing("Chipotle", Some("Mexican"))]);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].0, "Mexican");
+    assert!(group_by_cuisine(Vec::new()).is_empty());
+}
+
+#[test]
+fn test_cuisine() {
+    let restaurants: Vec<Restaurant> = serde_json::from_value(json!([
+        { "name": "Chipotle", "cuisine": "Mexican", "hours": {} },
+        { "name": "Crossroads", "hours": {} }
+    ]))
+    .unwrap();
+    assert_eq!(restaurants[0].cuisine(), Some("Mexican"));
+    assert_eq!(restaurants[1].cuisine(), None);
+}
+
+fn named(names: &[&str]) -> Vec<Listing> {
+    names.iter().map(|name| listing(name, None)).collect()
+}
+
+fn found(listings: Vec<Listing>) -> Vec<String> {
+    listings.into_iter().map(|l| l.name).collect()
+}
+
+#[test]
+fn test_filter_by_name() {
+    let listings = named(&["Chipotle", "Chick-fil-A", "Crossroads"]);
+    assert_eq!(
+        found(filter_by_name(&listings, "chi")),
+        vec!["Chipotle", "Chick-fil-A"]
+    );
+    assert_eq!(
+        found(filter_by_name(&listings, "roads")),
+        vec!["Crossroads"]
+    );
+    assert!(filter_by_name(&listings, "pizza").is_empty());
+}
+
+#[test]
+fn test_filter_by_name_ignores_case() {
+    let listings = named(&["Chipotle", "Chick-fil-A"]);
+    assert_eq!(
+        found(filter_by_name(&listings, "FIL-a")),
+        vec!["Chick-fil-A"]
+    );
+    assert_eq!(found(filter_by_name(&listings, " CHIP ")), vec!["Chipotle"]);
+}
+
+#[test]
+fn test_filter_by_name_empty_query() {
+    let listings = named(&["Chipotle", "Crossroads"]);
+    assert_eq!(filter_by_name(&listings, ""), listings);
+    assert_eq!(filter_by_name(&listings, "  "), listings);
+}
+
+fn week() -> Vec<Restaurant> {
+    let open = |name: &str, day: Day, start: &str, end: &str| {
+        RestaurantBuilder::new()
+            .name(name)
+            .open(day, start, end)
+            .build()
+            .unwrap()
+    };
+    let bistro = RestaurantBuilder::new()
+        .name("Bistro")
+        .hours(
+            Day::Monday,
+            Hours::new(Time::new(11, 0), Time::new(21, 0))
+                .with_break(TimeRange::new(Time::new(11, 30), Time::new(12, 30))),
+        )
+        .build()
+        .unwrap();
+    let crossroads = Day::all()
+        .fold(RestaurantBuilder::new().name("Crossroads"), |b, day| {
+            b.open(day, "0:00", "24:00")
+        })
+        .build()
+        .unwrap();
+    vec![
+        open("Supper Club", Day::Monday, "17:00", "23:00"),
+        crossroads,
+        open("Lunch Counter", Day::Monday, "11:00", "14:00"),
+        open("Weekend Brunch", Day::Saturday, "9:00", "14:00"),
+        bistro,
+        open("Cafe", Day::Monday, "7:00", "13:00"),
+        open("Breakfast Nook", Day::Monday, "6:00", "12:00"),
+        open("Noon Deli", Day::Monday, "12:00", "15:00"),
+    ]
+}
+
+fn section_names(availability: &Availability) -> Vec<(&'static str, Vec<String>)> {
+    availability
+        .sections()
+        .into_iter()
+        .map(|(heading, restaurants)| {
+            (
+                heading,
+                restaurants.into_iter().map(|r| r.name.clone()).collect(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_group_by_availability() {
+    let restaurants = week();
+    let availability = group_by_availability(&restaurants, Day::Monday, Time::new(12, 0));
+    assert_eq!(
+        section_names(&availability),
+        vec![
+            (
+                OPEN_NOW,
+                vec![
+                    "Cafe".to_string(),
+                    "Lunch Counter".into(),
+                    "Noon Deli".into(),
+                    "Crossroads".into(),
+                ]
+            ),
+            (OPENS_LATER, vec!["Bistro".into(), "Supper Club".into()]),
+            (
+                CLOSED_TODAY,
+                vec!["Breakfast Nook".into(), "Weekend Brunch".into()]
+            ),
+        ]
+    );
+    let opening_times = availability
+        .opens_later
+        .iter()
+        .map(|&(_, opens)| opens)
+        .collect::<Vec<_>>();
+    assert_eq!(opening_times, vec![Time::new(12, 30), Time::new(17, 0)]);
+}
+
+#[test]
+fn test_group_by_availability_at_transition() {
+    let restaurants = week();
+    let names = |time| {
+        let availability = group_by_availability(&restaurants, Day::Monday, time);
+        let later = availability
+            .opens_later
+            .iter()
+            .map(|&(r, _)| r.name.clone())
+            .collect::<Vec<_>>();
+        let closed = availability
+            .closed_today
+            .iter()
+            .map(|r| r.name.clone())
+            .collect::<Vec<_>>();
+        (later, closed)
+    };
+    // Just before noon, Noon Deli opens later and Breakfast Nook is still open.
+    let (later, closed) = names(Time::new(11, 59));
+    assert!(later.contains(&"Noon Deli".to_string()));
+    assert!(!closed.contains(&"Breakfast Nook".to_string()));
+    // At noon exactly, Noon Deli has opened and Breakfast Nook has closed.
+    let (later, closed) = names(Time::new(12, 0));
+    assert!(!later.contains(&"Noon Deli".to_string()));
+    assert!(closed.contains(&"Breakfast Nook".to_string()));
+}
+
+#[test]
+fn test_sections_skip_empty_groups() {
+    let restaurants = week();
+    let availability = group_by_availability(&restaurants, Day::Saturday, Time::new(15, 0));
+    let headings = availability
+        .sections()
+        .into_iter()
+        .map(|(heading, _)| heading)
+        .collect::<Vec<_>>();
+    assert_eq!(headings, vec![OPEN_NOW, CLOSED_TODAY]);
+}
@@ -0,0 +1,79 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{compare_by_distance, Coordinates, Restaurant, SortBy};
+use std::cmp::Ordering;
+
+const CAMPUS: Coordinates = Coordinates {
+    latitude: 35.2059,
+    longitude: -97.4457,
+};
+
+fn restaurant(name: &str, coordinates: Option<(f64, f64)>) -> Restaurant {
+    let mut json = json!({ "name": name, "hours": {} });
+    if let Some((latitude, longitude)) = coordinates {
+        json["coordinates"] = json!({ "latitude": latitude, "longitude": longitude });
+    }
+    serde_json::from_value(json).unwrap()
+}
+
+#[test]
+fn test_distance_km() {
+    let union = Coordinates::new(35.2101, -97.4446);
+    let distance = CAMPUS.distance_km(union);
+    assert!((distance - 0.477).abs() < 0.01, "{}", distance);
+    assert_eq!(CAMPUS.distance_km(CAMPUS), 0.0);
+    let r = restaurant("Union", Some((35.2101, -97.4446)));
+    assert_eq!(r.coordinates(), Some(union));
+    assert_eq!(r.distance_km(CAMPUS), Some(distance));
+    assert_eq!(restaurant("Nowhere", None).distance_km(CAMPUS), None);
+}
+
+#[test]
+fn test_compare_by_distance() {
+    let near = restaurant("Near", Some((35.2060, -97.4457)));
+    let far = restaurant("Far", Some((35.2200, -97.4457)));
+    let unknown = restaurant("Unknown", None);
+    assert_eq!(compare_by_distance(&near, &far, CAMPUS), Ordering::Less);
+    assert_eq!(compare_by_distance(&far, &near, CAMPUS), Ordering::Greater);
+    assert_eq!(compare_by_distance(&far, &unknown, CAMPUS), Ordering::Less);
+    assert_eq!(
+        compare_by_distance(&unknown, &near, CAMPUS),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_sort_mixed_coordinates() {
+    let restaurants = [
+        restaurant("Zebra Cafe", None),
+        restaurant("Far", Some((35.2200, -97.4457))),
+        restaurant("Aardvark Deli", None),
+        restaurant("Near", Some((35.2060, -97.4457))),
+    ];
+    let names = |sort_by: SortBy| {
+        let mut sorted = restaurants.iter().collect::<Vec<_>>();
+        sort_by.sort(&mut sorted);
+        sorted
+            .into_iter()
+            .map(|r| r.name.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(SortBy::Distance(CAMPUS)),
+        vec!["Near", "Far", "Aardvark Deli", "Zebra Cafe"]
+    );
+    assert_eq!(
+        names(SortBy::Name),
+        vec!["Aardvark Deli", "Far", "Near", "Zebra Cafe"]
+    );
+}
+
+#[test]
+fn test_sort_for_position() {
+    assert_eq!(SortBy::for_position(None), SortBy::Name);
+    assert_eq!(SortBy::for_position(Some(CAMPUS)), SortBy::Distance(CAMPUS));
+    assert_eq!(SortBy::default(), SortBy::Name);
+}
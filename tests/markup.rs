@@ -0,0 +1,63 @@
+extern crate eat_ou;
+
+use eat_ou::ui::{live_setting, CLEARED_ELEMENTS, STATE_ATTRIBUTES, TRANSITION_CLASS};
+
+const INDEX: &str = include_str!("../static/index.html");
+const STYLE: &str = include_str!("../static/css/style.css");
+
+/// Returns the opening tag of the element with the given ID in the page's markup.
+fn opening_tag(id: &str) -> &'static str {
+    let attribute = format!("id=\"{}\"", id);
+    let at = INDEX.find(&attribute).expect("element missing from markup");
+    let start = INDEX[..at].rfind('<').unwrap();
+    let end = at + INDEX[at..].find('>').unwrap();
+    &INDEX[start..=end]
+}
+
+/// Returns whether the element with the given ID is empty in the page's markup.
+fn is_empty(id: &str) -> bool {
+    let tag = opening_tag(id);
+    let at = INDEX.find(tag).unwrap() + tag.len();
+    INDEX[at..].starts_with("</")
+}
+
+#[test]
+fn test_live_setting() {
+    assert_eq!(live_setting(None), Some("polite"));
+    assert_eq!(live_setting(Some("assertive")), None);
+    assert_eq!(live_setting(Some("off")), None);
+}
+
+#[test]
+fn test_suggestion_is_live_region() {
+    for id in &["place", "times"] {
+        assert!(
+            opening_tag(id).contains("aria-live=\"polite\""),
+            "{} isn't a live region",
+            id
+        );
+    }
+}
+
+#[test]
+fn test_suggestion_is_focusable() {
+    // Headings only take focus from scripts with a tabindex.
+    assert!(opening_tag("place").contains("tabindex=\"-1\""));
+}
+
+#[test]
+fn test_transition_class_is_styled() {
+    // The class is only ever applied to the suggestion.
+    let selector = format!("#place.{} {{", TRANSITION_CLASS);
+    assert!(STYLE.contains(&selector), "{} isn't styled", selector);
+}
+
+#[test]
+fn test_clear_restores_initial_markup() {
+    for id in CLEARED_ELEMENTS.iter() {
+        assert!(is_empty(id), "{} isn't empty to begin with", id);
+    }
+    for &(id, name) in STATE_ATTRIBUTES.iter() {
+        assert!(!opening_tag(id).contains(&format!("data-{}", name)));
+    }
+}
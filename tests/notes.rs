@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::{Day, Listing, Restaurant, RestaurantBuilder};
+
+const LONG_NOTE: &str = "Cash only. The counter closes 30 minutes before the posted time, and \
+                         the entrance is on the east side of the building, past the loading dock.";
+
+#[test]
+fn test_round_trip() {
+    let restaurant: Restaurant = serde_json::from_value(json!({
+        "name": "Couch",
+        "hours": {},
+        "notes": LONG_NOTE
+    }))
+    .unwrap();
+    assert_eq!(restaurant.note(), Some(LONG_NOTE));
+    let written = serde_json::to_value(&restaurant).unwrap();
+    assert_eq!(written["note"], json!(LONG_NOTE));
+    let read: Restaurant = serde_json::from_value(written).unwrap();
+    assert_eq!(read.note(), Some(LONG_NOTE));
+}
+
+#[test]
+fn test_listing_carries_note() {
+    let noted = RestaurantBuilder::new()
+        .name("Couch")
+        .note(LONG_NOTE)
+        .build()
+        .unwrap();
+    let listing = Listing::new(&noted, "Closed today".into(), false);
+    assert_eq!(listing.note.as_deref(), Some(LONG_NOTE));
+    let plain = RestaurantBuilder::new().name("Crossroads").build().unwrap();
+    assert_eq!(
+        Listing::new(&plain, "Closed today".into(), false).note,
+        None
+    );
+}
+
+#[test]
+fn test_temporarily_closed_listing_omits_note() {
+    let closed = RestaurantBuilder::new()
+        .name("Couch")
+        .note("Closed for renovation")
+        .temporarily_closed()
+        .build()
+        .unwrap();
+    let listing = Listing::new(&closed, closed.describe_hours(Day::Monday), false);
+    assert_eq!(listing.hours, "Temporarily closed: Closed for renovation");
+    assert_eq!(listing.note, None);
+}
@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::{open_counts_by_hour, Day, Restaurant};
+
+fn restaurants() -> Vec<Restaurant> {
+    serde_json::from_value(json!([
+        { "name": "Crossroads", "hours": { "daily": { "start": "0:00", "end": "24:00" } } },
+        {
+            "name": "Pepe Delgado's",
+            "hours": {
+                "weekdays": {
+                    "start": "11:00",
+                    "end": "21:00",
+                    "breaks": [{ "start": "15:00", "end": "17:00" }]
+                }
+            }
+        },
+        { "name": "Couch", "hours": { "weekdays": { "start": "7:00", "end": "19:30" } } },
+        { "name": "Night Owl Diner", "hours": { "thursday": { "start": "21:00", "end": "26:00" } } }
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_open_counts_by_hour() {
+    let counts = open_counts_by_hour(&restaurants(), Day::Thursday);
+    assert_eq!(counts[6], 1);
+    assert_eq!(counts[7], 2);
+    assert_eq!(counts[12], 3);
+    // Pepe Delgado's is on its break.
+    assert_eq!(counts[16], 2);
+    assert_eq!(counts[19], 3);
+    assert_eq!(counts[21], 2);
+    assert_eq!(counts[23], 2);
+}
+
+#[test]
+fn test_late_hours_count_the_next_morning() {
+    let restaurants = restaurants();
+    assert_eq!(open_counts_by_hour(&restaurants, Day::Friday)[1], 2);
+    assert_eq!(open_counts_by_hour(&restaurants, Day::Thursday)[1], 1);
+    assert_eq!(
+        open_counts_by_hour(&restaurants, Day::Sunday)
+            .iter()
+            .sum::<u32>(),
+        24
+    );
+}
@@ -0,0 +1,109 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::{from_opening_hours, to_opening_hours, Day, Hours, Restaurant, Time, TimeRange};
+
+fn hours(start: (i32, i32), end: (i32, i32)) -> Hours {
+    Hours::new(Time::new(start.0, start.1), Time::new(end.0, end.1))
+}
+
+#[test]
+fn test_round_trips() {
+    for s in &[
+        "Mo-Th 11:00-21:00; Fr-Sa 11:00-23:00",
+        "Mo-Fr 07:30-14:00,17:00-21:00; Sa 10:00-14:00",
+        "Mo,We,Fr 10:00-18:00",
+        "Tu-Sa 17:00-02:00; Su 12:00-20:00",
+        "Mo-Su 06:00-24:00",
+        "24/7",
+    ] {
+        let map = from_opening_hours(s).unwrap();
+        assert_eq!(to_opening_hours(&map), *s);
+        assert_eq!(from_opening_hours(&to_opening_hours(&map)).unwrap(), map);
+    }
+}
+
+#[test]
+fn test_off_and_overrides() {
+    let map = from_opening_hours("Mo-Th 11:00-21:00; Fr-Sa 11:00-23:00; Su off").unwrap();
+    assert_eq!(map.get(Day::Thursday), Some(&hours((11, 0), (21, 0))));
+    assert_eq!(map.get(Day::Saturday), Some(&hours((11, 0), (23, 0))));
+    assert_eq!(map.get(Day::Sunday), None);
+    assert_eq!(
+        to_opening_hours(&map),
+        "Mo-Th 11:00-21:00; Fr-Sa 11:00-23:00"
+    );
+    let map = from_opening_hours("08:00-20:00; Tu off; Sa 10:00-14:00").unwrap();
+    assert_eq!(map.get(Day::Monday), Some(&hours((8, 0), (20, 0))));
+    assert_eq!(map.get(Day::Tuesday), None);
+    assert_eq!(map.get(Day::Saturday), Some(&hours((10, 0), (14, 0))));
+}
+
+#[test]
+fn test_breaks_and_overnight() {
+    let map = from_opening_hours("Fr 11:00-14:00,17:00-02:00").unwrap();
+    assert_eq!(
+        map.get(Day::Friday),
+        Some(
+            &hours((11, 0), (26, 0)).with_break(TimeRange::new(Time::new(14, 0), Time::new(17, 0)))
+        )
+    );
+}
+
+#[test]
+fn test_24_7_is_open_24_hours() {
+    let map = from_opening_hours("24/7").unwrap();
+    for day in Day::all() {
+        let all_day = map.get(day).unwrap();
+        assert_eq!(all_day, &hours((0, 0), (24, 0)));
+        assert_eq!(all_day.to_string(), "Open 24 hours");
+    }
+    let map = from_opening_hours("Mo-Su 00:00-24:00").unwrap();
+    assert_eq!(to_opening_hours(&map), "24/7");
+}
+
+#[test]
+fn test_unsupported_constructs() {
+    let reason = |s: &str| from_opening_hours(s).unwrap_err().reason;
+    assert_eq!(
+        reason("Mo-Fr 10:00-20:00; PH off"),
+        "public holidays aren't supported"
+    );
+    assert_eq!(
+        reason("Jan-Mar Mo-Fr 10:00-16:00"),
+        "months aren't supported"
+    );
+    assert_eq!(
+        reason("Mo-Fr 10:00+"),
+        "hours without a set closing time aren't supported"
+    );
+    assert_eq!(
+        reason("Mo[1] 10:00-12:00"),
+        "particular weeks of the month aren't supported"
+    );
+    let error = from_opening_hours("Mo-Fr 10:00-20:00; Sa 10-14").unwrap_err();
+    assert_eq!(error.fragment, "10-14");
+    assert_eq!(
+        from_opening_hours("Mo-Fy 10:00-20:00")
+            .unwrap_err()
+            .fragment,
+        "Fy"
+    );
+}
+
+#[test]
+fn test_restaurant_opening_hours() {
+    let restaurant: Restaurant = serde_json::from_value(json!({
+        "name": "Crossroads",
+        "hours": {
+            "weekdays": { "start": "07:00", "end": "20:00" },
+            "saturday": { "start": "10:00", "end": "14:00" }
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        restaurant.opening_hours(),
+        "Mo-Fr 07:00-20:00; Sa 10:00-14:00"
+    );
+}
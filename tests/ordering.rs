@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{Restaurant, SortBy};
+use std::cmp::Ordering;
+
+fn restaurants() -> Vec<Restaurant> {
+    // Loaded as a list, so that each Subway gets its own ID.
+    Restaurant::from_json_value(json!([
+        { "name": "Subway", "walk_minutes": 3, "hours": { "monday": { "start": "10:00", "end": "22:00" } } },
+        { "name": "Crossroads", "hours": { "daily": { "start": "7:00", "end": "26:00" } } },
+        { "name": "Subway", "walk_minutes": 9, "hours": { "sunday": { "start": "11:00", "end": "20:00" } } },
+        { "name": "Subway", "walk_minutes": 6, "hours": { "monday": { "start": "7:30", "end": "15:00" } } },
+        { "name": "Subway", "walk_minutes": 12, "hours": {} }
+    ]))
+    .unwrap()
+}
+
+fn walks(restaurants: &[&Restaurant]) -> Vec<Option<u64>> {
+    restaurants
+        .iter()
+        .map(|r| r.travel_time().map(|t| t.as_secs() / 60))
+        .collect()
+}
+
+#[test]
+fn test_ordering_breaks_name_ties_by_first_opening() {
+    let restaurants = restaurants();
+    let mut sorted = restaurants.iter().collect::<Vec<_>>();
+    SortBy::Name.sort(&mut sorted);
+    assert_eq!(sorted[0].name, "Crossroads");
+    assert_eq!(
+        walks(&sorted[1..]),
+        vec![Some(12), Some(9), Some(6), Some(3)]
+    );
+}
+
+#[test]
+fn test_ordering_is_stable_across_input_orders() {
+    let restaurants = restaurants();
+    let mut forward = restaurants.iter().collect::<Vec<_>>();
+    let mut backward = restaurants.iter().rev().collect::<Vec<_>>();
+    SortBy::Name.sort(&mut forward);
+    SortBy::Name.sort(&mut backward);
+    assert_eq!(walks(&forward), walks(&backward));
+}
+
+#[test]
+fn test_ordering_breaks_remaining_ties_by_id() {
+    let restaurants = Restaurant::from_json_value(json!([
+        { "name": "Subway", "id": "subway-union", "hours": {} },
+        { "name": "Subway", "id": "subway-campus-corner", "hours": {} }
+    ]))
+    .unwrap();
+    let (union, campus_corner) = (&restaurants[0], &restaurants[1]);
+    assert_eq!(SortBy::Name.compare(union, union), Ordering::Equal);
+    assert_eq!(
+        SortBy::Name.compare(union, campus_corner),
+        Ordering::Greater
+    );
+    assert_eq!(SortBy::Name.compare(campus_corner, union), Ordering::Less);
+}
@@ -0,0 +1,58 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+extern crate rand;
+
+use eat_ou::Restaurant;
+use rand::{Rng, SeedableRng, XorShiftRng};
+use std::collections::HashSet;
+
+fn restaurants(count: usize) -> Vec<Restaurant> {
+    let entries = (0..count)
+        .map(|i| json!({ "name": format!("Cart {}", i), "hours": {} }))
+        .collect::<Vec<_>>();
+    serde_json::from_value(json!(entries)).unwrap()
+}
+
+fn pick(restaurants: &[Restaurant], n: usize, seed: u32) -> Vec<String> {
+    let mut rng = XorShiftRng::from_seed([seed, 2, 3, 4]);
+    Restaurant::pick_random(restaurants, n, |n| rng.gen_range(0, n))
+        .into_iter()
+        .map(|r| r.name.clone())
+        .collect()
+}
+
+#[test]
+fn test_pick_is_unique_and_deterministic() {
+    let restaurants = restaurants(10);
+    for seed in 1..20 {
+        let picked = pick(&restaurants, 4, seed);
+        assert_eq!(picked.len(), 4);
+        assert_eq!(picked.iter().collect::<HashSet<_>>().len(), 4);
+        assert_eq!(picked, pick(&restaurants, 4, seed));
+    }
+}
+
+#[test]
+fn test_pick_more_than_available() {
+    let restaurants = restaurants(3);
+    let mut picked = pick(&restaurants, 5, 1);
+    picked.sort();
+    assert_eq!(picked, vec!["Cart 0", "Cart 1", "Cart 2"]);
+}
+
+#[test]
+fn test_pick_degenerate() {
+    assert!(pick(&restaurants(3), 0, 1).is_empty());
+    assert!(pick(&[], 3, 1).is_empty());
+    assert!(pick(&[], 0, 1).is_empty());
+}
+
+#[test]
+fn test_pick_covers_every_restaurant() {
+    let restaurants = restaurants(5);
+    let seen = (1..200)
+        .flat_map(|seed| pick(&restaurants, 1, seed))
+        .collect::<HashSet<_>>();
+    assert_eq!(seen.len(), 5);
+}
@@ -0,0 +1,84 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{validate, Price, Restaurant, RestaurantFilter, Severity};
+
+fn restaurants() -> Vec<Restaurant> {
+    serde_json::from_value(json!([
+        { "name": "Taco Stand", "price": 1, "hours": {} },
+        { "name": "Bistro", "price": "$$$", "hours": {} },
+        { "name": "Diner", "price": "$$", "hours": {} },
+        { "name": "Mystery Spot", "hours": {} }
+    ]))
+    .unwrap()
+}
+
+fn names(filter: &RestaurantFilter) -> Vec<String> {
+    filter
+        .apply(&restaurants())
+        .into_iter()
+        .map(|r| r.name.clone())
+        .collect()
+}
+
+#[test]
+fn test_price_encodings() {
+    let restaurants = restaurants();
+    assert_eq!(restaurants[0].price(), Price::new(1));
+    assert_eq!(restaurants[1].price(), Price::new(3));
+    assert_eq!(restaurants[2].price(), Price::new(2));
+    assert_eq!(restaurants[3].price(), None);
+}
+
+#[test]
+fn test_price_display_and_order() {
+    let prices = (1..=4).map(|l| Price::new(l).unwrap()).collect::<Vec<_>>();
+    assert_eq!(
+        prices.iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+        vec!["$", "$$", "$$$", "$$$$"]
+    );
+    assert!(prices.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(Price::new(0), None);
+    assert_eq!(Price::new(5), None);
+}
+
+#[test]
+fn test_malformed_price_rejected() {
+    for price in &[json!("cheap"), json!(""), json!(-1)] {
+        let result = serde_json::from_value::<Restaurant>(
+            json!({ "name": "Diner", "price": price, "hours": {} }),
+        );
+        assert!(result.is_err(), "{} was accepted", price);
+    }
+}
+
+#[test]
+fn test_out_of_range_price_fails_validation() {
+    let restaurants: Vec<Restaurant> = serde_json::from_value(json!([
+        { "name": "Caviar Bar", "price": "$$$$$", "hours": {} },
+        { "name": "Free Lunch", "price": 0, "hours": {} }
+    ]))
+    .unwrap();
+    let errors = validate(&restaurants)
+        .into_iter()
+        .filter(|i| i.severity == Severity::Error)
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].restaurant, "Caviar Bar");
+    assert!(errors[0].message.contains("price level 5 is out of range"));
+    assert_eq!(errors[1].restaurant, "Free Lunch");
+}
+
+#[test]
+fn test_max_price_filter() {
+    assert_eq!(
+        names(&RestaurantFilter::new().max_price(Price::new(2).unwrap())),
+        vec!["Taco Stand", "Diner", "Mystery Spot"]
+    );
+    assert_eq!(
+        names(&RestaurantFilter::new().max_price(Price::new(1).unwrap())),
+        vec!["Taco Stand", "Mystery Spot"]
+    );
+}
@@ -0,0 +1,47 @@
+extern crate eat_ou;
+
+use eat_ou::{query, Day, Time};
+
+#[test]
+fn test_query_get() {
+    assert_eq!(query::get("?weekend", "weekend"), Some(""));
+    assert_eq!(query::get("?day=friday&weekend", "day"), Some("friday"));
+    assert_eq!(query::get("day=friday", "day"), Some("friday"));
+    assert_eq!(query::get("?day=friday", "weekend"), None);
+    assert_eq!(query::get("", "weekend"), None);
+}
+
+#[test]
+fn test_day_and_time() {
+    assert_eq!(
+        query::day_and_time("?day=friday&time=23:30"),
+        Some((Day::Friday, Time::new(23, 30)))
+    );
+    assert_eq!(
+        query::day_and_time("?time=9%3A05&day=Mon"),
+        Some((Day::Monday, Time::new(9, 5)))
+    );
+    assert_eq!(
+        query::day_and_time("?day=3&time=12:00"),
+        Some((Day::Wednesday, Time::new(12, 0)))
+    );
+    assert_eq!(query::day_and_time("?day=friday"), None);
+    assert_eq!(query::day_and_time("?time=23:30"), None);
+    assert_eq!(query::day_and_time("?day=caturday&time=23:30"), None);
+    assert_eq!(query::day_and_time("?day=friday&time=25:75"), None);
+    assert_eq!(query::day_and_time(""), None);
+}
+
+#[test]
+fn test_query_get_list() {
+    assert_eq!(
+        query::get_list("?tags=vegan,halal", "tags"),
+        vec!["vegan", "halal"]
+    );
+    assert_eq!(
+        query::get_list("?tags=vegan,&weekend", "tags"),
+        vec!["vegan"]
+    );
+    assert!(query::get_list("?tags", "tags").is_empty());
+    assert!(query::get_list("?weekend", "tags").is_empty());
+}
@@ -3,9 +3,277 @@ extern crate serde_json;
 
 extern crate eat_ou;
 
-use eat_ou::Restaurant;
+use eat_ou::{soonest_opening, validate, Day, Restaurant, RestaurantFilter, Time};
 
 #[test]
 fn test_json() {
-    let _: Vec<Restaurant> = serde_json::from_str(include_str!("../food.json")).unwrap();
+    let restaurants: Vec<Restaurant> = serde_json::from_str(include_str!("../food.json")).unwrap();
+    assert_eq!(validate(&restaurants), Vec::new());
+}
+
+fn weekday_only() -> Restaurant {
+    serde_json::from_value(json!({
+        "name": "Baja Fresh",
+        "hours": {
+            "monday": { "start": "10:00", "end": "18:00" },
+            "friday": { "start": "10:00", "end": "17:00" }
+        }
+    }))
+    .unwrap()
+}
+
+fn weekend_only() -> Restaurant {
+    serde_json::from_value(json!({
+        "name": "Brunch Spot",
+        "hours": {
+            "saturday": { "start": "9:00", "end": "14:00" },
+            "sunday": { "start": "9:00", "end": "14:00" }
+        }
+    }))
+    .unwrap()
+}
+
+fn every_day() -> Restaurant {
+    serde_json::from_value(json!({
+        "name": "Crossroads",
+        "hours": {
+            "monday": { "start": "0:00", "end": "24:00" },
+            "sunday": { "start": "0:00", "end": "24:00" }
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_weekend_predicates() {
+    assert!(Day::Saturday.is_weekend());
+    assert!(Day::Sunday.is_weekend());
+    assert!(Day::Monday.is_weekday());
+    assert!(Day::Friday.is_weekday());
+    assert!(!Day::Friday.is_weekend());
+}
+
+#[test]
+fn test_is_open_on_weekend() {
+    assert!(!weekday_only().is_open_on_weekend());
+    assert!(weekend_only().is_open_on_weekend());
+    assert!(every_day().is_open_on_weekend());
+}
+
+#[test]
+fn test_weekend_filter() {
+    let restaurants = vec![weekday_only(), weekend_only(), every_day()];
+    let names = |filter: RestaurantFilter| {
+        filter
+            .apply(&restaurants)
+            .into_iter()
+            .map(|r| r.name.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(RestaurantFilter::new()).len(), 3);
+    assert_eq!(
+        names(RestaurantFilter::new().open_on_weekend()),
+        vec!["Brunch Spot", "Crossroads"]
+    );
+}
+
+#[test]
+fn test_day_addition_wraps() {
+    assert_eq!(Day::Friday + 4, Day::Tuesday);
+    assert_eq!(Day::Saturday + 1, Day::Sunday);
+    assert_eq!(Day::Monday + 0, Day::Monday);
+    assert_eq!(Day::Monday + 14, Day::Monday);
+}
+
+#[test]
+fn test_days_until_matches_addition() {
+    assert_eq!(Day::Friday.days_until(Day::Tuesday), 4);
+    assert_eq!(Day::Tuesday.days_until(Day::Tuesday), 0);
+    for from in Day::all() {
+        for to in Day::all() {
+            assert_eq!(from + from.days_until(to), to);
+        }
+    }
+}
+
+#[test]
+fn test_upcoming_week() {
+    let week = Day::Friday.upcoming().collect::<Vec<_>>();
+    assert_eq!(
+        week,
+        vec![
+            Day::Friday,
+            Day::Saturday,
+            Day::Sunday,
+            Day::Monday,
+            Day::Tuesday,
+            Day::Wednesday,
+            Day::Thursday,
+        ]
+    );
+}
+
+fn opens_at(name: &str, day: &str, start: &str) -> Restaurant {
+    let mut hours = serde_json::Map::new();
+    hours.insert(day.into(), json!({ "start": start, "end": "23:00" }));
+    serde_json::from_value(json!({ "name": name, "hours": hours })).unwrap()
+}
+
+#[test]
+fn test_next_opening() {
+    let r = opens_at("Joe's Diner", "monday", "9:00");
+    assert_eq!(
+        r.next_opening(Day::Monday, Time::new(8, 0)),
+        Some((Day::Monday, Time::new(9, 0)))
+    );
+    assert_eq!(
+        r.next_opening(Day::Friday, Time::new(12, 0)),
+        Some((Day::Monday, Time::new(9, 0)))
+    );
+    // Already open, so the restaurant is available right away.
+    assert_eq!(
+        r.next_opening(Day::Monday, Time::new(10, 0)),
+        Some((Day::Monday, Time::new(10, 0)))
+    );
+    // Already closed today, so the next opening is a week away.
+    assert_eq!(
+        r.next_opening(Day::Monday, Time::new(23, 30)),
+        Some((Day::Monday, Time::new(9, 0)))
+    );
+    let closed: Restaurant =
+        serde_json::from_value(json!({ "name": "Closed", "hours": {} })).unwrap();
+    assert_eq!(closed.next_opening(Day::Monday, Time::new(10, 0)), None);
+}
+
+#[test]
+fn test_next_opening_across_week_boundary() {
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Sooner Diner",
+        "hours": {
+            "monday": { "start": "11:00", "end": "21:00" },
+            "saturday": { "start": "18:00", "end": "26:00" }
+        }
+    }))
+    .unwrap();
+    // Saturday's late hours are still in progress early Sunday morning.
+    assert_eq!(
+        r.next_opening(Day::Sunday, Time::new(1, 0)),
+        Some((Day::Sunday, Time::new(1, 0)))
+    );
+    // Once they end, the next opening wraps around into the following week.
+    assert_eq!(
+        r.next_opening(Day::Sunday, Time::new(3, 0)),
+        Some((Day::Monday, Time::new(11, 0)))
+    );
+    assert_eq!(
+        r.next_opening(Day::Monday, Time::new(22, 0)),
+        Some((Day::Saturday, Time::new(18, 0)))
+    );
+}
+
+#[test]
+fn test_next_opening_after_break() {
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Pepe Delgado's",
+        "hours": {
+            "monday": {
+                "start": "11:00",
+                "end": "21:00",
+                "breaks": [{ "start": "14:00", "end": "17:00" }]
+            },
+            "tuesday": { "start": "11:00", "end": "21:00" }
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        r.next_opening(Day::Monday, Time::new(15, 0)),
+        Some((Day::Monday, Time::new(17, 0)))
+    );
+    assert_eq!(
+        soonest_opening(std::slice::from_ref(&r), Day::Monday, Time::new(15, 0))
+            .map(|(_, d, t)| (d, t)),
+        Some((Day::Monday, Time::new(17, 0)))
+    );
+    assert_eq!(
+        r.next_opening(Day::Monday, Time::new(21, 30)),
+        Some((Day::Tuesday, Time::new(11, 0)))
+    );
+}
+
+#[test]
+fn test_next_opening_all_day() {
+    let r = every_day();
+    assert_eq!(
+        r.next_opening(Day::Monday, Time::new(23, 59)),
+        Some((Day::Monday, Time::new(23, 59)))
+    );
+    // This fixture has no Tuesday hours, and Monday's end at midnight.
+    assert_eq!(
+        r.next_opening(Day::Tuesday, Time::new(0, 0)),
+        Some((Day::Sunday, Time::new(0, 0)))
+    );
+}
+
+#[test]
+fn test_soonest_opening() {
+    let restaurants = vec![
+        opens_at("Joe's Diner", "monday", "9:00"),
+        opens_at("Night Owl", "sunday", "20:00"),
+        opens_at("Early Bird", "monday", "6:30"),
+        opens_at("Brunch Spot", "sunday", "11:00"),
+    ];
+    let soonest = |day, time| {
+        soonest_opening(&restaurants, day, time).map(|(r, d, t)| (r.name.clone(), d, t))
+    };
+    assert_eq!(
+        soonest(Day::Sunday, Time::new(9, 0)),
+        Some(("Brunch Spot".into(), Day::Sunday, Time::new(11, 0)))
+    );
+    assert_eq!(
+        soonest(Day::Sunday, Time::new(12, 0)),
+        Some(("Night Owl".into(), Day::Sunday, Time::new(20, 0)))
+    );
+    assert_eq!(
+        soonest(Day::Sunday, Time::new(21, 0)),
+        Some(("Early Bird".into(), Day::Monday, Time::new(6, 30)))
+    );
+    assert_eq!(
+        soonest(Day::Monday, Time::new(7, 0)),
+        Some(("Joe's Diner".into(), Day::Monday, Time::new(9, 0)))
+    );
+    assert!(soonest_opening(&[], Day::Monday, Time::new(7, 0)).is_none());
+}
+
+#[test]
+fn test_note() {
+    let restaurant: Restaurant = serde_json::from_value(json!({
+        "name": "Couch Restaurants",
+        "hours": {},
+        "note": "Closed for renovation",
+        "_note": "reopens in the spring"
+    }))
+    .unwrap();
+    assert_eq!(restaurant.note(), Some("Closed for renovation"));
+    assert_eq!(weekday_only().note(), None);
+}
+
+#[test]
+fn test_day_note() {
+    let restaurant: Restaurant = serde_json::from_value(json!({
+        "name": "Crossroads",
+        "hours": {
+            "saturday": { "start": "11:00", "end": "22:00" },
+            "sunday": { "start": "11:00", "end": "20:00", "note": "Kitchen closes early" }
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        restaurant.day_note(Day::Sunday),
+        Some("Kitchen closes early")
+    );
+    assert_eq!(restaurant.day_note(Day::Saturday), None);
+    assert_eq!(restaurant.day_note(Day::Monday), None);
+    let written = Restaurant::to_json(&[restaurant]);
+    let read = Restaurant::from_json_str(&written).unwrap();
+    assert_eq!(read[0].day_note(Day::Sunday), Some("Kitchen closes early"));
 }
@@ -0,0 +1,84 @@
+extern crate eat_ou;
+
+use eat_ou::{Day, Restaurant};
+
+const DATA: &str = r#"{
+    "schedules": {
+        "union-food-court": {
+            "weekdays": { "start": "7:00", "end": "20:00" },
+            "saturday": { "start": "10:00", "end": "16:00" }
+        }
+    },
+    "restaurants": [
+        { "name": "Chick-fil-A", "hours": "union-food-court" },
+        {
+            "name": "Panda Express",
+            "hours": {
+                "extends": "union-food-court",
+                "friday": { "start": "7:00", "end": "15:00" },
+                "saturday": null,
+                "sunday": { "start": "12:00", "end": "18:00" }
+            }
+        }
+    ]
+}"#;
+
+const VERBOSE: &str = r#"[
+    {
+        "name": "Chick-fil-A",
+        "hours": {
+            "monday": { "start": "7:00", "end": "20:00" },
+            "tuesday": { "start": "7:00", "end": "20:00" },
+            "wednesday": { "start": "7:00", "end": "20:00" },
+            "thursday": { "start": "7:00", "end": "20:00" },
+            "friday": { "start": "7:00", "end": "20:00" },
+            "saturday": { "start": "10:00", "end": "16:00" }
+        }
+    },
+    {
+        "name": "Panda Express",
+        "hours": {
+            "sunday": { "start": "12:00", "end": "18:00" },
+            "monday": { "start": "7:00", "end": "20:00" },
+            "tuesday": { "start": "7:00", "end": "20:00" },
+            "wednesday": { "start": "7:00", "end": "20:00" },
+            "thursday": { "start": "7:00", "end": "20:00" },
+            "friday": { "start": "7:00", "end": "15:00" }
+        }
+    }
+]"#;
+
+#[test]
+fn test_schedule_references_resolve() {
+    let referenced = Restaurant::from_json_str(DATA).unwrap();
+    let verbose = Restaurant::from_json_str(VERBOSE).unwrap();
+    assert_eq!(referenced.len(), verbose.len());
+    for (a, b) in referenced.iter().zip(verbose.iter()) {
+        assert_eq!(a.name, b.name);
+        for day in Day::all() {
+            assert_eq!(a.get_hours(day), b.get_hours(day), "{} on {}", a.name, day);
+        }
+    }
+}
+
+#[test]
+fn test_dangling_schedule_reference() {
+    let error = Restaurant::from_json_str(
+        r#"{ "restaurants": [{ "name": "Qdoba", "hours": "union-food-cart" }] }"#,
+    )
+    .err()
+    .unwrap()
+    .to_string();
+    assert!(error.contains("Qdoba"), "{}", error);
+    assert!(
+        error.contains("unknown schedule \"union-food-cart\""),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_bare_array_still_accepted() {
+    let restaurants = Restaurant::from_json_str(include_str!("../food.json")).unwrap();
+    assert!(!restaurants.is_empty());
+}
@@ -0,0 +1,75 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::{search, Restaurant};
+
+fn restaurants() -> Vec<Restaurant> {
+    serde_json::from_value(json!([
+        { "name": "Raising Cane's", "hours": {} },
+        { "name": "Chick-fil-A", "hours": {} },
+        { "name": "Qdoba Mexican Eats", "hours": {} },
+        { "name": "Chipotle", "hours": {} },
+        { "name": "Couch Restaurants", "aliases": ["the Caf"], "hours": {} },
+        { "name": "Crossroads", "hours": {} },
+        { "name": "Pepe Delgado's", "hours": {} }
+    ]))
+    .unwrap()
+}
+
+fn found(query: &str) -> Vec<String> {
+    search(&restaurants(), query)
+        .into_iter()
+        .map(|r| r.name.clone())
+        .collect()
+}
+
+#[test]
+fn test_ignores_case_and_punctuation() {
+    assert_eq!(found("chick fil a"), vec!["Chick-fil-A"]);
+    assert_eq!(found("QDOBA"), vec!["Qdoba Mexican Eats"]);
+    assert_eq!(found("pepe delgados"), vec!["Pepe Delgado's"]);
+}
+
+#[test]
+fn test_prefix_before_substring() {
+    assert_eq!(
+        found("c"),
+        vec![
+            "Chick-fil-A",
+            "Chipotle",
+            "Couch Restaurants",
+            "Crossroads",
+            "Qdoba Mexican Eats",
+            "Raising Cane's",
+        ]
+    );
+    assert_eq!(
+        found("ch"),
+        vec!["Chick-fil-A", "Chipotle", "Couch Restaurants"]
+    );
+}
+
+#[test]
+fn test_substring_before_subsequence() {
+    assert_eq!(found("chp"), vec!["Chipotle"]);
+    assert_eq!(
+        found("co"),
+        vec!["Couch Restaurants", "Chipotle", "Crossroads"]
+    );
+    // "mexICAN EatS" has the letters of "canes" in order, but not together.
+    assert_eq!(found("canes"), vec!["Raising Cane's", "Qdoba Mexican Eats"]);
+}
+
+#[test]
+fn test_aliases() {
+    assert_eq!(found("caf"), vec!["Couch Restaurants"]);
+    assert_eq!(found("the caf"), vec!["Couch Restaurants"]);
+}
+
+#[test]
+fn test_empty_and_unmatched() {
+    assert_eq!(found("  ").len(), restaurants().len());
+    assert_eq!(found("")[0], "Chick-fil-A");
+    assert!(found("sonic").is_empty());
+}
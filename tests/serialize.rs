@@ -0,0 +1,117 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::{Day, Restaurant};
+
+/// Compares restaurants by everything they describe, not just their IDs.
+fn assert_same(a: &[Restaurant], b: &[Restaurant]) {
+    assert_eq!(a.len(), b.len());
+    for (a, b) in a.iter().zip(b) {
+        assert_eq!(a.name, b.name);
+        assert_eq!(a.id(), b.id());
+        for day in Day::all() {
+            assert_eq!(
+                a.get_hours(day),
+                b.get_hours(day),
+                "{} on {:?}",
+                a.name,
+                day
+            );
+        }
+        assert_eq!(
+            serde_json::to_value(a).unwrap(),
+            serde_json::to_value(b).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_round_trip_bundled_data() {
+    let list = Restaurant::get_list().unwrap();
+    let written = Restaurant::to_json(&list);
+    let read = Restaurant::from_json_str(&written).unwrap();
+    assert!(read == list);
+    assert_same(&list, &read);
+}
+
+#[test]
+fn test_schema() {
+    let list = Restaurant::from_json_value(json!({
+        "schedules": { "union": { "weekdays": { "start": "8:00", "end": "15:00" } } },
+        "restaurants": [
+            {
+                "name": "Chick-fil-A",
+                "hours": { "extends": "union", "friday": null }
+            },
+            {
+                "name": "Crossroads",
+                "hours": {
+                    "sunday": {
+                        "start": "11:00",
+                        "end": "26:30",
+                        "breaks": [{ "start": "14:00", "end": "16:00" }]
+                    }
+                },
+                "walk_minutes": 5,
+                "tags": ["late-night"],
+                "cuisine": "American",
+                "coordinates": { "latitude": 35.2, "longitude": -97.44 },
+                "dietary": ["Vegan", "kosher"],
+                "price": "$$",
+                "exceptions": [
+                    { "date": "2024-11-28" },
+                    {
+                        "from": "2024-12-24",
+                        "to": "2024-12-26",
+                        "hours": { "start": "12:00", "end": "14:00" }
+                    }
+                ],
+                "closed_ranges": [{ "from": "5-15", "to": "08-01" }]
+            }
+        ]
+    }))
+    .unwrap();
+    let value: serde_json::Value = serde_json::from_str(&Restaurant::to_json(&list)).unwrap();
+    assert_eq!(
+        value,
+        json!([
+            {
+                "name": "Chick-fil-A",
+                "id": "chick-fil-a",
+                "hours": {
+                    "monday": { "start": "8:00", "end": "15:00" },
+                    "tuesday": { "start": "8:00", "end": "15:00" },
+                    "wednesday": { "start": "8:00", "end": "15:00" },
+                    "thursday": { "start": "8:00", "end": "15:00" }
+                }
+            },
+            {
+                "name": "Crossroads",
+                "id": "crossroads",
+                "hours": {
+                    "sunday": {
+                        "start": "11:00",
+                        "end": "26:30",
+                        "breaks": [{ "start": "14:00", "end": "16:00" }]
+                    }
+                },
+                "walk_minutes": 5,
+                "tags": ["late-night"],
+                "cuisine": "American",
+                "coordinates": { "latitude": 35.2, "longitude": -97.44 },
+                "dietary": ["vegan", "kosher"],
+                "price": 2,
+                "exceptions": [
+                    { "date": "2024-11-28" },
+                    {
+                        "from": "2024-12-24",
+                        "to": "2024-12-26",
+                        "hours": { "start": "12:00", "end": "14:00" }
+                    }
+                ],
+                "closed_ranges": [{ "from": "05-15", "to": "08-01" }]
+            }
+        ])
+    );
+}
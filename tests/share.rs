@@ -0,0 +1,58 @@
+extern crate eat_ou;
+
+use eat_ou::app::share_message;
+use eat_ou::{Day, Hours, Time, TimeRange};
+
+fn hours(start: (i32, i32), end: (i32, i32)) -> Hours {
+    Hours::new(Time::new(start.0, start.1), Time::new(end.0, end.1))
+}
+
+fn message(hours: &Hours, time: Time) -> String {
+    share_message("Joe's Diner", hours, Day::Monday, time)
+}
+
+#[test]
+fn test_share_open() {
+    let dinner = hours((11, 0), (22, 0));
+    assert_eq!(
+        message(&dinner, Time::new(18, 0)),
+        "Let's eat at Joe's Diner (open until 10:00 PM)"
+    );
+    let late = hours((21, 0), (26, 0));
+    assert_eq!(
+        message(&late, Time::new(25, 0)),
+        "Let's eat at Joe's Diner (open until 2:00 AM)"
+    );
+    let always = hours((0, 0), (24, 0));
+    assert_eq!(
+        message(&always, Time::new(3, 0)),
+        "Let's eat at Joe's Diner (open 24 hours)"
+    );
+}
+
+#[test]
+fn test_share_open_before_break() {
+    let split =
+        hours((11, 0), (21, 0)).with_break(TimeRange::new(Time::new(14, 0), Time::new(17, 0)));
+    assert_eq!(
+        message(&split, Time::new(12, 0)),
+        "Let's eat at Joe's Diner (open until 2:00 PM)"
+    );
+    assert_eq!(
+        message(&split, Time::new(15, 0)),
+        "Let's eat at Joe's Diner (opens at 5:00 PM)"
+    );
+}
+
+#[test]
+fn test_share_closed() {
+    let dinner = hours((11, 0), (22, 0));
+    assert_eq!(
+        message(&dinner, Time::new(9, 0)),
+        "Let's eat at Joe's Diner (opens at 11:00 AM)"
+    );
+    assert_eq!(
+        message(&dinner, Time::new(22, 0)),
+        "Let's eat at Joe's Diner (closed for the rest of Monday)"
+    );
+}
@@ -0,0 +1,130 @@
+extern crate eat_ou;
+
+use eat_ou::sort::{by_closing_time, by_name, by_opening_time, by_remaining_open};
+use eat_ou::{Day, Restaurant, RestaurantBuilder, SortBy, Time};
+use std::cmp::Ordering;
+
+/// Builds a restaurant open only on the given day.
+fn open(name: &str, day: Day, start: &str, end: &str) -> Restaurant {
+    RestaurantBuilder::new()
+        .name(name)
+        .open(day, start, end)
+        .build()
+        .unwrap()
+}
+
+fn restaurants() -> Vec<Restaurant> {
+    let crossroads = Day::all()
+        .fold(RestaurantBuilder::new().name("Crossroads"), |b, day| {
+            b.open(day, "0:00", "24:00")
+        })
+        .build()
+        .unwrap();
+    vec![
+        open("Supper Club", Day::Monday, "17:00", "23:00"),
+        open("Lunch Counter", Day::Monday, "11:00", "14:00"),
+        open("Deli", Day::Monday, "11:00", "14:00"),
+        open("Weekend Brunch", Day::Saturday, "9:00", "14:00"),
+        crossroads,
+        open("Breakfast Nook", Day::Monday, "6:00", "10:00"),
+        RestaurantBuilder::new()
+            .name("Closed Diner")
+            .build()
+            .unwrap(),
+    ]
+}
+
+fn sorted<F>(compare: F) -> Vec<String>
+where
+    F: Fn(&Restaurant, &Restaurant) -> Ordering,
+{
+    let mut restaurants = restaurants();
+    restaurants.sort_by(compare);
+    restaurants.into_iter().map(|r| r.name).collect()
+}
+
+#[test]
+fn test_by_name() {
+    assert_eq!(
+        sorted(by_name()),
+        vec![
+            "Breakfast Nook",
+            "Closed Diner",
+            "Crossroads",
+            "Deli",
+            "Lunch Counter",
+            "Supper Club",
+            "Weekend Brunch",
+        ]
+    );
+}
+
+#[test]
+fn test_by_closing_time() {
+    let noon = Time::new(12, 0);
+    assert_eq!(
+        sorted(by_closing_time(Day::Monday, noon, true)),
+        vec![
+            "Deli",
+            "Lunch Counter",
+            "Supper Club",
+            "Crossroads",
+            "Breakfast Nook",
+            "Closed Diner",
+            "Weekend Brunch",
+        ]
+    );
+    assert_eq!(
+        sorted(by_closing_time(Day::Monday, noon, false)),
+        vec![
+            "Crossroads",
+            "Supper Club",
+            "Deli",
+            "Lunch Counter",
+            "Breakfast Nook",
+            "Closed Diner",
+            "Weekend Brunch",
+        ]
+    );
+}
+
+#[test]
+fn test_by_opening_time() {
+    assert_eq!(
+        sorted(by_opening_time(Day::Monday)),
+        vec![
+            "Crossroads",
+            "Breakfast Nook",
+            "Deli",
+            "Lunch Counter",
+            "Supper Club",
+            "Closed Diner",
+            "Weekend Brunch",
+        ]
+    );
+}
+
+#[test]
+fn test_by_remaining_open() {
+    assert_eq!(
+        sorted(by_remaining_open(Day::Monday, Time::new(12, 0))),
+        vec![
+            "Crossroads",
+            "Deli",
+            "Lunch Counter",
+            "Breakfast Nook",
+            "Closed Diner",
+            "Supper Club",
+            "Weekend Brunch",
+        ]
+    );
+}
+
+#[test]
+fn test_sort_by_selection() {
+    let restaurants = restaurants();
+    let mut refs = restaurants.iter().collect::<Vec<_>>();
+    SortBy::OpeningTime(Day::Monday).sort(&mut refs);
+    assert_eq!(refs[0].name, "Crossroads");
+    assert_eq!(refs[refs.len() - 1].name, "Weekend Brunch");
+}
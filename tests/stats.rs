@@ -0,0 +1,83 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::app::{AppState, Stats};
+use eat_ou::{Day, FixedClock, Restaurant, Time};
+
+fn session() -> AppState {
+    let mut app = AppState::new();
+    app.restaurants = serde_json::from_value(json!([
+        { "name": "Crossroads", "hours": { "daily": { "start": "0:00", "end": "24:00" } } },
+        { "name": "Couch", "hours": { "weekdays": { "start": "7:00", "end": "14:00" } } },
+        { "name": "Night Owl Diner", "hours": { "friday": { "start": "21:00", "end": "26:00" } } },
+        { "name": "Chick-fil-A", "hours": {} }
+    ]))
+    .unwrap();
+    // Favorites are kept by ID; the name alone doesn't count.
+    app.favorites = vec![
+        "couch".into(),
+        "night-owl-diner".into(),
+        "gone".into(),
+        "Crossroads".into(),
+    ];
+    app
+}
+
+#[test]
+fn test_stats() {
+    let app = session();
+    assert_eq!(
+        app.stats(&FixedClock::new(Day::Monday, Time::new(12, 0))),
+        Stats {
+            total: 4,
+            viable: 2,
+            favorites: 2
+        }
+    );
+    assert_eq!(
+        app.stats(&FixedClock::new(Day::Saturday, Time::new(1, 0))),
+        Stats {
+            total: 4,
+            viable: 2,
+            favorites: 2
+        }
+    );
+    assert_eq!(
+        app.stats(&FixedClock::new(Day::Sunday, Time::new(12, 0)))
+            .viable,
+        1
+    );
+    assert_eq!(
+        AppState::new().stats(&FixedClock::new(Day::Monday, Time::new(12, 0))),
+        Stats::default()
+    );
+}
+
+#[test]
+fn test_stats_display() {
+    let stats = Stats {
+        total: 42,
+        viable: 5,
+        favorites: 1,
+    };
+    assert_eq!(
+        stats.to_string(),
+        "42 places known, 5 open now, 1 favorite."
+    );
+    let stats = Stats {
+        favorites: 2,
+        ..stats
+    };
+    assert_eq!(
+        stats.to_string(),
+        "42 places known, 5 open now, 2 favorites."
+    );
+    assert_eq!(Stats::default().to_string(), "0 places known, 0 open now.");
+}
+
+#[test]
+fn test_count() {
+    assert_eq!(Restaurant::count(), Restaurant::get_list().unwrap().len());
+    assert!(Restaurant::count() > 0);
+}
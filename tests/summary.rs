@@ -0,0 +1,154 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{Date, Day, FixedClock, Restaurant, Time};
+
+fn mixed_week() -> Restaurant {
+    serde_json::from_value(json!({
+        "name": "Pepe Delgado's",
+        "hours": {
+            "weekdays": { "start": "11:00", "end": "21:00" },
+            "saturday": { "start": "11:00", "end": "23:00" }
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_weekly_hours() {
+    let r = mixed_week();
+    let days = r.weekly_hours().map(|(day, _)| day).collect::<Vec<_>>();
+    assert_eq!(
+        days,
+        vec![
+            Day::Monday,
+            Day::Tuesday,
+            Day::Wednesday,
+            Day::Thursday,
+            Day::Friday,
+            Day::Saturday,
+        ]
+    );
+    for (day, hours) in r.weekly_hours() {
+        assert_eq!(Some(hours.clone()), r.get_hours(day));
+    }
+    assert_eq!(r.days_open_count(), 6);
+}
+
+#[test]
+fn test_schedule_summary() {
+    assert_eq!(
+        mixed_week().schedule_summary(),
+        "Mon–Fri 11:00 AM–9:00 PM, Sat 11:00 AM–11:00 PM, closed Sun"
+    );
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Brunch Spot",
+        "hours": {
+            "tuesday": { "start": "9:00", "end": "14:00" },
+            "weekends": { "start": "9:00", "end": "14:00" }
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        r.schedule_summary(),
+        "closed Mon, Tue 9:00 AM–2:00 PM, closed Wed–Fri, Sat–Sun 9:00 AM–2:00 PM"
+    );
+    let closed: Restaurant =
+        serde_json::from_value(json!({ "name": "Closed", "hours": {} })).unwrap();
+    assert_eq!(closed.schedule_summary(), "closed Mon–Sun");
+    assert_eq!(closed.days_open_count(), 0);
+}
+
+#[test]
+fn test_open_days() {
+    let weekdays: Restaurant = serde_json::from_value(json!({
+        "name": "Union Market",
+        "hours": { "weekdays": { "start": "8:00", "end": "15:00" } }
+    }))
+    .unwrap();
+    assert_eq!(
+        weekdays.open_days(),
+        vec![
+            Day::Monday,
+            Day::Tuesday,
+            Day::Wednesday,
+            Day::Thursday,
+            Day::Friday,
+        ]
+    );
+    assert_eq!(
+        mixed_week().open_days().len(),
+        mixed_week().days_open_count()
+    );
+}
+
+#[test]
+fn test_days_open_count_includes_days_on_break() {
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Pepe Delgado's",
+        "hours": {
+            "monday": {
+                "start": "11:00",
+                "end": "21:00",
+                "breaks": [{ "start": "11:00", "end": "21:00" }]
+            },
+            "tuesday": { "start": "9:00", "end": "9:00" }
+        }
+    }))
+    .unwrap();
+    assert_eq!(r.days_open_count(), 2);
+}
+
+#[test]
+fn test_weekly_open_minutes() {
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Crossroads",
+        "hours": {
+            "monday": {
+                "start": "11:00",
+                "end": "21:00",
+                "breaks": [{ "start": "15:00", "end": "16:00" }]
+            },
+            "friday": { "start": "20:00", "end": "2:00" },
+            "saturday": { "start": "0:00", "end": "24:00" }
+        }
+    }))
+    .unwrap();
+    assert_eq!(r.weekly_open_minutes(), (9 + 6 + 24) * 60);
+    assert_eq!(mixed_week().weekly_open_minutes(), (5 * 10 + 12) * 60);
+    let closed: Restaurant =
+        serde_json::from_value(json!({ "name": "Closed", "hours": {} })).unwrap();
+    assert_eq!(closed.weekly_open_minutes(), 0);
+}
+
+#[test]
+fn test_describe_hours() {
+    let r = mixed_week();
+    assert_eq!(r.describe_hours(Day::Monday), "11:00 AM–9:00 PM");
+    assert_eq!(r.describe_hours(Day::Saturday), "11:00 AM–11:00 PM");
+    assert_eq!(
+        r.describe_hours(Day::Sunday),
+        "Closed (reopens Monday 11:00 AM)"
+    );
+    let never: Restaurant =
+        serde_json::from_value(json!({ "name": "Coming Soon", "hours": {} })).unwrap();
+    assert_eq!(never.describe_hours(Day::Monday), "Closed today");
+}
+
+#[test]
+fn test_describe_hours_today() {
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Pepe Delgado's",
+        "hours": { "weekdays": { "start": "11:00", "end": "21:00" } },
+        "exceptions": [{ "date": "2024-11-28" }]
+    }))
+    .unwrap();
+    let thursday = FixedClock::new(Day::Thursday, Time::new(12, 0));
+    assert_eq!(r.describe_hours_today(&thursday), "11:00 AM–9:00 PM");
+    assert_eq!(
+        r.describe_hours_today(&thursday.with_date(Date::new(2024, 11, 28))),
+        "Closed (reopens Friday 11:00 AM)"
+    );
+}
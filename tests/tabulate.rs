@@ -0,0 +1,101 @@
+extern crate eat_ou;
+
+use eat_ou::ui::{plan_rows, RowStep};
+
+#[test]
+fn test_plan_unchanged_rows() {
+    let rows = ["a", "b", "c"];
+    let plan = plan_rows(&rows, &rows);
+    assert_eq!(
+        plan,
+        vec![RowStep::Keep(0), RowStep::Keep(1), RowStep::Keep(2)]
+    );
+}
+
+#[test]
+fn test_plan_reorder() {
+    let plan = plan_rows(&["a", "b", "c"], &["c", "a", "b"]);
+    assert_eq!(
+        plan,
+        vec![RowStep::Move(2), RowStep::Keep(0), RowStep::Keep(1)]
+    );
+}
+
+#[test]
+fn test_plan_insertion() {
+    let plan = plan_rows(&["a", "c"], &["a", "b", "c", "d"]);
+    assert_eq!(
+        plan,
+        vec![
+            RowStep::Keep(0),
+            RowStep::Insert,
+            RowStep::Keep(1),
+            RowStep::Insert
+        ]
+    );
+}
+
+#[test]
+fn test_plan_removal() {
+    let plan = plan_rows(&["a", "b", "c"], &["a", "c"]);
+    assert_eq!(
+        plan,
+        vec![RowStep::Keep(0), RowStep::Keep(2), RowStep::Remove(1)]
+    );
+    let plan = plan_rows(&["a", "b", "c", "d"], &["a", "c", "d"]);
+    assert_eq!(
+        plan,
+        vec![
+            RowStep::Keep(0),
+            RowStep::Keep(2),
+            RowStep::Keep(3),
+            RowStep::Remove(1),
+        ]
+    );
+    let plan = plan_rows(&["a", "b", "c"], &["b", "c"]);
+    assert_eq!(
+        plan,
+        vec![RowStep::Keep(1), RowStep::Keep(2), RowStep::Remove(0)]
+    );
+    assert_eq!(
+        plan_rows(&["a", "b"], &[]),
+        vec![RowStep::Remove(0), RowStep::Remove(1)]
+    );
+}
+
+#[test]
+fn test_plan_removal_with_insertion() {
+    let plan = plan_rows(&["a", "x", "c"], &["a", "b", "c"]);
+    assert_eq!(
+        plan,
+        vec![
+            RowStep::Keep(0),
+            RowStep::Insert,
+            RowStep::Keep(2),
+            RowStep::Remove(1),
+        ]
+    );
+}
+
+#[test]
+fn test_plan_into_empty_list() {
+    assert_eq!(
+        plan_rows(&[], &["a", "b"]),
+        vec![RowStep::Insert, RowStep::Insert]
+    );
+}
+
+#[test]
+fn test_plan_repeated_keys() {
+    // Each existing row is reused at most once.
+    let plan = plan_rows(&["a", "a"], &["a", "b", "a", "a"]);
+    assert_eq!(
+        plan,
+        vec![
+            RowStep::Keep(0),
+            RowStep::Insert,
+            RowStep::Keep(1),
+            RowStep::Insert
+        ]
+    );
+}
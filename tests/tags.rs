@@ -0,0 +1,153 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{Day, Dietary, Price, Restaurant, RestaurantFilter, Time};
+
+fn restaurants() -> Vec<Restaurant> {
+    serde_json::from_value(json!([
+        { "name": "Veggie Grill", "tags": ["Vegan", "gluten-free"], "hours": {} },
+        { "name": "Halal Cart", "tags": ["halal"], "hours": {} },
+        { "name": "Mystery Diner", "hours": {} }
+    ]))
+    .unwrap()
+}
+
+fn names(filter: &RestaurantFilter) -> Vec<String> {
+    filter
+        .apply(&restaurants())
+        .into_iter()
+        .map(|r| r.name.clone())
+        .collect()
+}
+
+#[test]
+fn test_has_tag() {
+    let restaurants = restaurants();
+    assert!(restaurants[0].has_tag("vegan"));
+    assert!(restaurants[0].has_tag("GLUTEN-FREE"));
+    assert!(!restaurants[0].has_tag("halal"));
+    assert!(!restaurants[2].has_tag("vegan"));
+    assert!(restaurants[2].tags().is_empty());
+}
+
+#[test]
+fn test_tag_filter_matching() {
+    assert_eq!(
+        names(&RestaurantFilter::new().with_tags(vec!["vegan"])),
+        vec!["Veggie Grill"]
+    );
+    assert!(names(&RestaurantFilter::new().with_tags(vec!["kosher"])).is_empty());
+}
+
+#[test]
+fn test_tag_filter_requires_every_tag() {
+    assert_eq!(
+        names(&RestaurantFilter::new().with_tags(vec!["vegan", "gluten-free"])),
+        vec!["Veggie Grill"]
+    );
+    assert!(names(&RestaurantFilter::new().with_tags(vec!["vegan", "halal"])).is_empty());
+}
+
+#[test]
+fn test_tag_filter_untagged() {
+    assert_eq!(
+        names(
+            &RestaurantFilter::new()
+                .with_tags(vec!["halal"])
+                .include_untagged()
+        ),
+        vec!["Halal Cart", "Mystery Diner"]
+    );
+    assert_eq!(names(&RestaurantFilter::new()).len(), 3);
+}
+
+#[test]
+fn test_filter_by_tags_include() {
+    assert_eq!(
+        names(&RestaurantFilter::filter_by_tags(&["HALAL"], &[])),
+        vec!["Halal Cart"]
+    );
+    assert_eq!(
+        names(&RestaurantFilter::filter_by_tags(&[], &[])),
+        vec!["Veggie Grill", "Halal Cart", "Mystery Diner"]
+    );
+}
+
+#[test]
+fn test_filter_by_tags_exclude() {
+    assert_eq!(
+        names(&RestaurantFilter::filter_by_tags(&[], &["gluten-free"])),
+        vec!["Halal Cart", "Mystery Diner"]
+    );
+}
+
+#[test]
+fn test_filter_by_tags_include_and_exclude() {
+    assert_eq!(
+        names(&RestaurantFilter::filter_by_tags(&["vegan"], &["halal"])),
+        vec!["Veggie Grill"]
+    );
+    assert!(names(&RestaurantFilter::filter_by_tags(
+        &["vegan"],
+        &["Gluten-Free"]
+    ))
+    .is_empty());
+}
+
+#[test]
+fn test_composed_filters() {
+    let restaurants: Vec<Restaurant> = serde_json::from_value(json!([
+        {
+            "name": "Veggie Grill",
+            "tags": ["salads"],
+            "dietary": ["vegan"],
+            "price": 2,
+            "hours": { "monday": { "start": "11:00", "end": "21:00" } }
+        },
+        {
+            "name": "Vegan Brunch",
+            "tags": ["coffee"],
+            "dietary": ["vegan"],
+            "price": 1,
+            "hours": { "monday": { "start": "8:00", "end": "14:00" } }
+        },
+        {
+            "name": "Steakhouse",
+            "price": 4,
+            "hours": { "monday": { "start": "11:00", "end": "22:00" } }
+        },
+        {
+            "name": "Salad Bar",
+            "dietary": ["vegan"],
+            "price": 3,
+            "hours": { "monday": { "start": "11:00", "end": "15:00" } }
+        }
+    ]))
+    .unwrap();
+    let filter = RestaurantFilter::new()
+        .open_at(Day::Monday, Time::new(12, 0))
+        .dietary(Dietary::Vegan)
+        .without_tag("coffee")
+        .max_price(Price::new(2).unwrap());
+    let names = filter
+        .apply(&restaurants)
+        .into_iter()
+        .map(|r| r.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Veggie Grill"]);
+    assert_eq!(
+        restaurants.iter().filter(filter.predicate()).count(),
+        names.len()
+    );
+    let open_and_vegan = RestaurantFilter::new()
+        .open_at(Day::Monday, Time::new(12, 0))
+        .dietary(Dietary::Vegan)
+        .with_tag("salads");
+    assert_eq!(open_and_vegan.apply(&restaurants).len(), 1);
+    assert_eq!(
+        RestaurantFilter::new().apply(&restaurants).len(),
+        restaurants.len()
+    );
+}
@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::app::{get_viable, AppState, ListSource};
+use eat_ou::{
+    group_by_availability, Date, Day, FixedClock, Restaurant, RestaurantBuilder, RestaurantFilter,
+    Time, ViabilityOptions,
+};
+
+fn restaurants() -> Vec<Restaurant> {
+    serde_json::from_value(json!([
+        {
+            "name": "Crossroads",
+            "hours": { "daily": { "start": "0:00", "end": "24:00" } }
+        },
+        {
+            "name": "Couch Express",
+            "hours": { "daily": { "start": "0:00", "end": "24:00" } },
+            "temporarily_closed": true,
+            "note": "Closed for renovation"
+        }
+    ]))
+    .unwrap()
+}
+
+fn names(restaurants: &[Restaurant]) -> Vec<&str> {
+    restaurants.iter().map(|r| r.name.as_str()).collect()
+}
+
+#[test]
+fn test_never_viable() {
+    let restaurants = restaurants();
+    let closed = &restaurants[1];
+    assert!(closed.is_temporarily_closed());
+    assert!(!closed.is_open_at(Day::Monday, Time::new(12, 0)));
+    assert!(!closed.is_viable(Day::Monday, Time::new(12, 0)));
+    assert!(!closed.is_viable_on(Date::new(2024, 9, 2), Day::Monday, Time::new(12, 0)));
+    assert_eq!(closed.next_opening(Day::Monday, Time::new(12, 0)), None);
+    let clock = FixedClock::new(Day::Monday, Time::new(12, 0));
+    let viable = get_viable(
+        &restaurants,
+        &RestaurantFilter::new(),
+        ViabilityOptions::default(),
+        &clock,
+    );
+    assert_eq!(names(&viable), vec!["Crossroads"]);
+}
+
+#[test]
+fn test_listed_among_all_restaurants() {
+    let clock = FixedClock::new(Day::Monday, Time::new(12, 0));
+    let mut app = AppState::new();
+    app.restaurants = restaurants();
+    assert_eq!(names(&app.listed(&clock)), vec!["Crossroads"]);
+    app.list_source = ListSource::All;
+    assert_eq!(
+        names(&app.listed(&clock)),
+        vec!["Crossroads", "Couch Express"]
+    );
+    let listed = app.listed(&clock);
+    let availability = group_by_availability(&listed, Day::Monday, Time::new(12, 0));
+    let closed = availability
+        .closed_today
+        .iter()
+        .map(|r| r.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(closed, vec!["Couch Express"]);
+    assert_eq!(
+        listed[1].describe_hours(Day::Monday),
+        "Temporarily closed: Closed for renovation"
+    );
+}
+
+#[test]
+fn test_filter_excludes_temporarily_closed() {
+    let restaurants = restaurants();
+    assert_eq!(RestaurantFilter::new().apply(&restaurants).len(), 2);
+    let filter = RestaurantFilter::new().exclude_temporarily_closed();
+    let open = filter
+        .apply(&restaurants)
+        .into_iter()
+        .map(|r| r.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(open, vec!["Crossroads"]);
+    let open_now = RestaurantFilter::new().open_at(Day::Monday, Time::new(12, 0));
+    assert_eq!(open_now.apply(&restaurants).len(), 1);
+}
+
+#[test]
+fn test_builder_and_round_trip() {
+    let built = RestaurantBuilder::new()
+        .name("Couch Express")
+        .open(Day::Monday, "7:00", "20:00")
+        .temporarily_closed()
+        .build()
+        .unwrap();
+    assert!(built.is_temporarily_closed());
+    assert_eq!(built.describe_hours(Day::Monday), "Temporarily closed");
+    let written = Restaurant::to_json(&[built]);
+    assert!(written.contains("\"temporarily_closed\": true"));
+    assert!(Restaurant::from_json_str(&written).unwrap()[0].is_temporarily_closed());
+    assert!(!Restaurant::to_json(&restaurants()[..1]).contains("temporarily_closed"));
+}
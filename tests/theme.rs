@@ -0,0 +1,45 @@
+extern crate eat_ou;
+
+use eat_ou::ui::{theme_content, Content};
+use eat_ou::{MemoryStorage, Storage, Theme, THEME_KEY};
+
+const THEMES: [Theme; 3] = [Theme::Light, Theme::Dark, Theme::System];
+
+#[test]
+fn test_theme_round_trip() {
+    for &theme in &THEMES {
+        assert_eq!(theme.as_str().parse(), Ok(theme));
+        assert_eq!(theme.to_string().parse(), Ok(theme));
+    }
+    assert_eq!("Dark".parse(), Ok(Theme::Dark));
+    assert!("sepia".parse::<Theme>().is_err());
+}
+
+#[test]
+fn test_theme_cycle() {
+    for &theme in &THEMES {
+        assert_ne!(theme.next(), theme);
+        assert_eq!(theme.next().next().next(), theme);
+    }
+}
+
+#[test]
+fn test_theme_persistence() {
+    let storage = MemoryStorage::new();
+    assert_eq!(Theme::load(&storage), Theme::System);
+    Theme::Dark.save(&storage);
+    assert_eq!(storage.get("eat-ou:theme"), Some("dark".into()));
+    assert_eq!(Theme::load(&storage), Theme::Dark);
+    storage.set(THEME_KEY, "sepia");
+    assert_eq!(Theme::load(&storage), Theme::System);
+}
+
+#[test]
+fn test_theme_content_labels() {
+    for &theme in &THEMES {
+        match theme_content(theme) {
+            Content::Glyph { alt, .. } => assert!(!alt.is_empty()),
+            Content::Text(_) => panic!("{:?}: expected a glyph", theme),
+        }
+    }
+}
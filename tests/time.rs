@@ -0,0 +1,194 @@
+extern crate eat_ou;
+
+use eat_ou::{format_iso8601_minutes, FromStrError, Time, TimeRange};
+use std::time::Duration;
+
+#[test]
+fn test_add_wraps_past_48_hours() {
+    assert_eq!(Time::new(47, 30) + 200, Time::new(2, 50));
+    assert_eq!(Time::new(47, 59) + 255, Time::new(4, 14));
+    assert_eq!(Time::new(23, 50) + 20, Time::new(24, 10));
+}
+
+#[test]
+fn test_add_rolls_over_at_exactly_60_minutes() {
+    assert_eq!(Time::new(9, 55) + 5, Time::new(10, 0));
+    assert_eq!(Time::new(9, 0) + 60, Time::new(10, 0));
+    assert_eq!(
+        Time::new(9, 55) + Duration::from_secs(5 * 60),
+        Time::new(10, 0)
+    );
+    assert_eq!((Time::new(9, 55) + 5).to_string(), "10:00 AM");
+}
+
+#[test]
+fn test_sub_minutes() {
+    assert_eq!(Time::new(14, 0) - 15, Time::new(13, 45));
+    assert_eq!(Time::new(25, 10) - 20, Time::new(24, 50));
+}
+
+#[test]
+fn test_sub_minutes_wraps_past_midnight() {
+    assert_eq!(Time::new(0, 5) - 10, Time::new(47, 55));
+    assert_eq!(Time::new(0, 0) - 255, Time::new(43, 45));
+}
+
+#[test]
+fn test_add_then_sub_minutes_round_trips() {
+    for &time in &[
+        Time::new(0, 0),
+        Time::new(9, 30),
+        Time::new(23, 55),
+        Time::new(47, 55),
+    ] {
+        for &minutes in &[0, 10, 59, 255] {
+            assert_eq!((time + minutes) - minutes, time);
+        }
+    }
+}
+
+#[test]
+fn test_sub_times() {
+    assert_eq!(Time::new(14, 0) - Time::new(13, 45), 15);
+    assert_eq!(Time::new(25, 10) - Time::new(23, 20), 110);
+    assert_eq!(Time::new(0, 5) - Time::new(47, 55), 10);
+}
+
+#[test]
+fn test_sub_zero_minutes() {
+    assert_eq!(Time::new(9, 30) - 0, Time::new(9, 30));
+    assert_eq!(Time::new(0, 0) - 0, Time::new(0, 0));
+}
+
+#[test]
+fn test_range_contains_same_day() {
+    let lunch = TimeRange::new(Time::new(11, 0), Time::new(14, 0));
+    assert!(!lunch.contains(Time::new(10, 59)));
+    assert!(lunch.contains(Time::new(11, 0)));
+    assert!(lunch.contains(Time::new(13, 59)));
+    assert!(!lunch.contains(Time::new(14, 0)));
+}
+
+#[test]
+fn test_range_contains_overnight() {
+    for late in &[
+        TimeRange::new(Time::new(21, 0), Time::new(26, 0)),
+        TimeRange::new(Time::new(21, 0), Time::new(2, 0)),
+    ] {
+        assert!(!late.contains(Time::new(20, 59)));
+        assert!(late.contains(Time::new(21, 0)));
+        assert!(late.contains(Time::new(23, 30)));
+        assert!(late.contains(Time::new(25, 59)));
+        assert!(!late.contains(Time::new(26, 0)));
+        // Early-morning times are relative to the day the range begins.
+        assert!(!late.contains(Time::new(1, 0)));
+    }
+}
+
+#[test]
+fn test_range_contains_all_day() {
+    let all_day = TimeRange::new(Time::new(0, 0), Time::new(24, 0));
+    assert!(all_day.contains(Time::new(0, 0)));
+    assert!(all_day.contains(Time::new(23, 59)));
+    assert!(!all_day.contains(Time::new(24, 0)));
+    let around_the_clock = TimeRange::new(Time::new(11, 0), Time::new(11, 0));
+    assert!(around_the_clock.contains(Time::new(11, 0)));
+    assert!(around_the_clock.contains(Time::new(34, 59)));
+    assert!(!around_the_clock.contains(Time::new(35, 0)));
+}
+
+#[test]
+fn test_parse_validates_range() {
+    assert_eq!("9:5".parse::<Time>().unwrap(), Time::new(9, 5));
+    assert_eq!("47:59".parse::<Time>().unwrap(), Time::new(47, 59));
+    match "9:75".parse::<Time>() {
+        Err(FromStrError::MinutesOutOfRange) => {}
+        other => panic!("expected minutes out of range, got {:?}", other),
+    }
+    match "48:00".parse::<Time>() {
+        Err(FromStrError::HoursOutOfRange) => {}
+        other => panic!("expected hours out of range, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_compact() {
+    assert_eq!("0930".parse::<Time>().unwrap(), Time::new(9, 30));
+    assert_eq!("2145".parse::<Time>().unwrap(), Time::new(21, 45));
+    assert_eq!("930".parse::<Time>().unwrap(), Time::new(9, 30));
+    assert_eq!("2600".parse::<Time>().unwrap(), Time::new(26, 0));
+    match "0975".parse::<Time>() {
+        Err(FromStrError::MinutesOutOfRange) => {}
+        other => panic!("expected minutes out of range, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_malformed_compact() {
+    for s in &["99", "12345", "0"] {
+        match s.parse::<Time>() {
+            Err(FromStrError::MalformedCompact) => {}
+            other => panic!(
+                "expected a malformed compact time for {:?}, got {:?}",
+                s, other
+            ),
+        }
+    }
+    match "noon".parse::<Time>() {
+        Err(FromStrError::MissingColon) => {}
+        other => panic!("expected a missing colon, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_try_new_validates_range() {
+    assert_eq!(Time::try_new(9, 59), Some(Time::new(9, 59)));
+    assert_eq!(Time::try_new(9, 75), None);
+    assert_eq!(Time::try_new(48, 0), None);
+    assert_eq!(Time::try_new(-1, 0), None);
+}
+
+#[test]
+fn test_add_duration() {
+    assert_eq!(
+        Time::new(9, 50) + Duration::from_secs(15 * 60),
+        Time::new(10, 5)
+    );
+    assert_eq!(Time::new(9, 50) + Duration::from_secs(59), Time::new(9, 50));
+    assert_eq!(
+        Time::new(47, 30) + Duration::from_secs(60 * 60),
+        Time::new(0, 30)
+    );
+}
+
+#[test]
+fn test_round_to_down() {
+    assert_eq!(Time::new(9, 10).round_to(30), Time::new(9, 0));
+    assert_eq!(Time::new(9, 7).round_to(15), Time::new(9, 0));
+}
+
+#[test]
+fn test_round_to_up_with_carry() {
+    assert_eq!(Time::new(9, 50).round_to(30), Time::new(10, 0));
+    assert_eq!(Time::new(9, 45).round_to(30), Time::new(10, 0));
+    assert_eq!(Time::new(23, 55).round_to(10), Time::new(24, 0));
+    assert_eq!(Time::new(47, 50).round_to(30), Time::new(0, 0));
+}
+
+#[test]
+fn test_round_to_even_divisor() {
+    assert_eq!(Time::new(12, 30).round_to(30), Time::new(12, 30));
+    assert_eq!(Time::new(12, 0).round_to(60), Time::new(12, 0));
+    assert_eq!(Time::new(12, 29).round_to(1), Time::new(12, 29));
+    assert_eq!(Time::new(12, 29).round_to(0), Time::new(12, 29));
+}
+
+#[test]
+fn test_format_iso8601_minutes() {
+    assert_eq!(format_iso8601_minutes(0), "PT0M");
+    assert_eq!(format_iso8601_minutes(45), "PT45M");
+    assert_eq!(format_iso8601_minutes(90), "PT1H30M");
+    assert_eq!(format_iso8601_minutes(120), "PT2H");
+    assert_eq!(format_iso8601_minutes(1440), "PT24H");
+    assert_eq!(format_iso8601_minutes(-45), "-PT45M");
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "toml-data")]
+
+extern crate eat_ou;
+extern crate serde_json;
+extern crate toml;
+
+use eat_ou::{DataError, Date, Day, Hours, Restaurant, Time, TimeRange};
+
+#[test]
+fn test_matches_json() {
+    let from_toml = Restaurant::from_toml_str(include_str!("food.toml")).unwrap();
+    let mut from_json = Restaurant::from_json_str(include_str!("../food.json")).unwrap();
+    from_json.truncate(from_toml.len());
+    assert_eq!(from_toml.len(), 4);
+    assert!(from_toml == from_json);
+    assert_eq!(
+        serde_json::to_value(&from_toml).unwrap(),
+        serde_json::to_value(&from_json).unwrap()
+    );
+}
+
+#[test]
+fn test_time_and_hours_deserialize_from_toml() {
+    let overnight: Hours = toml::from_str("start = \"21:00\"\nend = \"26:30\"").unwrap();
+    assert_eq!(overnight, Hours::new(Time::new(21, 0), Time::new(26, 30)));
+    let hours: Hours = toml::from_str(
+        r#"
+        start = "11:00"
+        end = "2:00"
+        breaks = [{ start = "15:00", end = "16:00" }]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        hours,
+        Hours::new(Time::new(11, 0), Time::new(2, 0))
+            .with_break(TimeRange::new(Time::new(15, 0), Time::new(16, 0)))
+    );
+    let error = toml::from_str::<Hours>("start = \"11:00\"\nend = \"5 PM\"").unwrap_err();
+    assert!(
+        error.to_string().contains("invalid time \"5 PM\""),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_schedules_and_dates() {
+    let restaurants = Restaurant::from_toml_str(
+        r#"
+        version = 2
+        updated = 2024-09-01
+
+        [schedules.union]
+        weekdays = { start = "8:00", end = "15:00" }
+
+        [[restaurants]]
+        name = "Chick-fil-A"
+        hours = "union"
+        exceptions = [{ date = 2024-11-28 }]
+        "#,
+    )
+    .unwrap();
+    let r = &restaurants[0];
+    assert!(r.is_open_at(Day::Monday, Time::new(12, 0)));
+    assert!(!r.is_open_on(Date::new(2024, 11, 28), Day::Thursday, Time::new(12, 0)));
+}
+
+#[test]
+fn test_errors() {
+    match Restaurant::from_toml_str("[[restaurants]]\nname = \"Crossroads\"\nhours = [") {
+        Err(DataError::Parse(_)) => {}
+        _ => panic!("expected a parse error"),
+    }
+    match Restaurant::from_toml_str("version = 3\n[[restaurants]]\nname = \"Crossroads\"") {
+        Err(DataError::UnsupportedVersion(3)) => {}
+        _ => panic!("expected an unsupported version"),
+    }
+}
@@ -0,0 +1,44 @@
+extern crate eat_ou;
+
+use eat_ou::ui::UiError;
+use std::error::Error;
+
+#[test]
+fn test_missing_element() {
+    let error = UiError::MissingElement("listings");
+    match error {
+        UiError::MissingElement(id) => assert_eq!(id, "listings"),
+        _ => panic!("expected a missing element"),
+    }
+    assert_eq!(
+        error.to_string(),
+        "Failed to fetch element with ID \"listings\""
+    );
+}
+
+#[test]
+fn test_js_error() {
+    let error = UiError::Js("String contains an invalid character".into());
+    match error {
+        UiError::Js(ref message) => assert!(message.contains("invalid character")),
+        _ => panic!("expected a JavaScript error"),
+    }
+    assert_eq!(
+        error.to_string(),
+        "DOM operation failed: String contains an invalid character"
+    );
+    let _: &dyn Error = &error;
+}
+
+#[test]
+fn test_missing_elements() {
+    let error = UiError::MissingElements(vec!["list", "listings"]);
+    match error {
+        UiError::MissingElements(ref ids) => assert_eq!(ids, &["list", "listings"]),
+        _ => panic!("expected missing elements"),
+    }
+    assert_eq!(
+        error.to_string(),
+        "Missing required elements with IDs \"list\", \"listings\""
+    );
+}
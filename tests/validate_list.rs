@@ -0,0 +1,73 @@
+extern crate eat_ou;
+
+use eat_ou::Restaurant;
+
+#[test]
+fn test_validate_list_accepts_valid_entries() {
+    let restaurants = Restaurant::validate_list(
+        r#"[
+            { "name": "Crossroads", "hours": { "daily": { "start": "7:00", "end": "26:00" } } },
+            { "name": "Baja Fresh", "hours": {} }
+        ]"#,
+    )
+    .ok()
+    .unwrap();
+    assert_eq!(restaurants.len(), 2);
+}
+
+#[test]
+fn test_validate_list_collects_every_error() {
+    let errors = Restaurant::validate_list(
+        r#"{
+            "schedules": { "union": { "weekdays": { "start": "8:00", "end": "15:00" } } },
+            "restaurants": [
+                { "name": "Crossroads", "hours": "union" },
+                { "name": "Raising Cane's", "hours": { "tuesday": { "start": "1O:30", "end": "24:00" } } },
+                { "hours": {} },
+                { "name": "Baja Fresh", "hours": {} },
+                { "name": "Qdoba", "hours": "union-food-cart" }
+            ]
+        }"#,
+    )
+    .err()
+    .unwrap();
+    assert_eq!(
+        errors.iter().map(|&(index, _)| index).collect::<Vec<_>>(),
+        vec![1, 2, 4]
+    );
+    assert!(
+        errors[0]
+            .1
+            .starts_with("Raising Cane's → tuesday → start: invalid time \"1O:30\""),
+        "{}",
+        errors[0].1
+    );
+    assert!(
+        errors[1].1.contains("missing field `name`"),
+        "{}",
+        errors[1].1
+    );
+    assert!(
+        errors[2]
+            .1
+            .contains("Qdoba: unknown schedule \"union-food-cart\""),
+        "{}",
+        errors[2].1
+    );
+}
+
+#[test]
+fn test_validate_list_document_errors() {
+    let errors = Restaurant::validate_list("{ \"restaurants\": [")
+        .err()
+        .unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 0);
+    let errors = Restaurant::validate_list("{ \"name\": \"Crossroads\" }")
+        .err()
+        .unwrap();
+    assert_eq!(
+        errors,
+        vec![(0, "expected a \"restaurants\" array".to_string())]
+    );
+}
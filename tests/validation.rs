@@ -0,0 +1,89 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{validate, Day, Restaurant, Severity, ValidationIssue};
+
+fn restaurants() -> Vec<Restaurant> {
+    serde_json::from_value(json!([
+        {
+            "name": "Crossroads",
+            "hours": { "daily": { "start": "0:00", "end": "24:00" } }
+        },
+        {
+            "name": "Crossroads",
+            "hours": { "monday": { "start": "9:00", "end": "17:00" } }
+        },
+        {
+            "name": "Night Owl Diner",
+            "hours": {
+                "friday": { "start": "21:00", "end": "2:00" },
+                "saturday": { "start": "9:00", "end": "9:00" },
+                "sunday": {
+                    "start": "11:00",
+                    "end": "21:00",
+                    "breaks": [{ "start": "22:00", "end": "23:00" }]
+                }
+            }
+        },
+        { "name": "Coming Soon", "hours": {} }
+    ]))
+    .unwrap()
+}
+
+fn issue(
+    severity: Severity,
+    restaurant: &str,
+    day: Option<Day>,
+) -> (Severity, String, Option<Day>) {
+    (severity, restaurant.into(), day)
+}
+
+#[test]
+fn test_validate() {
+    let issues = validate(&restaurants());
+    assert_eq!(
+        issues
+            .iter()
+            .map(|i| (i.severity, i.restaurant.clone(), i.day))
+            .collect::<Vec<_>>(),
+        vec![
+            issue(Severity::Error, "Crossroads", None),
+            issue(Severity::Warning, "Night Owl Diner", Some(Day::Sunday)),
+            issue(Severity::Warning, "Night Owl Diner", Some(Day::Friday)),
+            issue(Severity::Warning, "Night Owl Diner", Some(Day::Saturday)),
+            issue(Severity::Warning, "Coming Soon", None),
+        ]
+    );
+}
+
+#[test]
+fn test_validation_issue_display() {
+    let issue = ValidationIssue {
+        severity: Severity::Warning,
+        restaurant: "Night Owl Diner".into(),
+        day: Some(Day::Friday),
+        message: "hours end (2:00 AM) before they start (9:00 PM)".into(),
+    };
+    assert_eq!(
+        issue.to_string(),
+        "Warning: Night Owl Diner → friday: hours end (2:00 AM) before they start (9:00 PM)"
+    );
+}
+
+#[test]
+fn test_valid_data_has_no_issues() {
+    assert!(validate(&restaurants()[..1]).is_empty());
+}
+
+#[test]
+fn test_bundled_data_has_no_errors() {
+    // The build script rejects data that doesn't parse; this covers the rest of the checks.
+    let errors = validate(&Restaurant::get_list().unwrap())
+        .into_iter()
+        .filter(|i| i.severity == Severity::Error)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>();
+    assert!(errors.is_empty(), "{:#?}", errors);
+}
@@ -0,0 +1,33 @@
+extern crate eat_ou;
+
+use eat_ou::ui::{verify_elements, UiError, CLEARED_ELEMENTS, REQUIRED_ELEMENTS, STATE_ATTRIBUTES};
+
+#[test]
+fn test_verify_complete_dom() {
+    assert_eq!(verify_elements(|_| true), Ok(()));
+}
+
+#[test]
+fn test_verify_names_missing_element() {
+    let error = verify_elements(|id| id != "listings").unwrap_err();
+    assert_eq!(error, UiError::MissingElements(vec!["listings"]));
+    assert!(error.to_string().contains("\"listings\""));
+}
+
+#[test]
+fn test_verify_names_every_missing_element() {
+    match verify_elements(|_| false) {
+        Err(UiError::MissingElements(ids)) => assert_eq!(ids, REQUIRED_ELEMENTS.to_vec()),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_cleared_elements_are_required() {
+    for id in CLEARED_ELEMENTS.iter() {
+        assert!(REQUIRED_ELEMENTS.contains(id), "{} isn't verified", id);
+    }
+    for &(id, _) in STATE_ATTRIBUTES.iter() {
+        assert!(REQUIRED_ELEMENTS.contains(&id), "{} isn't verified", id);
+    }
+}
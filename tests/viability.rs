@@ -0,0 +1,332 @@
+#[macro_use]
+extern crate serde_json;
+
+extern crate eat_ou;
+
+use eat_ou::{
+    get_viable_at, viable_now, Day, FixedClock, Restaurant, Time, Viability, ViabilityOptions,
+    CLOSING_SOON_THRESHOLD,
+};
+use std::time::Duration;
+
+fn lunch_spot() -> Restaurant {
+    serde_json::from_value(json!({
+        "name": "Noodles & Company",
+        "hours": {
+            "monday": { "start": "11:00", "end": "14:00" }
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_is_open_at_opening_minute() {
+    let r = lunch_spot();
+    assert!(!r.is_open_at(Day::Monday, Time::new(10, 59)));
+    assert!(r.is_open_at(Day::Monday, Time::new(11, 0)));
+    assert!(r.is_open_at(Day::Monday, Time::new(13, 59)));
+    assert!(!r.is_open_at(Day::Monday, Time::new(14, 0)));
+    assert!(!r.is_open_at(Day::Tuesday, Time::new(12, 0)));
+}
+
+#[test]
+fn test_is_viable_allows_travel_time() {
+    let r = lunch_spot();
+    assert!(!r.is_viable(Day::Monday, Time::new(10, 49)));
+    assert!(r.is_viable(Day::Monday, Time::new(10, 50)));
+    assert!(r.is_viable(Day::Monday, Time::new(13, 49)));
+    assert!(!r.is_viable(Day::Monday, Time::new(13, 50)));
+}
+
+#[test]
+fn test_is_open_at_differs_from_has_hours() {
+    let r = lunch_spot();
+    assert!(r.has_hours(Day::Monday));
+    assert!(!r.has_hours(Day::Tuesday));
+    assert!(!r.is_open_at(Day::Monday, Time::new(3, 0)));
+    assert!(!r.is_open_at(Day::Monday, Time::new(14, 30)));
+    assert!(r.is_open_at(Day::Monday, Time::new(12, 0)));
+}
+
+#[test]
+fn test_is_viable_with_travel_time() {
+    let r = lunch_spot();
+    let closing = Time::new(13, 55);
+    assert!(r.is_viable_with(Day::Monday, closing, Duration::from_secs(0)));
+    assert!(!r.is_viable_with(Day::Monday, closing, Duration::from_secs(10 * 60)));
+    assert!(!r.is_viable(Day::Monday, closing));
+}
+
+#[test]
+fn test_zero_travel_time_matches_is_open_at() {
+    let r = lunch_spot();
+    for hours in 0..24 {
+        for &minutes in &[0, 30, 59] {
+            let time = Time::new(hours, minutes);
+            assert_eq!(
+                r.is_viable_with(Day::Monday, time, Duration::from_secs(0)),
+                r.is_open_at(Day::Monday, time)
+            );
+        }
+    }
+}
+
+fn walkable() -> Vec<Restaurant> {
+    serde_json::from_value(json!([
+        {
+            "name": "Union Grill",
+            "walk_minutes": 2,
+            "hours": { "monday": { "start": "11:00", "end": "14:00" } }
+        },
+        {
+            "name": "Across Town",
+            "walk_minutes": 20,
+            "hours": { "monday": { "start": "11:00", "end": "14:00" } }
+        },
+        {
+            "name": "Somewhere",
+            "hours": { "monday": { "start": "11:00", "end": "14:00" } }
+        }
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_travel_time() {
+    let restaurants = walkable();
+    assert_eq!(
+        restaurants[0].travel_time(),
+        Some(Duration::from_secs(2 * 60))
+    );
+    assert_eq!(
+        restaurants[1].travel_time(),
+        Some(Duration::from_secs(20 * 60))
+    );
+    assert_eq!(restaurants[2].travel_time(), None);
+}
+
+#[test]
+fn test_is_viable_uses_travel_time() {
+    let restaurants = walkable();
+    let viable = |time| {
+        restaurants
+            .iter()
+            .filter(|r| r.is_viable(Day::Monday, time))
+            .map(|r| r.name.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(viable(Time::new(13, 45)), vec!["Union Grill", "Somewhere"]);
+    assert_eq!(viable(Time::new(13, 55)), vec!["Union Grill"]);
+    assert!(viable(Time::new(13, 58)).is_empty());
+}
+
+#[test]
+fn test_closes_soon_at_threshold() {
+    let r = lunch_spot();
+    let threshold = Duration::from_secs(15 * 60);
+    assert!(!r.closes_soon(Day::Monday, Time::new(13, 44), threshold));
+    assert!(r.closes_soon(Day::Monday, Time::new(13, 45), threshold));
+    assert!(r.closes_soon(Day::Monday, Time::new(13, 59), threshold));
+    assert!(!r.closes_soon(Day::Monday, Time::new(14, 0), threshold));
+}
+
+#[test]
+fn test_viability() {
+    let r = lunch_spot();
+    assert_eq!(
+        r.viability(Day::Monday, Time::new(12, 0)),
+        Viability::Open {
+            closes_in: Some(Duration::from_secs(2 * 60 * 60))
+        }
+    );
+    assert_eq!(
+        r.viability(Day::Monday, Time::new(13, 45)),
+        Viability::ClosingSoon {
+            closes_in: CLOSING_SOON_THRESHOLD
+        }
+    );
+    assert_eq!(
+        r.viability(Day::Monday, Time::new(14, 0)),
+        Viability::Closed
+    );
+    assert_eq!(
+        r.viability(Day::Tuesday, Time::new(12, 0)),
+        Viability::Closed
+    );
+}
+
+#[test]
+fn test_all_day_never_closes_soon() {
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Crossroads",
+        "hours": { "monday": { "start": "0:00", "end": "24:00" } }
+    }))
+    .unwrap();
+    let threshold = Duration::from_secs(15 * 60);
+    assert!(!r.closes_soon(Day::Monday, Time::new(23, 55), threshold));
+    assert_eq!(
+        r.viability(Day::Monday, Time::new(23, 55)),
+        Viability::Open { closes_in: None }
+    );
+}
+
+fn late_night() -> Restaurant {
+    serde_json::from_value(json!({
+        "name": "Night Owl Diner",
+        "hours": { "friday": { "start": "21:00", "end": "26:00" } }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_overnight_viability() {
+    let r = late_night();
+    assert!(r.is_viable(Day::Friday, Time::new(23, 30)));
+    assert!(r.is_viable(Day::Saturday, Time::new(0, 30)));
+    assert!(!r.is_viable(Day::Saturday, Time::new(2, 30)));
+    assert!(r.is_open_at(Day::Saturday, Time::new(1, 59)));
+    assert!(!r.is_open_at(Day::Saturday, Time::new(2, 0)));
+    assert!(!r.is_open_at(Day::Friday, Time::new(0, 30)));
+    assert_eq!(
+        r.viability(Day::Saturday, Time::new(1, 45)),
+        Viability::ClosingSoon {
+            closes_in: CLOSING_SOON_THRESHOLD
+        }
+    );
+}
+
+#[test]
+fn test_viable_now() {
+    let restaurants = vec![lunch_spot(), late_night()];
+    let names = |clock: FixedClock| {
+        viable_now(&restaurants, &clock)
+            .into_iter()
+            .map(|r| r.name.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(FixedClock::new(Day::Monday, Time::new(12, 0))),
+        vec!["Noodles & Company"]
+    );
+    assert_eq!(
+        names(FixedClock::new(Day::Saturday, Time::new(0, 30))),
+        vec!["Night Owl Diner"]
+    );
+    assert!(names(FixedClock::new(Day::Sunday, Time::new(12, 0))).is_empty());
+}
+
+#[test]
+fn test_now_helpers() {
+    let r = lunch_spot();
+    let clock = FixedClock::new(Day::Monday, Time::new(13, 55));
+    assert_eq!(r.hours_today(&clock), r.get_hours(Day::Monday));
+    assert!(!r.is_viable_now(&clock));
+    assert!(r.is_viable_now(&FixedClock::new(Day::Monday, Time::new(13, 0))));
+    assert_eq!(
+        r.hours_today(&FixedClock::new(Day::Tuesday, Time::new(12, 0))),
+        None
+    );
+    assert_eq!(FixedClock::snapshot(&clock), clock);
+}
+
+#[test]
+fn test_min_remaining_cutoff() {
+    let options = ViabilityOptions {
+        travel: Duration::from_secs(10 * 60),
+        min_remaining: Duration::from_secs(25 * 60),
+    };
+    let r = lunch_spot();
+    // Closes in exactly travel + min_remaining (35 minutes).
+    assert!(r.is_viable_with_options(Day::Monday, Time::new(13, 25), options));
+    assert!(!r.is_viable_with_options(Day::Monday, Time::new(13, 26), options));
+    assert!(r.is_viable(Day::Monday, Time::new(13, 45)));
+    assert_eq!(ViabilityOptions::default(), options);
+}
+
+#[test]
+fn test_min_remaining_overnight() {
+    let options = ViabilityOptions::default();
+    let r = late_night();
+    assert!(r.is_viable_with_options(Day::Friday, Time::new(23, 30), options));
+    assert!(r.is_viable_with_options(Day::Saturday, Time::new(1, 25), options));
+    assert!(!r.is_viable_with_options(Day::Saturday, Time::new(1, 26), options));
+    let short: Restaurant = serde_json::from_value(json!({
+        "name": "Midnight Snack",
+        "hours": { "friday": { "start": "21:00", "end": "0:15" } }
+    }))
+    .unwrap();
+    assert!(short.is_viable_with_options(Day::Friday, Time::new(23, 40), options));
+    assert!(!short.is_viable_with_options(Day::Friday, Time::new(23, 41), options));
+}
+
+#[test]
+fn test_min_remaining_all_day() {
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Crossroads",
+        "hours": { "daily": { "start": "0:00", "end": "24:00" } }
+    }))
+    .unwrap();
+    let options = ViabilityOptions {
+        travel: Duration::from_secs(0),
+        min_remaining: Duration::from_secs(24 * 60 * 60),
+    };
+    for day in Day::all() {
+        assert!(r.is_viable_with_options(day, Time::new(23, 59), options));
+    }
+}
+
+#[test]
+fn test_get_viable_at_shifted_time() {
+    let restaurants = vec![lunch_spot(), late_night()];
+    let names = |day: Day, time: Time| {
+        get_viable_at(&restaurants, day, time)
+            .into_iter()
+            .map(|r| r.name.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert!(names(Day::Monday, Time::new(10, 20)).is_empty());
+    // Noodles & Company opens 40 minutes after 10:20.
+    assert_eq!(
+        names(Day::Monday, Time::new(10, 20) + 60),
+        vec!["Noodles & Company"]
+    );
+}
+
+#[test]
+fn test_overnight_end_written_as_early_morning() {
+    // Hours ending "2:00" rather than "26:00" still spill over into Saturday.
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Night Owl Diner",
+        "hours": { "friday": { "start": "18:00", "end": "2:00" } }
+    }))
+    .unwrap();
+    assert!(r.is_viable(Day::Saturday, Time::new(0, 30)));
+    assert!(r.is_open_at(Day::Saturday, Time::new(1, 59)));
+    assert!(!r.is_viable(Day::Saturday, Time::new(1, 55)));
+    assert!(!r.is_open_at(Day::Friday, Time::new(1, 0)));
+}
+
+#[test]
+fn test_daytime_hours_dont_spill_over() {
+    let r: Restaurant = serde_json::from_value(json!({
+        "name": "Breakfast Club",
+        "hours": { "friday": { "start": "7:00", "end": "22:00" } }
+    }))
+    .unwrap();
+    assert!(r.is_viable(Day::Friday, Time::new(21, 0)));
+    for &(hours, minutes) in &[(0, 30), (7, 30), (21, 0)] {
+        assert!(!r.is_viable(Day::Saturday, Time::new(hours, minutes)));
+    }
+}
+
+#[test]
+fn test_min_open_threshold() {
+    let r = lunch_spot();
+    let (travel, min_remaining) = (Duration::from_secs(0), Duration::from_secs(30 * 60));
+    // 40 and 10 minutes before closing at 14:00.
+    assert!(r.is_viable_with_min_open(Day::Monday, Time::new(13, 20), travel, min_remaining));
+    assert!(!r.is_viable_with_min_open(Day::Monday, Time::new(13, 50), travel, min_remaining));
+    // Travel comes out of the remaining time.
+    let travel = Duration::from_secs(15 * 60);
+    assert!(!r.is_viable_with_min_open(Day::Monday, Time::new(13, 20), travel, min_remaining));
+}
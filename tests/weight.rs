@@ -0,0 +1,85 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+extern crate rand;
+
+use eat_ou::app::weighted_shuffle;
+use eat_ou::{validate, Restaurant, Severity, DEFAULT_WEIGHT};
+use rand::{Rng, SeedableRng, XorShiftRng};
+
+fn weighted(weights: &[f64]) -> Vec<Restaurant> {
+    let entries = weights
+        .iter()
+        .enumerate()
+        .map(|(i, weight)| json!({ "name": format!("Cart {}", i), "hours": {}, "weight": weight }))
+        .collect::<Vec<_>>();
+    serde_json::from_value(json!(entries)).unwrap()
+}
+
+#[test]
+fn test_weight_defaults() {
+    let restaurants: Vec<Restaurant> = serde_json::from_value(json!([
+        { "name": "Crossroads", "hours": {} },
+        { "name": "Coffee Cart", "hours": {}, "weight": 3 }
+    ]))
+    .unwrap();
+    assert_eq!(restaurants[0].weight(), DEFAULT_WEIGHT);
+    assert_eq!(restaurants[1].weight(), 3.0);
+}
+
+#[test]
+fn test_non_positive_weights_are_errors() {
+    let errors = validate(&weighted(&[1.0, 0.0, -2.0]))
+        .into_iter()
+        .filter(|i| i.severity == Severity::Error)
+        .map(|i| (i.restaurant, i.message))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            ("Cart 1".into(), "weight 0 must be a positive number".into()),
+            (
+                "Cart 2".into(),
+                "weight -2 must be a positive number".into()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_weighted_shuffle_keeps_every_restaurant() {
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    let mut restaurants = weighted(&[1.0, 2.0, 3.0, 4.0]);
+    weighted_shuffle(&mut restaurants, |n| rng.gen_range(0, n));
+    let mut names = restaurants
+        .iter()
+        .map(|r| r.name.clone())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["Cart 0", "Cart 1", "Cart 2", "Cart 3"]);
+}
+
+#[test]
+fn test_weighted_shuffle_bias() {
+    const TRIALS: usize = 20_000;
+    let weights = [1.0, 2.0, 7.0];
+    let mut rng = XorShiftRng::from_seed([5, 6, 7, 8]);
+    let mut first = [0; 3];
+    for _ in 0..TRIALS {
+        let mut restaurants = weighted(&weights);
+        weighted_shuffle(&mut restaurants, |n| rng.gen_range(0, n));
+        // The queue is consumed from the back.
+        let suggested = restaurants.last().unwrap();
+        first[suggested.name[5..].parse::<usize>().unwrap()] += 1;
+    }
+    let total = weights.iter().sum::<f64>();
+    for (count, weight) in first.iter().zip(weights.iter()) {
+        let observed = *count as f64 / TRIALS as f64;
+        assert!(
+            (observed - weight / total).abs() < 0.02,
+            "weight {} was suggested first {:.3} of the time",
+            weight,
+            observed
+        );
+    }
+}
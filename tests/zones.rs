@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate serde_json;
+extern crate eat_ou;
+
+use eat_ou::app::get_viable;
+use eat_ou::{
+    preferred_zone, Day, FixedClock, MemoryStorage, Restaurant, RestaurantFilter, Storage, Time,
+    ViabilityOptions, ZONE_KEY,
+};
+
+fn restaurants() -> Vec<Restaurant> {
+    serde_json::from_value(json!([
+        { "name": "Crossroads", "zone": "campus-corner", "hours": { "daily": { "start": "0:00", "end": "24:00" } } },
+        { "name": "Research Cafe", "zone": "research", "hours": { "daily": { "start": "0:00", "end": "24:00" } } },
+        { "name": "Food Truck", "hours": { "daily": { "start": "0:00", "end": "24:00" } } }
+    ]))
+    .unwrap()
+}
+
+fn names(filter: &RestaurantFilter) -> Vec<String> {
+    let clock = FixedClock::new(Day::Monday, Time::new(12, 0));
+    get_viable(&restaurants(), filter, ViabilityOptions::default(), &clock)
+        .into_iter()
+        .map(|r| r.name)
+        .collect()
+}
+
+#[test]
+fn test_zone() {
+    let restaurants = restaurants();
+    assert_eq!(restaurants[1].zone(), Some("research"));
+    assert_eq!(restaurants[2].zone(), None);
+}
+
+#[test]
+fn test_selected_zone() {
+    assert_eq!(
+        names(&RestaurantFilter::new().zone("Research")),
+        vec!["Research Cafe", "Food Truck"]
+    );
+    assert_eq!(
+        names(&RestaurantFilter::new().zone("campus-corner")),
+        vec!["Crossroads", "Food Truck"]
+    );
+}
+
+#[test]
+fn test_no_zone_passes() {
+    assert_eq!(
+        names(&RestaurantFilter::new().zone("south")),
+        vec!["Food Truck"]
+    );
+    assert_eq!(names(&RestaurantFilter::new()).len(), 3);
+}
+
+#[test]
+fn test_preferred_zone() {
+    let storage = MemoryStorage::new();
+    assert_eq!(preferred_zone("", &storage), None);
+    assert_eq!(
+        preferred_zone("?zone=research", &storage),
+        Some("research".into())
+    );
+    assert_eq!(storage.get(ZONE_KEY), Some("research".into()));
+    assert_eq!(
+        preferred_zone("?weekend", &storage),
+        Some("research".into())
+    );
+    assert_eq!(preferred_zone("?zone=", &storage), None);
+    assert_eq!(preferred_zone("", &storage), None);
+}